@@ -1,32 +1,40 @@
 // Copyright 2025 Recall Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
+use std::path::PathBuf;
 use std::time::Duration;
 
 use anyhow::anyhow;
 use clap::{Args, Subcommand, ValueEnum};
+use futures::future::try_join_all;
 use recall_provider::{
     fvm_shared::{address::Address, econ::TokenAmount},
+    gas_oracle::{FallbackGasOracle, FixedGasOracle, GasOracle, MultiplierGasOracle, NodeGasOracle},
     json_rpc::JsonRpcProvider,
+    message::GasParams,
     util::{get_eth_address, parse_address, parse_token_amount},
+    Client,
 };
 use recall_sdk::{
-    account::AccountStatus as SdkAccountStatus,
-    account::{Account, SetSponsorOptions, SetStatusOptions},
+    account::TtlStatus as SdkAccountStatus,
+    account::Account,
     credits::{Balance, Credits},
     ipc::subnet::EVMSubnet,
+    middleware::SigningMiddleware,
     network::{NetworkConfig, ParentNetworkConfig},
     TxParams,
 };
 use recall_signer::{
-    key::{parse_secret_key, random_secretkey, SecretKey},
+    key::{self, parse_secret_key, random_secretkey, SecretKey},
     AccountKind, EthAddress, Signer, SubnetID, Void, Wallet,
 };
 use reqwest::Url;
 use serde_json::{json, Value};
 
 use crate::credit::{handle_credit, CreditArgs};
-use crate::{get_address, print_json, print_tx_json, AddressArgs, BroadcastMode, TxArgs};
+use crate::{
+    get_address, print_json, print_tx_json, AddressArgs, BroadcastMode, KeySourceArgs, TxArgs,
+};
 
 #[derive(Clone, Debug, Args)]
 pub struct AccountArgs {
@@ -37,7 +45,11 @@ pub struct AccountArgs {
 #[derive(Clone, Debug, Subcommand)]
 enum AccountCommands {
     /// Create a new local wallet from a random seed (wallet details are NOT sent to the network).
-    Create,
+    Create(CreateArgs),
+    /// Import an existing private key into a new encrypted keystore file.
+    Import(ImportArgs),
+    /// Decrypt a keystore file (or take a raw private key) and print its plaintext private key.
+    Export(ExportArgs),
     /// Get account information.
     Info(InfoArgs),
     /// Deposit funds into a subnet from its parent.
@@ -46,6 +58,8 @@ enum AccountCommands {
     Withdraw(FundArgs),
     /// Transfer funds to another account in a subnet.
     Transfer(TransferArgs),
+    /// Transfer funds to many recipients in a subnet, submitted concurrently.
+    BatchTransfer(BatchTransferArgs),
     /// Sponsor related commands.
     #[command(subcommand)]
     Sponsor(SponsorCommands),
@@ -86,6 +100,36 @@ struct SubnetArgs {
     evm_supply_source: Option<Address>,
 }
 
+#[derive(Clone, Debug, Args)]
+struct CreateArgs {
+    /// Write the new wallet to a Web3 Secret Storage (v3) keystore file instead of printing its
+    /// raw private key.
+    #[arg(long)]
+    keystore: Option<PathBuf>,
+    /// Passphrase for `--keystore`. Prompted for on stdin if not given.
+    #[arg(long, requires = "keystore", env = "RECALL_KEYSTORE_PASSWORD", hide_env_values = true)]
+    keystore_password: Option<String>,
+}
+
+#[derive(Clone, Debug, Args)]
+struct ImportArgs {
+    /// The existing private key to import (ECDSA, secp256k1).
+    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
+    private_key: SecretKey,
+    /// Path to write the new encrypted keystore file to.
+    #[arg(long)]
+    keystore: PathBuf,
+    /// Passphrase to protect the new keystore file with. Prompted for on stdin if not given.
+    #[arg(long, env = "RECALL_NEW_KEYSTORE_PASSWORD", hide_env_values = true)]
+    keystore_password: Option<String>,
+}
+
+#[derive(Clone, Debug, Args)]
+struct ExportArgs {
+    #[command(flatten)]
+    key_source: KeySourceArgs,
+}
+
 #[derive(Clone, Debug, Args)]
 struct InfoArgs {
     #[command(flatten)]
@@ -94,11 +138,33 @@ struct InfoArgs {
     subnet: SubnetArgs,
 }
 
+/// Builds the [`GasOracle`] a transaction is sent through -- only consulted when the caller left
+/// `gas_limit` at its zero default (see [`SigningMiddleware::send_transaction`]). Tries
+/// estimating from `provider`'s own fee history first, falling back to `gas_params` (the static
+/// minimums from [`GasParams::default`], or whatever `--gas-fee-cap`/`--gas-premium` overrode
+/// them with) if that query fails, and scales whichever estimate wins by `multiplier`.
+fn build_gas_oracle<C>(
+    provider: JsonRpcProvider<C>,
+    gas_params: GasParams,
+    multiplier: f64,
+) -> Box<dyn GasOracle + Sync>
+where
+    C: Client + Send + Sync + 'static,
+{
+    Box::new(MultiplierGasOracle::new(
+        FallbackGasOracle::new(vec![
+            Box::new(NodeGasOracle::new(provider, 20, 50.0, 1.1)),
+            Box::new(FixedGasOracle::new(gas_params)),
+        ]),
+        multiplier,
+    ))
+}
+
 #[derive(Clone, Debug, Args)]
 struct FundArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// The recipient account address. If not present, the signer address is used.
     #[arg(long, value_parser = parse_address)]
     to: Option<Address>,
@@ -112,8 +178,8 @@ struct FundArgs {
 #[derive(Clone, Debug, Args)]
 struct TransferArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// The recipient account address.
     #[arg(long, value_parser = parse_address)]
     to: Address,
@@ -124,11 +190,23 @@ struct TransferArgs {
     subnet: SubnetArgs,
 }
 
+#[derive(Clone, Debug, Args)]
+struct BatchTransferArgs {
+    /// Wallet private key (ECDSA, secp256k1) for signing transactions.
+    #[command(flatten)]
+    key_source: KeySourceArgs,
+    /// Path to a file listing one `<recipient-address> <amount-in-fil>` pair per line. Blank
+    /// lines and lines starting with `#` are ignored.
+    file: PathBuf,
+    #[command(flatten)]
+    subnet: SubnetArgs,
+}
+
 #[derive(Clone, Debug, Args)]
 struct SetSponsorArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Credit sponsor address.
     #[arg(value_parser = parse_address)]
     sponsor: Address,
@@ -142,8 +220,8 @@ struct SetSponsorArgs {
 #[derive(Clone, Debug, Args)]
 struct UnsetSponsorArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Broadcast mode for the transaction.
     #[arg(short, long, value_enum, env = "RECALL_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
     broadcast_mode: BroadcastMode,
@@ -154,8 +232,8 @@ struct UnsetSponsorArgs {
 #[derive(Clone, Debug, Args)]
 pub struct SetStatusArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Account address for which the status is being set.
     #[arg(long, value_parser = parse_address)]
     address: Address,
@@ -190,22 +268,39 @@ impl AccountStatus {
 }
 
 /// Account commands handler.
+///
+/// Note: Ledger hardware-wallet signing (`--ledger-account-index`) was removed from these
+/// commands -- they either need `Signer::send_transaction` (which `LedgerSigner` can't implement:
+/// the vendored `SignedMessage` type has no constructor that accepts a pre-computed signature) or
+/// go through `get_eth_signer`, which needs the secret key a hardware wallet never exposes.
+/// Ledger-backed EIP-1559 signing does work today, just not via this type; see
+/// `cli::validator::get_ledger_signer_with_fee_estimator`.
 pub async fn handle_account(
     cfg: NetworkConfig,
     args: &AccountArgs,
     verbosity: usize,
 ) -> anyhow::Result<()> {
     let provider =
-        JsonRpcProvider::new_http(cfg.rpc_url.clone(), cfg.subnet_id.chain_id(), None, None)?;
+        JsonRpcProvider::new_auto(cfg.rpc_url.clone(), cfg.subnet_id.chain_id(), None, None)?;
 
     match &args.command {
-        AccountCommands::Create => {
+        AccountCommands::Create(args) => {
             let sk = random_secretkey();
             let pk = sk.public_key().serialize();
             let address = Address::from(EthAddress::new_secp256k1(&pk)?);
             let eth_address = get_eth_address(address)?;
-            let sk_hex = hex::encode(sk.serialize());
 
+            if let Some(path) = &args.keystore {
+                let password = resolve_keystore_password(args.keystore_password.as_deref())?;
+                key::save_keystore(&sk, path, &password)?;
+                return print_json(&json!({
+                    "keystore": path,
+                    "address": eth_address,
+                    "fvm_address": address.to_string(),
+                }));
+            }
+
+            let sk_hex = hex::encode(sk.serialize());
             let mut json = json!({"private_key": sk_hex, "address": eth_address});
             if verbosity > 0 {
                 if let Value::Object(ref mut obj) = json {
@@ -218,6 +313,29 @@ pub async fn handle_account(
 
             print_json(&json)
         }
+        AccountCommands::Import(args) => {
+            let password = resolve_keystore_password(args.keystore_password.as_deref())?;
+            key::save_keystore(&args.private_key, &args.keystore, &password)?;
+            let pk = args.private_key.public_key().serialize();
+            let address = Address::from(EthAddress::new_secp256k1(&pk)?);
+            let eth_address = get_eth_address(address)?;
+            print_json(&json!({
+                "keystore": args.keystore,
+                "address": eth_address,
+                "fvm_address": address.to_string(),
+            }))
+        }
+        AccountCommands::Export(args) => {
+            let sk = args.key_source.resolve()?;
+            let pk = sk.public_key().serialize();
+            let address = Address::from(EthAddress::new_secp256k1(&pk)?);
+            let eth_address = get_eth_address(address)?;
+            print_json(&json!({
+                "private_key": hex::encode(sk.serialize()),
+                "address": eth_address,
+                "fvm_address": address.to_string(),
+            }))
+        }
         AccountCommands::Info(args) => {
             let address = get_address(args.address.clone(), &cfg.subnet_id)?;
             let eth_address = get_eth_address(address)?;
@@ -274,149 +392,174 @@ pub async fn handle_account(
                 .parent_network_config
                 .ok_or(anyhow!("address {} does not have parent", &cfg.subnet_id))?;
             let config = get_parent_subnet_config(&cfg.subnet_id, parent, args.subnet.clone())?;
+            // Signer must target the parent subnet.
+            let subnet_id = cfg.subnet_id.parent()?;
 
-            let signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
-                AccountKind::Ethereum,
-                cfg.subnet_id.parent()?, // Signer must target the parent subnet
-            )?;
-
-            let tx = Account::deposit(
+            let signer =
+                Wallet::new_secp256k1(args.key_source.resolve()?, AccountKind::Ethereum, subnet_id)?;
+            let balance = Account::deposit(
                 &signer,
                 args.to.unwrap_or(signer.address()),
                 config,
                 cfg.subnet_id,
                 args.amount.clone(),
             )
+            .await?
             .await?;
 
-            print_json(&tx)
+            print_json(&balance)
         }
         AccountCommands::Withdraw(args) => {
             let config = get_subnet_config(&cfg, args.subnet.clone())?;
 
             let signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+                args.key_source.resolve()?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
-
-            let tx = Account::withdraw(
+            let balance = Account::withdraw(
                 &signer,
                 args.to.unwrap_or(signer.address()),
                 config,
                 args.amount.clone(),
             )
+            .await?
             .await?;
 
-            print_json(&tx)
+            print_json(&balance)
         }
         AccountCommands::Transfer(args) => {
             let config = get_subnet_config(&cfg, args.subnet.clone())?;
 
             let signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+                args.key_source.resolve()?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
-
             let tx = Account::transfer(&signer, args.to, config, args.amount.clone()).await?;
 
             print_json(&tx)
         }
+        AccountCommands::BatchTransfer(args) => {
+            let config = get_subnet_config(&cfg, args.subnet.clone())?;
+            let transfers = parse_transfer_file(&args.file)?;
+
+            let signer = Wallet::new_secp256k1(
+                args.key_source.resolve()?,
+                AccountKind::Ethereum,
+                cfg.subnet_id,
+            )?;
+
+            // `Account::transfer` shares a per-address nonce cache across concurrent calls (see
+            // `EvmManager`'s `NonceManager`), so firing every transfer at once still produces a
+            // gap-free, monotonically increasing sequence of nonces instead of racing.
+            let txs = try_join_all(transfers.into_iter().map(|(to, amount)| {
+                let signer = signer.clone();
+                let config = config.clone();
+                async move { Account::transfer(&signer, to, config, amount).await }
+            }))
+            .await?;
+
+            print_json(&txs)
+        }
         AccountCommands::Sponsor(cmd) => match cmd {
             SponsorCommands::Set(args) => {
-                let broadcast_mode = args.broadcast_mode.get();
-                let TxParams {
-                    gas_params,
-                    sequence,
-                } = args.tx_args.to_tx_params();
-
-                let mut signer = Wallet::new_secp256k1(
-                    args.private_key.clone(),
+                let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
+                let TxParams { gas_params, .. } = args.tx_args.to_tx_params()?;
+
+                let signer = Wallet::new_secp256k1(
+                    args.key_source.resolve()?,
                     AccountKind::Ethereum,
                     cfg.subnet_id,
                 )?;
-                signer.set_sequence(sequence, &provider).await?;
-
-                let tx = Account::set_sponsor(
-                    &provider,
-                    &mut signer,
-                    Some(args.sponsor),
-                    SetSponsorOptions {
-                        broadcast_mode,
-                        gas_params,
-                    },
-                )
-                .await?;
+                let mut middleware = SigningMiddleware::connect_retrying(
+                    provider.clone(),
+                    signer,
+                    build_gas_oracle(provider.clone(), gas_params, args.tx_args.gas_fee_multiplier),
+                );
+                let tx =
+                    Account::set_sponsor_via(&mut middleware, Some(args.sponsor), broadcast_mode).await?;
 
                 print_tx_json(&tx)
             }
             SponsorCommands::Unset(args) => {
-                let broadcast_mode = args.broadcast_mode.get();
-                let TxParams {
-                    gas_params,
-                    sequence,
-                } = args.tx_args.to_tx_params();
-
-                let mut signer = Wallet::new_secp256k1(
-                    args.private_key.clone(),
+                let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
+                let TxParams { gas_params, .. } = args.tx_args.to_tx_params()?;
+
+                let signer = Wallet::new_secp256k1(
+                    args.key_source.resolve()?,
                     AccountKind::Ethereum,
                     cfg.subnet_id,
                 )?;
-                signer.set_sequence(sequence, &provider).await?;
-
-                let tx = Account::set_sponsor(
-                    &provider,
-                    &mut signer,
-                    None,
-                    SetSponsorOptions {
-                        broadcast_mode,
-                        gas_params,
-                    },
-                )
-                .await?;
+                let mut middleware = SigningMiddleware::connect_retrying(
+                    provider.clone(),
+                    signer,
+                    build_gas_oracle(provider.clone(), gas_params, args.tx_args.gas_fee_multiplier),
+                );
+                let tx = Account::set_sponsor_via(&mut middleware, None, broadcast_mode).await?;
 
                 print_tx_json(&tx)
             }
         },
         AccountCommands::Credit(args) => handle_credit(cfg, args).await,
         AccountCommands::SetStatus(args) => {
-            let broadcast_mode = args.broadcast_mode.get();
-            let TxParams {
-                gas_params,
-                sequence,
-            } = args.tx_args.to_tx_params();
-
-            let mut signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
+            let TxParams { gas_params, .. } = args.tx_args.to_tx_params()?;
+
+            let signer = Wallet::new_secp256k1(
+                args.key_source.resolve()?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
-
-            signer.set_sequence(sequence, &provider).await?;
-            let tx = Account::set_status(
-                &provider,
-                &mut signer,
-                args.address,
-                SetStatusOptions {
-                    status: args.status.get(),
-                    broadcast_mode,
-                    gas_params,
-                },
-            )
-            .await?;
+            let mut middleware = SigningMiddleware::connect_retrying(
+                provider.clone(),
+                signer,
+                build_gas_oracle(provider.clone(), gas_params, args.tx_args.gas_fee_multiplier),
+            );
+            let tx =
+                Account::set_ttl_status_via(&mut middleware, args.address, args.status.get(), broadcast_mode)
+                    .await?;
 
             print_tx_json(&tx)
         }
     }
 }
 
+/// Parses a `--file` for [`AccountCommands::BatchTransfer`]: one `<address> <amount>` pair per
+/// line, blank lines and `#`-prefixed comments ignored.
+fn parse_transfer_file(path: &std::path::Path) -> anyhow::Result<Vec<(Address, TokenAmount)>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| anyhow!("failed to read transfer file {}: {err}", path.display()))?;
+
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let (to, amount) = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| anyhow!("invalid transfer line '{line}', expected '<address> <amount>'"))?;
+            Ok((parse_address(to)?, parse_token_amount(amount.trim())?))
+        })
+        .collect()
+}
+
+/// Resolves a `--keystore-password` value, prompting on stdin if one wasn't given.
+fn resolve_keystore_password(given: Option<&str>) -> anyhow::Result<String> {
+    match given {
+        Some(p) => Ok(p.to_string()),
+        None => Ok(rpassword::prompt_password("Keystore passphrase: ")?),
+    }
+}
+
 /// Returns the subnet configuration from args.
 fn get_subnet_config(cfg: &NetworkConfig, args: SubnetArgs) -> anyhow::Result<EVMSubnet> {
     Ok(EVMSubnet {
         id: cfg.subnet_id.clone(),
-        provider_http: args.evm_rpc_url.unwrap_or(cfg.evm_rpc_url.clone()),
+        provider_http: args
+            .evm_rpc_url
+            .map(|url| vec![url])
+            .unwrap_or(cfg.evm_rpc_url.clone()),
         provider_timeout: Some(args.evm_rpc_timeout),
         auth_token: args.evm_rpc_auth_token,
         registry_addr: args.evm_registry.unwrap_or(cfg.evm_registry_address),
@@ -433,7 +576,10 @@ fn get_parent_subnet_config(
 ) -> anyhow::Result<EVMSubnet> {
     Ok(EVMSubnet {
         id: subnet_id.parent()?,
-        provider_http: args.evm_rpc_url.unwrap_or(parent.evm_rpc_url),
+        provider_http: args
+            .evm_rpc_url
+            .map(|url| vec![url])
+            .unwrap_or(parent.evm_rpc_url),
         provider_timeout: Some(args.evm_rpc_timeout),
         auth_token: args.evm_rpc_auth_token,
         registry_addr: args.evm_registry.unwrap_or(parent.evm_registry_address),
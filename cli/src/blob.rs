@@ -66,11 +66,11 @@ pub async fn handle_blob(cli: Cli, args: &BlobArgs) -> anyhow::Result<()> {
             print_json(&json!({"status": status}))
         }
         BlobCommands::Fund(args) => {
-            let broadcast_mode = args.broadcast_mode.get();
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
             let TxParams {
                 gas_params,
                 sequence,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
 
             let mut signer =
                 Wallet::new_secp256k1(args.private_key.clone(), AccountKind::Ethereum, subnet_id)?;
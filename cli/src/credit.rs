@@ -2,22 +2,26 @@
 // SPDX-License-Identifier: Apache-2.0, MIT
 
 use std::collections::HashSet;
+use std::path::PathBuf;
 
+use anyhow::anyhow;
 use clap::{Args, Subcommand};
 use serde_json::json;
 
 use hoku_provider::{
     fvm_shared::{address::Address, clock::ChainEpoch, econ::TokenAmount},
+    gas_oracle::{FixedGasOracle, GasOracle, MultiplierGasOracle, NodeGasOracle},
     json_rpc::JsonRpcProvider,
+    message::GasParams,
     util::{parse_address, parse_credit_amount, parse_token_amount, parse_token_amount_from_atto},
 };
 use hoku_sdk::{
-    credits::{ApproveOptions, BuyOptions, Credit, Credits, RevokeOptions},
+    credits::{ApproveOptions, BuyOptions, Credit, Credits, RevokeOptions, SiloPolicy},
     network::NetworkConfig,
     TxParams,
 };
 use hoku_signer::{
-    key::{parse_secret_key, SecretKey},
+    key::{self, parse_secret_key, SecretKey},
     AccountKind, Signer, Wallet,
 };
 
@@ -42,6 +46,41 @@ enum CreditCommands {
     Approve(ApproveArgs),
     /// Revoke an account from using credits from another account.
     Revoke(RevokeArgs),
+    /// List the credit approvals granted by, and received by, an account.
+    #[clap(alias = "show")]
+    List(ListArgs),
+}
+
+/// Gas-fee oracle used to fill in `--gas-fee-cap`/`--gas-premium` when `TxArgs` leaves them
+/// unset.
+#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+enum GasOracleKind {
+    /// Estimate fee cap/premium from the node's own recent base-fee history (the default).
+    Node,
+    /// Skip node estimation and use the fixed `--gas-fee-cap`/`--gas-premium` values (or their
+    /// enforced minimums) as-is.
+    Fixed,
+    /// Estimate from the node's fee history same as `node`, then scale the result by
+    /// `--gas-oracle-multiplier`.
+    Multiplier,
+}
+
+/// Builds the [`GasOracle`] selected by `--gas-oracle`, falling back to `fixed_gas_params` for
+/// [`GasOracleKind::Fixed`].
+fn build_gas_oracle(
+    provider: &JsonRpcProvider,
+    kind: GasOracleKind,
+    multiplier: f64,
+    fixed_gas_params: &GasParams,
+) -> Box<dyn GasOracle + Sync> {
+    match kind {
+        GasOracleKind::Node => Box::new(NodeGasOracle::new(provider.clone(), 20, 50.0, 1.1)),
+        GasOracleKind::Fixed => Box::new(FixedGasOracle::new(fixed_gas_params.clone())),
+        GasOracleKind::Multiplier => Box::new(MultiplierGasOracle::new(
+            NodeGasOracle::new(provider.clone(), 20, 50.0, 1.1),
+            multiplier,
+        )),
+    }
 }
 
 #[derive(Clone, Debug, Args)]
@@ -56,11 +95,24 @@ struct BalanceArgs {
     address: AddressArgs,
 }
 
+#[derive(Clone, Debug, Args)]
+struct ListArgs {
+    #[command(flatten)]
+    address: AddressArgs,
+}
+
 #[derive(Clone, Debug, Args)]
 struct BuyArgs {
-    /// Wallet private key (ECDSA, secp256k1) for signing transactions.
+    /// Wallet private key (ECDSA, secp256k1) for signing transactions. Alternative to
+    /// `--keystore`.
     #[arg(short, long, env = "HOKU_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    private_key: Option<SecretKey>,
+    /// Path to a Web3 Secret Storage (v3) keystore file, as an alternative to `--private-key`.
+    #[arg(long, env = "HOKU_KEYSTORE", conflicts_with = "private_key")]
+    keystore: Option<PathBuf>,
+    /// Passphrase for `--keystore`. Prompted for on stdin if not given.
+    #[arg(long, env = "HOKU_KEYSTORE_PASSWORD", hide_env_values = true)]
+    keystore_password: Option<String>,
     /// The recipient account address. If not present, the signer address is used.
     #[arg(long, value_parser = parse_address)]
     to: Option<Address>,
@@ -70,15 +122,29 @@ struct BuyArgs {
     /// Broadcast mode for the transaction.
     #[arg(short, long, value_enum, env = "HOKU_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
     broadcast_mode: BroadcastMode,
+    /// Gas-fee oracle used to fill in `--gas-fee-cap`/`--gas-premium` when unset.
+    #[arg(long, value_enum, default_value_t = GasOracleKind::Node)]
+    gas_oracle: GasOracleKind,
+    /// Multiplier applied to the estimate when `--gas-oracle multiplier` is selected, e.g. `1.5`
+    /// to bias 50% upward during network congestion.
+    #[arg(long, default_value_t = 1.5)]
+    gas_oracle_multiplier: f64,
     #[command(flatten)]
     tx_args: TxArgs,
 }
 
 #[derive(Clone, Debug, Args)]
 struct ApproveArgs {
-    /// Wallet private key (ECDSA, secp256k1) for signing transactions.
+    /// Wallet private key (ECDSA, secp256k1) for signing transactions. Alternative to
+    /// `--keystore`.
     #[arg(short, long, env = "HOKU_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    private_key: Option<SecretKey>,
+    /// Path to a Web3 Secret Storage (v3) keystore file, as an alternative to `--private-key`.
+    #[arg(long, env = "HOKU_KEYSTORE", conflicts_with = "private_key")]
+    keystore: Option<PathBuf>,
+    /// Passphrase for `--keystore`. Prompted for on stdin if not given.
+    #[arg(long, env = "HOKU_KEYSTORE_PASSWORD", hide_env_values = true)]
+    keystore_password: Option<String>,
     /// The receiver account address.
     #[arg(long, value_parser = parse_address)]
     to: Address,
@@ -101,18 +167,51 @@ struct ApproveArgs {
     /// If specified, the approval becomes invalid after this duration.
     #[arg(long)]
     ttl: Option<ChainEpoch>,
+    /// Fixed gas-fee budget per sponsored transaction, in atto. Requires `--max-tx-count`;
+    /// together they derive `gas_fee_limit` as `fixed_cost_per_tx * max_tx_count`, overriding
+    /// `--gas-fee-limit`, so sponsors can plan a deterministic total budget instead of an
+    /// open-ended gas ceiling.
+    #[arg(long, value_parser = parse_token_amount_from_atto, requires = "max_tx_count")]
+    fixed_cost_per_tx: Option<TokenAmount>,
+    /// The number of sponsored transactions `--fixed-cost-per-tx` budgets for.
+    #[arg(long, requires = "fixed_cost_per_tx")]
+    max_tx_count: Option<u64>,
     /// Broadcast mode for the transaction.
     #[arg(short, long, value_enum, env = "HOKU_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
     broadcast_mode: BroadcastMode,
+    /// Gas-fee oracle used to fill in `--gas-fee-cap`/`--gas-premium` when unset.
+    #[arg(long, value_enum, default_value_t = GasOracleKind::Node)]
+    gas_oracle: GasOracleKind,
+    /// Multiplier applied to the estimate when `--gas-oracle multiplier` is selected, e.g. `1.5`
+    /// to bias 50% upward during network congestion.
+    #[arg(long, default_value_t = 1.5)]
+    gas_oracle_multiplier: f64,
     #[command(flatten)]
     tx_args: TxArgs,
 }
 
+impl ApproveArgs {
+    /// Builds the [`SiloPolicy`] from `--fixed-cost-per-tx`/`--max-tx-count`, if both were given.
+    fn silo(&self) -> Option<SiloPolicy> {
+        Some(SiloPolicy {
+            fixed_cost_per_tx: self.fixed_cost_per_tx.clone()?,
+            max_tx_count: self.max_tx_count?,
+        })
+    }
+}
+
 #[derive(Clone, Debug, Args)]
 struct RevokeArgs {
-    /// Wallet private key (ECDSA, secp256k1) for signing transactions.
+    /// Wallet private key (ECDSA, secp256k1) for signing transactions. Alternative to
+    /// `--keystore`.
     #[arg(short, long, env = "HOKU_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    private_key: Option<SecretKey>,
+    /// Path to a Web3 Secret Storage (v3) keystore file, as an alternative to `--private-key`.
+    #[arg(long, env = "HOKU_KEYSTORE", conflicts_with = "private_key")]
+    keystore: Option<PathBuf>,
+    /// Passphrase for `--keystore`. Prompted for on stdin if not given.
+    #[arg(long, env = "HOKU_KEYSTORE_PASSWORD", hide_env_values = true)]
+    keystore_password: Option<String>,
     /// The receiver account address.
     #[arg(long, value_parser = parse_address)]
     to: Address,
@@ -123,28 +222,46 @@ struct RevokeArgs {
     /// Broadcast mode for the transaction.
     #[arg(short, long, value_enum, env = "HOKU_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
     broadcast_mode: BroadcastMode,
+    /// Gas-fee oracle used to fill in `--gas-fee-cap`/`--gas-premium` when unset.
+    #[arg(long, value_enum, default_value_t = GasOracleKind::Node)]
+    gas_oracle: GasOracleKind,
+    /// Multiplier applied to the estimate when `--gas-oracle multiplier` is selected, e.g. `1.5`
+    /// to bias 50% upward during network congestion.
+    #[arg(long, default_value_t = 1.5)]
+    gas_oracle_multiplier: f64,
     #[command(flatten)]
     tx_args: TxArgs,
 }
 
 /// Credit commands handler.
 pub async fn handle_credit(cfg: NetworkConfig, args: &CreditArgs) -> anyhow::Result<()> {
-    let provider = JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+    let provider = JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
 
     match &args.command {
         CreditCommands::Stats(args) => {
             let stats = Credits::stats(&provider, args.address.height).await?;
             print_json(&json!(stats))
         }
+        CreditCommands::List(args) => {
+            let address = get_address(args.address.clone(), &cfg.subnet_id)?;
+            let approvals = Credits::list(&provider, address, args.address.height).await?;
+            print_json(&json!(approvals))
+        }
         CreditCommands::Buy(args) => {
-            let broadcast_mode = args.broadcast_mode.get();
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
             let TxParams {
                 gas_params,
                 sequence,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
+            let gas_oracle = build_gas_oracle(
+                &provider,
+                args.gas_oracle,
+                args.gas_oracle_multiplier,
+                &gas_params,
+            );
 
             let mut signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+                resolve_key_source(&args.private_key, &args.keystore, &args.keystore_password)?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
@@ -159,6 +276,7 @@ pub async fn handle_credit(cfg: NetworkConfig, args: &CreditArgs) -> anyhow::Res
                 BuyOptions {
                     broadcast_mode,
                     gas_params,
+                    gas_oracle: Some(gas_oracle.as_ref()),
                 },
             )
             .await?;
@@ -166,14 +284,20 @@ pub async fn handle_credit(cfg: NetworkConfig, args: &CreditArgs) -> anyhow::Res
             print_tx_json(&tx)
         }
         CreditCommands::Approve(args) => {
-            let broadcast_mode = args.broadcast_mode.get();
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
             let TxParams {
                 gas_params,
                 sequence,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
+            let gas_oracle = build_gas_oracle(
+                &provider,
+                args.gas_oracle,
+                args.gas_oracle_multiplier,
+                &gas_params,
+            );
 
             let mut signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+                resolve_key_source(&args.private_key, &args.keystore, &args.keystore_password)?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
@@ -188,9 +312,11 @@ pub async fn handle_credit(cfg: NetworkConfig, args: &CreditArgs) -> anyhow::Res
                 ApproveOptions {
                     credit_limit: args.credit_limit.clone(),
                     gas_fee_limit: args.gas_fee_limit.clone(),
+                    silo: args.silo(),
                     ttl: args.ttl,
                     broadcast_mode,
                     gas_params,
+                    gas_oracle: Some(gas_oracle.as_ref()),
                 },
             )
             .await?;
@@ -198,14 +324,20 @@ pub async fn handle_credit(cfg: NetworkConfig, args: &CreditArgs) -> anyhow::Res
             print_tx_json(&tx)
         }
         CreditCommands::Revoke(args) => {
-            let broadcast_mode = args.broadcast_mode.get();
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
             let TxParams {
                 gas_params,
                 sequence,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
+            let gas_oracle = build_gas_oracle(
+                &provider,
+                args.gas_oracle,
+                args.gas_oracle_multiplier,
+                &gas_params,
+            );
 
             let mut signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+                resolve_key_source(&args.private_key, &args.keystore, &args.keystore_password)?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
@@ -220,6 +352,7 @@ pub async fn handle_credit(cfg: NetworkConfig, args: &CreditArgs) -> anyhow::Res
                 RevokeOptions {
                     broadcast_mode,
                     gas_params,
+                    gas_oracle: Some(gas_oracle.as_ref()),
                 },
             )
             .await?;
@@ -228,3 +361,30 @@ pub async fn handle_credit(cfg: NetworkConfig, args: &CreditArgs) -> anyhow::Res
         }
     }
 }
+
+/// Resolves `--private-key` or `--keystore` (prompting for its passphrase on stdin if
+/// `--keystore-password` wasn't given) to a signing [`SecretKey`], erroring out if neither was
+/// given (they're only conditionally required alternatives to each other).
+///
+/// Note: `--ledger-account-index` was removed from these commands (it always failed -- the
+/// vendored `SignedMessage` type has no constructor that accepts a pre-computed signature, so
+/// `LedgerSigner` can't actually sign the FVM message these commands send). Ledger hardware-wallet
+/// signing only works for EVM-routed, EIP-1559 transactions today; see
+/// `cli::validator::get_ledger_signer_with_fee_estimator`.
+fn resolve_key_source(
+    private_key: &Option<SecretKey>,
+    keystore: &Option<PathBuf>,
+    keystore_password: &Option<String>,
+) -> anyhow::Result<SecretKey> {
+    if let Some(sk) = private_key {
+        Ok(sk.clone())
+    } else if let Some(path) = keystore {
+        let password = match keystore_password {
+            Some(p) => p.clone(),
+            None => rpassword::prompt_password("Keystore passphrase: ")?,
+        };
+        key::load_keystore(path, &password)
+    } else {
+        Err(anyhow!("either --private-key or --keystore is required"))
+    }
+}
@@ -0,0 +1,417 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! A long-running JSON-RPC 2.0 daemon that exposes SDK operations over both an HTTP
+//! transport and a local Unix-domain-socket transport, so other processes (including
+//! non-Rust language bindings) can drive Recall without re-implementing signing and
+//! sequence-tracking logic themselves.
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context};
+use clap::Args;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, Server};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use tokio::net::UnixListener;
+use tokio::sync::Mutex;
+
+use recall_provider::{
+    fvm_shared::address::Address,
+    json_rpc::JsonRpcProvider,
+    object::ObjectProvider,
+    query::FvmQueryHeight,
+    util::{parse_address, parse_token_amount_from_atto},
+};
+use recall_sdk::{
+    credits::{ApproveOptions, BuyOptions, Credits, RevokeOptions},
+    machine::{sqlite::ExecuteOptions, sqlite::Sqlite, Machine},
+    network::NetworkConfig,
+};
+use recall_signer::{AccountKind, Signer, Wallet};
+
+use crate::KeySourceArgs;
+
+#[derive(Clone, Debug, Args)]
+pub struct DaemonArgs {
+    /// Wallet private key (ECDSA, secp256k1) used to sign transactions submitted over RPC.
+    #[command(flatten)]
+    key_source: KeySourceArgs,
+    /// HTTP address to serve JSON-RPC on, e.g. `127.0.0.1:8645`.
+    #[arg(long, default_value = "127.0.0.1:8645")]
+    http_addr: SocketAddr,
+    /// Path to a Unix-domain socket to additionally serve JSON-RPC on.
+    #[arg(long)]
+    ipc_path: Option<PathBuf>,
+}
+
+/// JSON-RPC 2.0 request envelope.
+#[derive(Debug, Deserialize)]
+struct RpcRequest {
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// JSON-RPC 2.0 response envelope.
+#[derive(Debug, Serialize)]
+struct RpcResponse {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RpcError>,
+}
+
+#[derive(Debug, Serialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+/// Shared daemon state: one provider and one signing wallet multiplexed across all clients.
+struct DaemonState {
+    provider: JsonRpcProvider,
+    signer: Mutex<Wallet>,
+}
+
+/// Run the JSON-RPC daemon, serving on HTTP and, optionally, a Unix socket.
+///
+/// The daemon is intended to be run as a single long-lived, authenticated signer process
+/// that multiple local clients can multiplex requests against, instead of spawning the CLI
+/// binary (and re-entering a private key) per operation.
+pub async fn handle_daemon(cfg: NetworkConfig, args: &DaemonArgs) -> anyhow::Result<()> {
+    let provider = JsonRpcProvider::new_http(
+        cfg.rpc_url,
+        cfg.subnet_id.chain_id(),
+        None,
+        Some(cfg.object_api_url),
+    )?;
+
+    let mut signer = Wallet::new_secp256k1(
+        args.key_source.resolve()?,
+        AccountKind::Ethereum,
+        cfg.subnet_id,
+    )?;
+    signer.init_sequence(&provider).await?;
+
+    let state = Arc::new(DaemonState {
+        provider,
+        signer: Mutex::new(signer),
+    });
+
+    let ipc_task = if let Some(path) = args.ipc_path.clone() {
+        let state = state.clone();
+        Some(tokio::spawn(async move { serve_ipc(path, state).await }))
+    } else {
+        None
+    };
+
+    let http_state = state.clone();
+    let make_svc = make_service_fn(move |_conn| {
+        let state = http_state.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |req| {
+                let state = state.clone();
+                async move { Ok::<_, Infallible>(handle_http(req, state).await) }
+            }))
+        }
+    });
+
+    tracing::info!("JSON-RPC daemon listening on http://{}", args.http_addr);
+    let server = Server::bind(&args.http_addr).serve(make_svc);
+
+    if let Some(ipc_task) = ipc_task {
+        tokio::select! {
+            res = server => { res?; }
+            res = ipc_task => { res??; }
+        }
+    } else {
+        server.await?;
+    }
+    Ok(())
+}
+
+async fn handle_http(req: Request<Body>, state: Arc<DaemonState>) -> Response<Body> {
+    let body = match hyper::body::to_bytes(req.into_body()).await {
+        Ok(b) => b,
+        Err(e) => return json_response(500, &json!({"error": e.to_string()})),
+    };
+    let text = String::from_utf8_lossy(&body).into_owned();
+    let response = dispatch_line(&text, &state).await;
+    json_response(200, &response)
+}
+
+fn json_response(status: u16, value: &Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(Body::from(value.to_string()))
+        .expect("building a response from a fixed status/header never fails")
+}
+
+/// Serve JSON-RPC over a Unix-domain socket, one JSON object per line.
+async fn serve_ipc(path: PathBuf, state: Arc<DaemonState>) -> anyhow::Result<()> {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    if path.exists() {
+        std::fs::remove_file(&path)
+            .with_context(|| format!("failed to remove stale socket at {}", path.display()))?;
+    }
+    let listener = UnixListener::bind(&path)
+        .with_context(|| format!("failed to bind IPC socket at {}", path.display()))?;
+    tracing::info!("JSON-RPC daemon listening on ipc://{}", path.display());
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let state = state.clone();
+        tokio::spawn(async move {
+            let (reader, mut writer) = stream.into_split();
+            let mut lines = BufReader::new(reader).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                let response = dispatch_line(&line, &state).await;
+                let mut out = response.to_string();
+                out.push('\n');
+                if writer.write_all(out.as_bytes()).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
+
+async fn dispatch_line(line: &str, state: &Arc<DaemonState>) -> Value {
+    let request: RpcRequest = match serde_json::from_str(line) {
+        Ok(r) => r,
+        Err(e) => {
+            return serde_json::to_value(RpcResponse {
+                jsonrpc: "2.0",
+                id: Value::Null,
+                result: None,
+                error: Some(RpcError {
+                    code: -32700,
+                    message: format!("parse error: {e}"),
+                }),
+            })
+            .expect("RpcResponse always serializes")
+        }
+    };
+    let id = request.id.clone();
+    match dispatch(request, state).await {
+        Ok(result) => serde_json::to_value(RpcResponse {
+            jsonrpc: "2.0",
+            id,
+            result: Some(result),
+            error: None,
+        }),
+        Err(e) => serde_json::to_value(RpcResponse {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(RpcError {
+                code: -32000,
+                message: e.to_string(),
+            }),
+        }),
+    }
+    .expect("RpcResponse always serializes")
+}
+
+/// One RPC method per SDK call, taking the same option structs as JSON params.
+async fn dispatch(request: RpcRequest, state: &Arc<DaemonState>) -> anyhow::Result<Value> {
+    let provider = &state.provider;
+    match request.method.as_str() {
+        "credits_stats" => {
+            let stats = Credits::stats(provider, FvmQueryHeight::Committed).await?;
+            Ok(json!(stats))
+        }
+        "credits_balance" => {
+            let params: BalanceParams = serde_json::from_value(request.params)?;
+            let balance = Credits::balance(provider, params.address, FvmQueryHeight::Committed)
+                .await?;
+            Ok(json!(balance))
+        }
+        "credits_buy" => {
+            let params: BuyParams = serde_json::from_value(request.params)?;
+            let mut signer = state.signer.lock().await;
+            let tx = Credits::buy(
+                provider,
+                &mut *signer,
+                params.to,
+                params.amount,
+                BuyOptions::default(),
+            )
+            .await?;
+            Ok(json!(tx))
+        }
+        "credits_approve" => {
+            let params: ApproveParams = serde_json::from_value(request.params)?;
+            let mut signer = state.signer.lock().await;
+            let from = signer.address();
+            let tx = Credits::approve(
+                provider,
+                &mut *signer,
+                from,
+                params.to,
+                ApproveOptions {
+                    credit_limit: params.credit_limit,
+                    ..Default::default()
+                },
+            )
+            .await?;
+            Ok(json!(tx))
+        }
+        "credits_revoke" => {
+            let params: RevokeParams = serde_json::from_value(request.params)?;
+            let mut signer = state.signer.lock().await;
+            let from = signer.address();
+            let tx = Credits::revoke(provider, &mut *signer, from, params.to, RevokeOptions::default())
+                .await?;
+            Ok(json!(tx))
+        }
+        "sqlite_query" => {
+            let params: SqliteQueryParams = serde_json::from_value(request.params)?;
+            let machine = Sqlite::attach(params.address).await?;
+            let res = machine
+                .query(provider, params.stmt, FvmQueryHeight::Committed)
+                .await?;
+            Ok(json!(res))
+        }
+        "sqlite_execute" => {
+            let params: SqliteExecuteParams = serde_json::from_value(request.params)?;
+            let machine = Sqlite::attach(params.address).await?;
+            let mut signer = state.signer.lock().await;
+            let tx = machine
+                .execute(provider, &mut *signer, params.stmts, ExecuteOptions::default())
+                .await?;
+            Ok(json!(tx))
+        }
+        "object_upload" => {
+            // Accepts base64-encoded bytes as `data`; the daemon streams them to the node
+            // rather than buffering the whole multipart form in a single `Vec<u8>`.
+            let params: ObjectUploadParams = serde_json::from_value(request.params)?;
+            let bytes = base64::Engine::decode(
+                &base64::engine::general_purpose::STANDARD,
+                params.data,
+            )
+            .context("data is not valid base64")?;
+            let size = bytes.len() as u64;
+            let stream = futures::stream::once(async move { Ok::<_, std::io::Error>(bytes) });
+            let body = reqwest::Body::wrap_stream(stream);
+            let res = provider.upload(body, size).await?;
+            Ok(json!({"hash": res.hash, "metadata_hash": res.metadata_hash}))
+        }
+        "object_download" => {
+            // Mirrors `object_upload`'s base64 framing: the whole object is read into memory and
+            // returned as one base64 string rather than streamed, since JSON-RPC has no
+            // chunked-response mechanism here.
+            let params: ObjectDownloadParams = serde_json::from_value(request.params)?;
+            let res = provider
+                .download(params.address, &params.key, params.range, params.height)
+                .await?;
+            let bytes = res.bytes().await?;
+            let data = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, bytes);
+            Ok(json!({"data": data}))
+        }
+        "object_size" => {
+            let params: ObjectSizeParams = serde_json::from_value(request.params)?;
+            let size = provider.size(params.address, &params.key, params.height).await?;
+            Ok(json!({"size": size}))
+        }
+        other => Err(anyhow!("unknown method: {other}")),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct BalanceParams {
+    #[serde(deserialize_with = "deserialize_address")]
+    address: Address,
+}
+
+#[derive(Debug, Deserialize)]
+struct BuyParams {
+    #[serde(deserialize_with = "deserialize_address")]
+    to: Address,
+    #[serde(deserialize_with = "deserialize_token_amount")]
+    amount: recall_provider::fvm_shared::econ::TokenAmount,
+}
+
+#[derive(Debug, Deserialize)]
+struct ApproveParams {
+    #[serde(deserialize_with = "deserialize_address")]
+    to: Address,
+    credit_limit: Option<recall_sdk::credits::Credit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RevokeParams {
+    #[serde(deserialize_with = "deserialize_address")]
+    to: Address,
+}
+
+#[derive(Debug, Deserialize)]
+struct SqliteQueryParams {
+    #[serde(deserialize_with = "deserialize_address")]
+    address: Address,
+    stmt: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SqliteExecuteParams {
+    #[serde(deserialize_with = "deserialize_address")]
+    address: Address,
+    stmts: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ObjectUploadParams {
+    data: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ObjectDownloadParams {
+    #[serde(deserialize_with = "deserialize_address")]
+    address: Address,
+    key: String,
+    #[serde(default)]
+    range: Option<String>,
+    #[serde(default)]
+    height: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ObjectSizeParams {
+    #[serde(deserialize_with = "deserialize_address")]
+    address: Address,
+    key: String,
+    #[serde(default)]
+    height: u64,
+}
+
+fn deserialize_address<'de, D>(d: D) -> Result<Address, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s = String::deserialize(d)?;
+    parse_address(&s).map_err(serde::de::Error::custom)
+}
+
+fn deserialize_token_amount<'de, D>(
+    d: D,
+) -> Result<recall_provider::fvm_shared::econ::TokenAmount, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s = String::deserialize(d)?;
+    parse_token_amount_from_atto(&s).map_err(serde::de::Error::custom)
+}
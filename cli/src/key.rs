@@ -0,0 +1,215 @@
+// Copyright 2026 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Local signing-key lifecycle: generate, inspect, and deterministically derive/recover
+//! secp256k1 keys. Unlike the other command modules, these never touch the network.
+
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use clap::{Args, Subcommand};
+use recall_provider::{fvm_shared::address::Address, util::get_eth_address};
+use recall_signer::{
+    key::{random_secretkey, save_keystore, SecretKey},
+    EthAddress,
+};
+use serde_json::json;
+use sha3::{Digest, Keccak256};
+
+use crate::{print_json, KeySourceArgs};
+
+/// Default number of iterated [`Keccak256`] rounds for [`KeyCommands::Brain`], chosen to make
+/// brute-forcing the round count (not just the passphrase) meaningfully expensive.
+const DEFAULT_BRAIN_ROUNDS: u64 = 1_000_000;
+
+#[derive(Clone, Debug, Args)]
+pub struct KeyArgs {
+    #[command(subcommand)]
+    command: KeyCommands,
+}
+
+#[derive(Clone, Debug, Subcommand)]
+enum KeyCommands {
+    /// Generate a new random keypair, or search for a vanity address with `--prefix`.
+    Generate(GenerateArgs),
+    /// Show the address and public key for a secret key or keystore file.
+    Inspect(InspectArgs),
+    /// Deterministically derive a keypair from a passphrase (a "brain wallet").
+    Brain(BrainArgs),
+    /// Recover a brain-wallet key given its address and passphrase, searching nearby round
+    /// counts in case the exact count used to derive it was forgotten.
+    Recover(RecoverArgs),
+}
+
+#[derive(Clone, Debug, Args)]
+struct GenerateArgs {
+    /// Only accept an address starting with this hex prefix (case-insensitive, without `0x`).
+    /// Every additional hex digit roughly multiplies the expected number of attempts by 16.
+    #[arg(long)]
+    prefix: Option<String>,
+    /// Write the key to a Web3 Secret Storage (v3) keystore file instead of printing the raw
+    /// secret.
+    #[arg(long)]
+    keystore: Option<PathBuf>,
+    /// Passphrase for `--keystore`. Prompted for on stdin if not given.
+    #[arg(long, requires = "keystore")]
+    keystore_password: Option<String>,
+}
+
+#[derive(Clone, Debug, Args)]
+struct InspectArgs {
+    #[command(flatten)]
+    key_source: KeySourceArgs,
+}
+
+#[derive(Clone, Debug, Args)]
+struct BrainArgs {
+    /// Passphrase to derive the key from.
+    #[arg(long)]
+    passphrase: String,
+    /// Number of iterated keccak256 rounds applied to the hashed passphrase.
+    #[arg(long, default_value_t = DEFAULT_BRAIN_ROUNDS)]
+    rounds: u64,
+}
+
+#[derive(Clone, Debug, Args)]
+struct RecoverArgs {
+    /// The Ethereum address to recover the key for.
+    #[arg(long, value_parser = recall_provider::util::parse_address)]
+    address: Address,
+    /// Passphrase the key was derived from.
+    #[arg(long)]
+    passphrase: String,
+    /// Round count to center the search on.
+    #[arg(long, default_value_t = DEFAULT_BRAIN_ROUNDS)]
+    rounds: u64,
+    /// Number of round counts to try on either side of `--rounds`.
+    #[arg(long, default_value_t = 1_000)]
+    search: u64,
+}
+
+/// Key commands handler. Unlike the other `handle_*` functions, this never touches the network,
+/// so it doesn't take a [`recall_sdk::network::NetworkConfig`].
+pub fn handle_key(args: &KeyArgs) -> anyhow::Result<()> {
+    match &args.command {
+        KeyCommands::Generate(args) => generate(args),
+        KeyCommands::Inspect(args) => inspect(args),
+        KeyCommands::Brain(args) => brain(args),
+        KeyCommands::Recover(args) => recover(args),
+    }
+}
+
+fn generate(args: &GenerateArgs) -> anyhow::Result<()> {
+    let prefix = args
+        .prefix
+        .as_deref()
+        .map(|p| p.trim_start_matches("0x").to_lowercase());
+
+    let mut attempts: u64 = 0;
+    let (sk, address, eth_address) = loop {
+        attempts += 1;
+        let sk = random_secretkey();
+        let (address, eth_address) = derive_address(&sk)?;
+        let matches = match &prefix {
+            Some(prefix) => format!("{eth_address:x}").starts_with(prefix.as_str()),
+            None => true,
+        };
+        if matches {
+            break (sk, address, eth_address);
+        }
+    };
+
+    if let Some(path) = &args.keystore {
+        let password = resolve_keystore_password(args.keystore_password.as_deref())?;
+        save_keystore(&sk, path, &password)?;
+        return print_json(&json!({
+            "keystore": path,
+            "address": eth_address,
+            "fvm_address": address.to_string(),
+            "attempts": attempts,
+        }));
+    }
+
+    print_json(&json!({
+        "private_key": hex::encode(sk.serialize()),
+        "public_key": hex::encode(sk.public_key().serialize()),
+        "address": eth_address,
+        "fvm_address": address.to_string(),
+        "attempts": attempts,
+    }))
+}
+
+fn inspect(args: &InspectArgs) -> anyhow::Result<()> {
+    let sk = args.key_source.resolve()?;
+    let (address, eth_address) = derive_address(&sk)?;
+    print_json(&json!({
+        "public_key": hex::encode(sk.public_key().serialize()),
+        "address": eth_address,
+        "fvm_address": address.to_string(),
+    }))
+}
+
+fn brain(args: &BrainArgs) -> anyhow::Result<()> {
+    let sk = derive_brain_key(&args.passphrase, args.rounds)?;
+    let (address, eth_address) = derive_address(&sk)?;
+    print_json(&json!({
+        "rounds": args.rounds,
+        "private_key": hex::encode(sk.serialize()),
+        "public_key": hex::encode(sk.public_key().serialize()),
+        "address": eth_address,
+        "fvm_address": address.to_string(),
+    }))
+}
+
+fn recover(args: &RecoverArgs) -> anyhow::Result<()> {
+    let target = get_eth_address(args.address)?;
+    let lo = args.rounds.saturating_sub(args.search);
+    let hi = args.rounds.saturating_add(args.search);
+
+    for rounds in lo..=hi {
+        let Ok(sk) = derive_brain_key(&args.passphrase, rounds) else {
+            continue;
+        };
+        let (address, eth_address) = derive_address(&sk)?;
+        if eth_address == target {
+            return print_json(&json!({
+                "rounds": rounds,
+                "private_key": hex::encode(sk.serialize()),
+                "address": eth_address,
+                "fvm_address": address.to_string(),
+            }));
+        }
+    }
+
+    Err(anyhow!(
+        "no key derived from the given passphrase over rounds {lo}..={hi} matches address {target:?}"
+    ))
+}
+
+/// Derives the FVM and Ethereum-style addresses for a secp256k1 secret key, the same way
+/// [`recall_signer::Wallet::new_secp256k1`] does internally.
+fn derive_address(sk: &SecretKey) -> anyhow::Result<(Address, ethers::types::Address)> {
+    let pk = sk.public_key().serialize();
+    let address = Address::from(EthAddress::new_secp256k1(&pk)?);
+    let eth_address = get_eth_address(address)?;
+    Ok((address, eth_address))
+}
+
+/// Derives a secp256k1 key from `passphrase` by hashing it with keccak256, then re-hashing the
+/// digest `rounds` more times and reducing the final 32 bytes into a secret key scalar.
+fn derive_brain_key(passphrase: &str, rounds: u64) -> anyhow::Result<SecretKey> {
+    let mut digest = Keccak256::digest(passphrase.as_bytes()).to_vec();
+    for _ in 0..rounds {
+        digest = Keccak256::digest(&digest).to_vec();
+    }
+    SecretKey::try_from(digest).map_err(|e| {
+        anyhow!("derived brain-wallet hash at {rounds} rounds is not a valid secp256k1 scalar (try a neighboring --rounds value): {e}")
+    })
+}
+
+fn resolve_keystore_password(given: Option<&str>) -> anyhow::Result<String> {
+    match given {
+        Some(p) => Ok(p.to_string()),
+        None => Ok(rpassword::prompt_password("Keystore passphrase: ")?),
+    }
+}
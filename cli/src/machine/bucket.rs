@@ -1,18 +1,25 @@
 // Copyright 2025 Recall Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, HashSet};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
 
+use anyhow::anyhow;
 use clap::{Args, Parser, Subcommand};
 use ethers::utils::hex::ToHexExt;
+use futures::stream::{self, StreamExt};
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use tokio::io::{self};
 
 use recall_provider::{
     fvm_shared::{address::Address, clock::ChainEpoch, econ::TokenAmount},
-    json_rpc::{JsonRpcProvider, Url},
-    query::FvmQueryHeight,
+    json_rpc::{JsonRpcProvider, RpcClient, Url},
+    object::DEFAULT_CHUNK_SIZE,
+    query::{FvmQueryHeight, QueryProvider},
     tx::TxStatus,
     util::{
         get_eth_address, parse_address, parse_metadata, parse_metadata_optional,
@@ -21,9 +28,15 @@ use recall_provider::{
 };
 use recall_sdk::machine::bucket::validate_metadata;
 use recall_sdk::{
+    encryption::{
+        decryptor::{ciphertext_range_for, decrypt_range_writer},
+        kms::HttpKeyManagementService,
+        object::{size_encrypted, EncryptedObjectExt},
+        sse_c, sse_kms, CipherSuite as SdkCipherSuite,
+    },
     machine::{
         bucket::{
-            AddOptions, Bucket, DeleteOptions, GetOptions, ObjectState, QueryOptions,
+            hash, AddOptions, Bucket, DeleteOptions, GetOptions, ObjectState, QueryOptions,
             UpdateObjectMetadataOptions,
         },
         Machine,
@@ -31,13 +44,13 @@ use recall_sdk::{
     network::NetworkConfig,
     TxParams,
 };
-use recall_signer::{
-    key::{parse_secret_key, SecretKey},
-    AccountKind, Signer, Void, Wallet,
-};
+use recall_signer::{AccountKind, Signer, Void, Wallet};
 
+use crate::{
+    get_address, print_json, print_tx_json, AddressArgs, BroadcastMode, KeySourceArgs, TxArgs,
+};
 
-use crate::{get_address, print_json, print_tx_json, AddressArgs, BroadcastMode, TxArgs};
+mod serve;
 
 #[derive(Clone, Debug, Args)]
 pub struct BucketArgs {
@@ -54,21 +67,33 @@ enum BucketCommands {
     List(AddressArgs),
     /// Add an object with a key prefix.
     Add(BucketAddArgs),
+    /// Copy an object to a new key without re-uploading its bytes.
+    Copy(BucketCopyArgs),
+    /// Move an object to a new key without re-uploading its bytes.
+    #[clap(alias = "mv")]
+    Rename(BucketCopyArgs),
     /// Delete an object.
     Delete(BucketDeleteArgs),
+    /// Delete many objects in a single call.
+    #[clap(alias = "rm")]
+    DeleteMany(BucketDeleteManyArgs),
     /// Get an object.
     Get(BucketGetArgs),
     /// Query for objects.
     Query(BucketQueryArgs),
     /// Metadata for objects.
     Metadata(BucketMetadataArgs),
+    /// Mirror a local directory to a bucket under a key prefix.
+    Sync(BucketSyncArgs),
+    /// Serve a bucket over an S3-compatible HTTP gateway.
+    Serve(BucketServeArgs),
 }
 
 #[derive(Clone, Debug, Args)]
 struct BucketCreateArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Bucket owner address.
     /// The owner defaults to the signer if not specified.
     #[arg(short, long, value_parser = parse_address)]
@@ -79,6 +104,11 @@ struct BucketCreateArgs {
     /// User-defined metadata.
     #[arg(short, long, value_parser = parse_metadata)]
     metadata: Vec<(String, String)>,
+    /// Deploy idempotently: if this signer already created a bucket with the same salt, attach
+    /// to it instead of deploying a new one, so re-running the same command after losing track
+    /// of the address recovers it instead of creating a duplicate bucket.
+    #[arg(long)]
+    salt: Option<String>,
     #[command(flatten)]
     tx_args: TxArgs,
 }
@@ -86,8 +116,8 @@ struct BucketCreateArgs {
 #[derive(Clone, Debug, Parser)]
 struct BucketAddArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Node Object API URL.
     #[arg(long, env = "RECALL_OBJECT_API_URL")]
     object_api_url: Option<Url>,
@@ -113,6 +143,35 @@ struct BucketAddArgs {
     /// Amount of tokens to use for inline buying of credits
     #[arg(long, value_parser = parse_token_amount)]
     token_amount: Option<TokenAmount>,
+    /// Upload in fixed-size, content-addressed parts and checkpoint progress to a sidecar
+    /// manifest, so a connection drop partway through a large upload can resume instead of
+    /// restarting from byte zero. Not supported together with `--sse-c`/`--sse-kms`.
+    #[arg(long, conflicts_with_all = ["sse_c", "sse_c_passphrase", "sse_kms"])]
+    resumable: bool,
+    /// Directory to store the resumable upload's checkpoint manifest in. Required when
+    /// `--resumable` is set.
+    #[arg(long, requires = "resumable")]
+    checkpoint_dir: Option<PathBuf>,
+    /// Part size in bytes for `--resumable` uploads.
+    #[arg(long, default_value_t = DEFAULT_CHUNK_SIZE)]
+    chunk_size: u64,
+    /// Encrypt the object with SSE-C, using this base64-encoded customer key.
+    #[arg(long, conflicts_with_all = ["sse_kms", "sse_c_passphrase"])]
+    sse_c: Option<String>,
+    /// Encrypt the object with SSE-C, deriving the customer key from this passphrase via
+    /// Argon2id. The salt and KDF parameters are stored in the object's metadata, so `bucket get`
+    /// only needs the same passphrase to decrypt it.
+    #[arg(long, conflicts_with_all = ["sse_kms", "sse_c"])]
+    sse_c_passphrase: Option<String>,
+    /// Encrypt the object with SSE-KMS, using this key ID to request a data key from the KMS.
+    #[arg(long, conflicts_with = "sse_c")]
+    sse_kms: Option<String>,
+    /// SSE-KMS key-server URL. Required when `--sse-kms` is set.
+    #[arg(long, env = "RECALL_KMS_URL")]
+    kms_url: Option<Url>,
+    /// Content cipher suite to use when `--sse-c` or `--sse-kms` is set. Has no effect otherwise.
+    #[arg(long, value_enum, default_value_t = CipherSuite::Aes256Gcm)]
+    cipher_suite: CipherSuite,
     /// Broadcast mode for the transaction.
     #[arg(short, long, value_enum, env = "RECALL_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
     broadcast_mode: BroadcastMode,
@@ -120,11 +179,30 @@ struct BucketAddArgs {
     tx_args: TxArgs,
 }
 
+/// Content cipher suite for an encrypted bucket upload.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+enum CipherSuite {
+    /// AES-256-GCM. Fast on CPUs with AES hardware acceleration (most server/desktop targets).
+    Aes256Gcm,
+    /// ChaCha20-Poly1305. Faster than AES-GCM in software, e.g. on ARM targets without AES-NI.
+    ChaCha20Poly1305,
+}
+
+impl CipherSuite {
+    /// Converts to the SDK cipher suite.
+    pub fn get(&self) -> SdkCipherSuite {
+        match self {
+            CipherSuite::Aes256Gcm => SdkCipherSuite::AES256GCM,
+            CipherSuite::ChaCha20Poly1305 => SdkCipherSuite::ChaCha20Poly1305,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Parser)]
 struct BucketDeleteArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Bucket machine address.
     #[arg(short, long, value_parser = parse_address)]
     address: Address,
@@ -137,6 +215,56 @@ struct BucketDeleteArgs {
     tx_args: TxArgs,
 }
 
+#[derive(Clone, Debug, Parser)]
+struct BucketDeleteManyArgs {
+    /// Wallet private key (ECDSA, secp256k1) for signing transactions.
+    #[command(flatten)]
+    key_source: KeySourceArgs,
+    /// Bucket machine address.
+    #[arg(short, long, value_parser = parse_address)]
+    address: Address,
+    /// Keys of the objects to delete.
+    #[arg(required = true)]
+    keys: Vec<String>,
+    /// Broadcast mode for the transaction.
+    #[arg(short, long, value_enum, env = "RECALL_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
+    broadcast_mode: BroadcastMode,
+    #[command(flatten)]
+    tx_args: TxArgs,
+}
+
+#[derive(Clone, Debug, Parser)]
+struct BucketCopyArgs {
+    /// Wallet private key (ECDSA, secp256k1) for signing transactions.
+    #[command(flatten)]
+    key_source: KeySourceArgs,
+    /// Node Object API URL.
+    #[arg(long, env = "RECALL_OBJECT_API_URL")]
+    object_api_url: Option<Url>,
+    /// Bucket machine address.
+    #[arg(short, long, value_parser = parse_address)]
+    address: Address,
+    /// Key of the object to copy.
+    src_key: String,
+    /// Key to copy the object to.
+    dst_key: String,
+    /// Overwrite the destination object if it already exists.
+    #[arg(short, long)]
+    overwrite: bool,
+    /// Object time-to-live (TTL) duration for the destination object. If not specified, the
+    /// source object's TTL carries over.
+    #[arg(long)]
+    ttl: Option<ChainEpoch>,
+    /// Additional user-defined metadata, merged over the source object's metadata.
+    #[arg(short, long, value_parser = parse_metadata)]
+    metadata: Vec<(String, String)>,
+    /// Broadcast mode for the transaction.
+    #[arg(short, long, value_enum, env = "RECALL_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
+    broadcast_mode: BroadcastMode,
+    #[command(flatten)]
+    tx_args: TxArgs,
+}
+
 #[derive(Clone, Debug, Args)]
 struct BucketAddressArgs {
     /// Bucket machine address.
@@ -173,6 +301,29 @@ struct BucketGetArgs {
     /// or a specific block height, e.g., "123".
     #[arg(long, value_parser = parse_query_height, default_value = "committed")]
     height: FvmQueryHeight,
+    /// Base64-encoded customer key to decrypt an SSE-C encrypted object.
+    #[arg(long, conflicts_with = "sse_c_passphrase")]
+    sse_c: Option<String>,
+    /// Passphrase to decrypt an SSE-C encrypted object that was encrypted with
+    /// `--sse-c-passphrase`.
+    #[arg(long, conflicts_with = "sse_c")]
+    sse_c_passphrase: Option<String>,
+    /// SSE-KMS key-server URL, to decrypt an SSE-KMS encrypted object.
+    #[arg(long, env = "RECALL_KMS_URL")]
+    kms_url: Option<Url>,
+    /// Write the object to this file instead of stdout. Required for `--concurrency`.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Split the download into this many concurrent range requests, each writing directly to its
+    /// offset in `--output`. Progress is tracked in a sidecar manifest next to `--output`, so a
+    /// re-run with the same `--output` resumes only the chunks that didn't finish last time.
+    #[arg(long, default_value_t = 1)]
+    concurrency: usize,
+    /// Skip verifying the downloaded bytes against the object's recorded BLAKE3 hash. Ranged
+    /// requests (`--range`) only ever verify the byte count, never the full-object hash, since a
+    /// partial object can't reproduce it.
+    #[arg(long)]
+    no_verify: bool,
 }
 
 #[derive(Clone, Debug, Args)]
@@ -199,13 +350,29 @@ struct BucketQueryArgs {
     /// or a specific block height, e.g., "123".
     #[arg(long, value_parser = parse_query_height, default_value = "committed")]
     height: FvmQueryHeight,
+    /// Follow `next_key` and keep querying until the bucket is exhausted, instead of returning
+    /// just the first page.
+    #[arg(long)]
+    all: bool,
+    /// Output format.
+    #[arg(long, value_enum, default_value_t = QueryFormat::Json)]
+    format: QueryFormat,
+}
+
+/// Output format for [`BucketCommands::Query`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
+enum QueryFormat {
+    /// A single JSON object with `objects`, `common_prefixes`, and `next_key`.
+    Json,
+    /// One JSON object per line, streamed to stdout as pages arrive.
+    Ndjson,
 }
 
 #[derive(Clone, Debug, Args)]
 struct BucketMetadataArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Bucket machine address.
     #[arg(short, long, value_parser = parse_address)]
     address: Address,
@@ -222,6 +389,56 @@ struct BucketMetadataArgs {
     tx_args: TxArgs,
 }
 
+#[derive(Clone, Debug, Parser)]
+struct BucketSyncArgs {
+    /// Wallet private key (ECDSA, secp256k1) for signing transactions.
+    #[command(flatten)]
+    key_source: KeySourceArgs,
+    /// Node Object API URL.
+    #[arg(long, env = "RECALL_OBJECT_API_URL")]
+    object_api_url: Option<Url>,
+    /// Bucket machine address.
+    #[arg(short, long, value_parser = parse_address)]
+    address: Address,
+    /// Local directory to mirror into the bucket.
+    local_dir: PathBuf,
+    /// Key prefix under which the local directory is mirrored.
+    #[arg(long, default_value = "")]
+    prefix: String,
+    /// Delete remote keys under the prefix that no longer exist locally.
+    #[arg(long)]
+    delete: bool,
+    /// Maximum number of in-flight upload transactions.
+    #[arg(long, default_value_t = 4)]
+    concurrency: usize,
+    /// Broadcast mode for the transaction.
+    #[arg(short, long, value_enum, env = "RECALL_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
+    broadcast_mode: BroadcastMode,
+    #[command(flatten)]
+    tx_args: TxArgs,
+}
+
+#[derive(Clone, Debug, Parser)]
+struct BucketServeArgs {
+    /// Wallet private key (ECDSA, secp256k1) for signing transactions.
+    #[command(flatten)]
+    key_source: KeySourceArgs,
+    /// Node Object API URL.
+    #[arg(long, env = "RECALL_OBJECT_API_URL")]
+    object_api_url: Option<Url>,
+    /// Bucket machine address.
+    #[arg(short, long, value_parser = parse_address)]
+    address: Address,
+    /// Address to listen on for the S3-compatible HTTP gateway.
+    #[arg(long, default_value = "127.0.0.1:8014")]
+    listen: SocketAddr,
+    /// Broadcast mode for write transactions (PutObject/DeleteObject).
+    #[arg(short, long, value_enum, env = "RECALL_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
+    broadcast_mode: BroadcastMode,
+    #[command(flatten)]
+    tx_args: TxArgs,
+}
+
 /// Bucket commands handler.
 pub async fn handle_bucket(
     cfg: NetworkConfig,
@@ -231,15 +448,15 @@ pub async fn handle_bucket(
     match &args.command {
         BucketCommands::Create(args) => {
             let provider =
-                JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+                JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
 
             let TxParams {
                 sequence,
                 gas_params,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
 
             let mut signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+                args.key_source.resolve()?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
@@ -252,20 +469,35 @@ pub async fn handle_bucket(
 
             validate_metadata(&metadata)?;
 
-            let (store, tx) =
-                Bucket::new(&provider, &mut signer, args.owner, metadata, gas_params).await?;
+            let (store, tx) = match &args.salt {
+                Some(salt) => {
+                    Bucket::new_deterministic(
+                        &provider, &mut signer, args.owner, metadata, salt, gas_params,
+                    )
+                    .await?
+                }
+                None => {
+                    let (store, tx) =
+                        Bucket::new(&provider, &mut signer, args.owner, metadata, gas_params)
+                            .await?;
+                    (store, Some(tx))
+                }
+            };
             let address = store.eth_address()?;
 
-            let tx_json = match &tx.status {
-                TxStatus::Pending(tx) => serde_json::to_value(tx)?,
-                TxStatus::Committed(receipt) => serde_json::to_value(receipt)?,
+            let tx_json = match &tx {
+                Some(tx) => match &tx.status {
+                    TxStatus::Pending(tx) => serde_json::to_value(tx)?,
+                    TxStatus::Committed(receipt) => serde_json::to_value(receipt)?,
+                },
+                None => json!({"reused": true}),
             };
 
             print_json(&json!({"address": address.encode_hex_with_prefix(), "tx": &tx_json}))
         }
         BucketCommands::List(args) => {
             let provider =
-                JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+                JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
 
             let address = get_address(args.clone(), &cfg.subnet_id)?;
             let metadata = Bucket::list(&provider, &Void::new(address), args.height).await?;
@@ -282,22 +514,22 @@ pub async fn handle_bucket(
         }
         BucketCommands::Add(args) => {
             let object_api_url = args.object_api_url.clone().unwrap_or(cfg.object_api_url);
-            let provider = JsonRpcProvider::new_http(
+            let provider = JsonRpcProvider::new_auto(
                 cfg.rpc_url,
                 cfg.subnet_id.chain_id(),
                 None,
                 Some(object_api_url),
             )?;
 
-            let broadcast_mode = args.broadcast_mode.get();
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
             let TxParams {
                 sequence,
                 gas_params,
-            } = args.tx_args.to_tx_params();
-            let metadata: HashMap<String, String> = args.metadata.clone().into_iter().collect();
+            } = args.tx_args.to_tx_params()?;
+            let mut metadata: HashMap<String, String> = args.metadata.clone().into_iter().collect();
 
             let mut signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+                args.key_source.resolve()?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
@@ -306,21 +538,245 @@ pub async fn handle_bucket(
             let machine = Bucket::attach(args.address).await?;
             let token_amount = args.token_amount.clone();
             let from = signer.address();
+
+            let tx = if args.sse_c.is_some() || args.sse_c_passphrase.is_some() {
+                let key = match (&args.sse_c, &args.sse_c_passphrase) {
+                    (Some(key), _) => sse_c::SseCKey::Key(key),
+                    (_, Some(passphrase)) => sse_c::SseCKey::Passphrase(passphrase),
+                    (None, None) => unreachable!("checked by the outer condition"),
+                };
+
+                let plaintext_size = tokio::fs::metadata(&args.input).await?.len();
+                let file = tokio::fs::File::open(&args.input).await?;
+                let (reader, enc_metadata) =
+                    sse_c::encrypt_reader(file, key, &args.key, args.cipher_suite.get())?;
+                metadata.extend(enc_metadata);
+                metadata
+                    .entry("content-type".into())
+                    .or_insert_with(|| "application/octet-stream".into());
+
+                machine
+                    .add_reader(
+                        &provider,
+                        &mut signer,
+                        from,
+                        &args.key,
+                        reader,
+                        size_encrypted(plaintext_size),
+                        AddOptions {
+                            ttl: args.ttl,
+                            metadata,
+                            overwrite: args.overwrite,
+                            token_amount,
+                            broadcast_mode,
+                            gas_params,
+                            encryption: None,
+                            show_progress,
+                        },
+                    )
+                    .await?
+            } else if let Some(key_id) = &args.sse_kms {
+                let kms_url = args
+                    .kms_url
+                    .clone()
+                    .ok_or_else(|| anyhow!("--kms-url is required when --sse-kms is set"))?;
+                let kms = HttpKeyManagementService::new(kms_url, Some(key_id.clone()));
+
+                let plaintext_size = tokio::fs::metadata(&args.input).await?.len();
+                let file = tokio::fs::File::open(&args.input).await?;
+                let (reader, enc_metadata) =
+                    sse_kms::encrypt_reader(file, &kms, &args.key, args.cipher_suite.get()).await?;
+                metadata.extend(enc_metadata);
+                metadata
+                    .entry("content-type".into())
+                    .or_insert_with(|| "application/octet-stream".into());
+
+                machine
+                    .add_reader(
+                        &provider,
+                        &mut signer,
+                        from,
+                        &args.key,
+                        reader,
+                        size_encrypted(plaintext_size),
+                        AddOptions {
+                            ttl: args.ttl,
+                            metadata,
+                            overwrite: args.overwrite,
+                            token_amount,
+                            broadcast_mode,
+                            gas_params,
+                            encryption: None,
+                            show_progress,
+                        },
+                    )
+                    .await?
+            } else if args.resumable {
+                let checkpoint_dir = args
+                    .checkpoint_dir
+                    .clone()
+                    .ok_or_else(|| anyhow!("--checkpoint-dir is required when --resumable is set"))?;
+                machine
+                    .add_from_path_resumable(
+                        &provider,
+                        &mut signer,
+                        from,
+                        &args.key,
+                        &args.input,
+                        checkpoint_dir,
+                        args.chunk_size,
+                        AddOptions {
+                            ttl: args.ttl,
+                            metadata,
+                            overwrite: args.overwrite,
+                            token_amount,
+                            broadcast_mode,
+                            gas_params,
+                            encryption: None,
+                            show_progress,
+                        },
+                    )
+                    .await?
+            } else {
+                machine
+                    .add_from_path(
+                        &provider,
+                        &mut signer,
+                        from,
+                        &args.key,
+                        &args.input,
+                        AddOptions {
+                            ttl: args.ttl,
+                            metadata,
+                            overwrite: args.overwrite,
+                            token_amount,
+                            broadcast_mode,
+                            gas_params,
+                            encryption: None,
+                            show_progress,
+                        },
+                    )
+                    .await?
+            };
+
+            print_tx_json(&tx)
+        }
+        BucketCommands::Copy(args) => {
+            let object_api_url = args.object_api_url.clone().unwrap_or(cfg.object_api_url);
+            let provider = JsonRpcProvider::new_auto(
+                cfg.rpc_url,
+                cfg.subnet_id.chain_id(),
+                None,
+                Some(object_api_url),
+            )?;
+
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
+            let TxParams {
+                sequence,
+                gas_params,
+            } = args.tx_args.to_tx_params()?;
+
+            let mut signer = Wallet::new_secp256k1(
+                args.key_source.resolve()?,
+                AccountKind::Ethereum,
+                cfg.subnet_id,
+            )?;
+            signer.set_sequence(sequence, &provider).await?;
+
+            let machine = Bucket::attach(args.address).await?;
+            let from = signer.address();
             let tx = machine
-                .add_from_path(
+                .copy(
                     &provider,
                     &mut signer,
                     from,
-                    &args.key,
-                    &args.input,
+                    &args.src_key,
+                    &args.dst_key,
+                    AddOptions {
+                        ttl: args.ttl,
+                        metadata: args.metadata.clone().into_iter().collect(),
+                        overwrite: args.overwrite,
+                        broadcast_mode,
+                        gas_params,
+                        ..Default::default()
+                    },
+                )
+                .await?;
+
+            print_tx_json(&tx)
+        }
+        BucketCommands::Rename(args) => {
+            let object_api_url = args.object_api_url.clone().unwrap_or(cfg.object_api_url);
+            let provider = JsonRpcProvider::new_auto(
+                cfg.rpc_url,
+                cfg.subnet_id.chain_id(),
+                None,
+                Some(object_api_url),
+            )?;
+
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
+            let TxParams {
+                sequence,
+                gas_params,
+            } = args.tx_args.to_tx_params()?;
+
+            let mut signer = Wallet::new_secp256k1(
+                args.key_source.resolve()?,
+                AccountKind::Ethereum,
+                cfg.subnet_id,
+            )?;
+            signer.set_sequence(sequence, &provider).await?;
+
+            let machine = Bucket::attach(args.address).await?;
+            let from = signer.address();
+            let tx = machine
+                .rename(
+                    &provider,
+                    &mut signer,
+                    from,
+                    &args.src_key,
+                    &args.dst_key,
                     AddOptions {
                         ttl: args.ttl,
-                        metadata,
+                        metadata: args.metadata.clone().into_iter().collect(),
                         overwrite: args.overwrite,
-                        token_amount,
                         broadcast_mode,
                         gas_params,
-                        show_progress,
+                        ..Default::default()
+                    },
+                )
+                .await?;
+
+            print_tx_json(&tx)
+        }
+        BucketCommands::DeleteMany(args) => {
+            let provider =
+                JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
+            let TxParams {
+                sequence,
+                gas_params,
+            } = args.tx_args.to_tx_params()?;
+
+            let mut signer = Wallet::new_secp256k1(
+                args.key_source.resolve()?,
+                AccountKind::Ethereum,
+                cfg.subnet_id,
+            )?;
+            signer.set_sequence(sequence, &provider).await?;
+
+            let machine = Bucket::attach(args.address).await?;
+            let from = signer.address();
+            let tx = machine
+                .delete_many(
+                    &provider,
+                    &mut signer,
+                    from,
+                    &args.keys,
+                    DeleteOptions {
+                        broadcast_mode,
+                        gas_params,
                     },
                 )
                 .await?;
@@ -329,16 +785,16 @@ pub async fn handle_bucket(
         }
         BucketCommands::Delete(args) => {
             let provider =
-                JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+                JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
 
-            let broadcast_mode = args.broadcast_mode.get();
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
             let TxParams {
                 sequence,
                 gas_params,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
 
             let mut signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+                args.key_source.resolve()?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
@@ -363,7 +819,7 @@ pub async fn handle_bucket(
         }
         BucketCommands::Get(args) => {
             let object_api_url = args.object_api_url.clone().unwrap_or(cfg.object_api_url);
-            let provider = JsonRpcProvider::new_http(
+            let provider = JsonRpcProvider::new_auto(
                 cfg.rpc_url,
                 cfg.subnet_id.chain_id(),
                 None,
@@ -371,6 +827,96 @@ pub async fn handle_bucket(
             )?;
 
             let machine = Bucket::attach(args.address).await?;
+
+            let decryption_key = if args.sse_c.is_some()
+                || args.sse_c_passphrase.is_some()
+                || args.kms_url.is_some()
+            {
+                let object = machine.head(&provider, &args.key, args.height).await?;
+                if object.is_sse_c() {
+                    let sealed_object_key = object.sealed_object_key()?;
+                    let object_key = if let Some(kdf_params) = object.kdf_params()? {
+                        let passphrase = args.sse_c_passphrase.clone().ok_or_else(|| {
+                            anyhow!(
+                                "object is SSE-C encrypted with a passphrase; pass --sse-c-passphrase to decrypt it"
+                            )
+                        })?;
+                        sealed_object_key.unseal_with_passphrase(
+                            &passphrase,
+                            &kdf_params,
+                            &args.key,
+                        )?
+                    } else {
+                        let sse_c_key = args.sse_c.clone().ok_or_else(|| {
+                            anyhow!("object is SSE-C encrypted; pass --sse-c to decrypt it")
+                        })?;
+                        sealed_object_key.unseal(sse_c_key, &args.key)?
+                    };
+                    Some(object_key.key)
+                } else if object.is_sse_kms() {
+                    let kms_url = args.kms_url.clone().ok_or_else(|| {
+                        anyhow!("object is SSE-KMS encrypted; pass --kms-url to decrypt it")
+                    })?;
+                    let kms = HttpKeyManagementService::new(kms_url, None);
+                    let sealed_key = object.sse_kms_sealed_key()?;
+                    Some(sse_kms::decrypt_key(&kms, &sealed_key, &args.key).await?)
+                } else {
+                    None
+                }
+            } else {
+                None
+            };
+
+            if let Some(output) = &args.output {
+                if args.concurrency > 1 && args.range.is_none() {
+                    let object = machine.head(&provider, &args.key, args.height).await?;
+                    return download_concurrent(
+                        &provider,
+                        &machine,
+                        &args.key,
+                        output,
+                        args.height,
+                        decryption_key,
+                        object.size_decrypted(),
+                        object.size,
+                        args.concurrency,
+                    )
+                    .await;
+                }
+
+                // Written to `<output>.partial` and renamed into place only once verification
+                // (if enabled) succeeds, so a failed hash/size check never leaves corrupted or
+                // substituted bytes sitting at `output` for a caller that doesn't check the exit
+                // code to stumble onto.
+                let partial_path = download_partial_path(output);
+                let file = tokio::fs::File::create(&partial_path).await?;
+                let result = machine
+                    .get(
+                        &provider,
+                        &args.key,
+                        file,
+                        GetOptions {
+                            range: args.range.clone(),
+                            height: args.height,
+                            decryption_key,
+                            verify: !args.no_verify,
+                            show_progress: true,
+                            parallelism: 1,
+                        },
+                    )
+                    .await;
+                return match result {
+                    Ok(_) => {
+                        tokio::fs::rename(&partial_path, output).await?;
+                        Ok(())
+                    }
+                    Err(e) => {
+                        let _ = tokio::fs::remove_file(&partial_path).await;
+                        Err(e)
+                    }
+                };
+            }
+
             machine
                 .get(
                     &provider,
@@ -379,46 +925,89 @@ pub async fn handle_bucket(
                     GetOptions {
                         range: args.range.clone(),
                         height: args.height,
+                        decryption_key,
+                        verify: !args.no_verify,
                         show_progress: true,
+                        parallelism: 1,
                     },
                 )
                 .await
+                .map(|_| ())
         }
         BucketCommands::Query(args) => {
             let provider =
-                JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+                JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
 
             let machine = Bucket::attach(args.address).await?;
-            let list = machine
-                .query(
-                    &provider,
-                    QueryOptions {
-                        prefix: args.prefix.clone(),
-                        delimiter: args.delimiter.clone(),
-                        start_key: args.start_key.clone().map(|key| key.into_bytes()),
-                        limit: args.limit,
-                        height: args.height,
-                    },
-                )
-                .await?;
 
-            let objects = list
-                .objects
-                .iter()
-                .map(|(key_bytes, object)| {
+            if args.format == QueryFormat::Ndjson {
+                let mut start_key = args.start_key.clone().map(|key| key.into_bytes());
+                loop {
+                    let list = machine
+                        .query(
+                            &provider,
+                            QueryOptions {
+                                prefix: args.prefix.clone(),
+                                delimiter: args.delimiter.clone(),
+                                start_key: start_key.clone(),
+                                limit: args.limit,
+                                height: args.height,
+                            },
+                        )
+                        .await?;
+
+                    for (key_bytes, object) in &list.objects {
+                        let key = core::str::from_utf8(key_bytes).unwrap_or_default();
+                        println!(
+                            "{}",
+                            json!({"key": key, "value": object_state_to_json(object)})
+                        );
+                    }
+
+                    if !args.all || list.next_key.is_none() {
+                        break;
+                    }
+                    start_key = list.next_key;
+                }
+                return Ok(());
+            }
+
+            let mut objects = Vec::new();
+            let mut common_prefixes = Vec::new();
+            let mut start_key = args.start_key.clone().map(|key| key.into_bytes());
+            let mut next_key;
+            loop {
+                let list = machine
+                    .query(
+                        &provider,
+                        QueryOptions {
+                            prefix: args.prefix.clone(),
+                            delimiter: args.delimiter.clone(),
+                            start_key: start_key.clone(),
+                            limit: args.limit,
+                            height: args.height,
+                        },
+                    )
+                    .await?;
+
+                objects.extend(list.objects.iter().map(|(key_bytes, object)| {
                     let key = core::str::from_utf8(key_bytes)
                         .unwrap_or_default()
                         .to_string();
                     json!({"key": key, "value": object_state_to_json(object)})
-                })
-                .collect::<Vec<Value>>();
-            let common_prefixes = list
-                .common_prefixes
-                .iter()
-                .map(|v| Value::String(core::str::from_utf8(v).unwrap_or_default().to_string()))
-                .collect::<Vec<Value>>();
+                }));
+                common_prefixes.extend(list.common_prefixes.iter().map(|v| {
+                    Value::String(core::str::from_utf8(v).unwrap_or_default().to_string())
+                }));
 
-            let next_key = match list.next_key {
+                next_key = list.next_key;
+                if !args.all || next_key.is_none() {
+                    break;
+                }
+                start_key = next_key.clone();
+            }
+
+            let next_key = match next_key {
                 Some(key) => {
                     Value::String(core::str::from_utf8(&key).unwrap_or_default().to_string())
                 }
@@ -431,16 +1020,16 @@ pub async fn handle_bucket(
         }
         BucketCommands::Metadata(args) => {
             let provider =
-                JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+                JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
 
-            let broadcast_mode = args.broadcast_mode.get();
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
             let TxParams {
                 sequence,
                 gas_params,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
 
             let mut signer = Wallet::new_secp256k1(
-                args.private_key.clone(),
+                args.key_source.resolve()?,
                 AccountKind::Ethereum,
                 cfg.subnet_id,
             )?;
@@ -467,9 +1056,407 @@ pub async fn handle_bucket(
 
             print_tx_json(&tx)
         }
+        BucketCommands::Sync(args) => {
+            let object_api_url = args.object_api_url.clone().unwrap_or(cfg.object_api_url);
+            let provider = JsonRpcProvider::new_auto(
+                cfg.rpc_url,
+                cfg.subnet_id.chain_id(),
+                None,
+                Some(object_api_url),
+            )?;
+
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
+            let TxParams {
+                sequence,
+                gas_params,
+            } = args.tx_args.to_tx_params()?;
+
+            let mut signer = Wallet::new_secp256k1(
+                args.key_source.resolve()?,
+                AccountKind::Ethereum,
+                cfg.subnet_id,
+            )?;
+            signer.set_sequence(sequence, &provider).await?;
+
+            let machine = Bucket::attach(args.address).await?;
+
+            let local_files = walk_dir(&args.local_dir).await?;
+            let remote = query_all(&machine, &provider, &args.prefix).await?;
+
+            let mut to_upload = Vec::new();
+            let mut local_keys = HashSet::new();
+            for (path, rel_key) in &local_files {
+                let key = format!("{}{}", args.prefix, rel_key);
+                local_keys.insert(key.clone());
+
+                let data = tokio::fs::read(path).await?;
+                if remote.get(&key).map(|o| o.hash.clone()) != Some(hash(&data)) {
+                    to_upload.push((path.clone(), key));
+                }
+            }
+            let skipped = local_files.len() - to_upload.len();
+
+            let uploaded = stream::iter(to_upload.into_iter().map(|(path, key)| {
+                let provider = &provider;
+                let machine = &machine;
+                let gas_params = gas_params.clone();
+                let mut signer = signer.clone();
+                async move {
+                    let from = signer.address();
+                    machine
+                        .add_from_path(
+                            provider,
+                            &mut signer,
+                            from,
+                            &key,
+                            &path,
+                            AddOptions {
+                                overwrite: true,
+                                broadcast_mode,
+                                gas_params,
+                                show_progress,
+                                ..Default::default()
+                            },
+                        )
+                        .await?;
+                    Ok::<String, anyhow::Error>(key)
+                }
+            }))
+            .buffer_unordered(args.concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<anyhow::Result<Vec<String>>>()?;
+
+            let mut deleted = Vec::new();
+            if args.delete {
+                for key in remote.keys() {
+                    if local_keys.contains(key) {
+                        continue;
+                    }
+                    let from = signer.address();
+                    machine
+                        .delete(
+                            &provider,
+                            &mut signer,
+                            from,
+                            key,
+                            DeleteOptions {
+                                broadcast_mode,
+                                gas_params: gas_params.clone(),
+                            },
+                        )
+                        .await?;
+                    deleted.push(key.clone());
+                }
+            }
+
+            print_json(&json!({
+                "uploaded": uploaded.len(),
+                "skipped": skipped,
+                "deleted": deleted.len(),
+            }))
+        }
+        BucketCommands::Serve(args) => {
+            let object_api_url = args.object_api_url.clone().unwrap_or(cfg.object_api_url);
+            let provider = JsonRpcProvider::new_auto(
+                cfg.rpc_url,
+                cfg.subnet_id.chain_id(),
+                None,
+                Some(object_api_url),
+            )?;
+
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
+            let TxParams {
+                sequence,
+                gas_params,
+            } = args.tx_args.to_tx_params()?;
+
+            let mut signer = Wallet::new_secp256k1(
+                args.key_source.resolve()?,
+                AccountKind::Ethereum,
+                cfg.subnet_id,
+            )?;
+            signer.set_sequence(sequence, &provider).await?;
+
+            serve::run(serve::GatewayConfig {
+                provider,
+                signer,
+                address: args.address,
+                listen: args.listen,
+                broadcast_mode,
+                gas_params,
+            })
+            .await
+        }
     }
 }
 
+/// Progress sidecar for a [`download_concurrent`] run, written to `<output>.download-manifest.json`
+/// after every completed chunk so an interrupted download can resume without refetching finished
+/// ranges.
+#[derive(Debug, Serialize, Deserialize)]
+struct DownloadManifest {
+    key: String,
+    total_size: u64,
+    chunks: Vec<DownloadChunk>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DownloadChunk {
+    start: u64,
+    end: u64,
+    done: bool,
+}
+
+fn download_manifest_path(output: &Path) -> PathBuf {
+    let mut path = output.as_os_str().to_owned();
+    path.push(".download-manifest.json");
+    PathBuf::from(path)
+}
+
+/// Staging path a single-stream download is written to before being renamed into `output`, so a
+/// download that fails verification never leaves bytes at `output` itself.
+fn download_partial_path(output: &Path) -> PathBuf {
+    let mut path = output.as_os_str().to_owned();
+    path.push(".partial");
+    PathBuf::from(path)
+}
+
+/// Divides `total_size` bytes into up to `concurrency` contiguous, roughly equal chunks.
+fn split_chunks(total_size: u64, concurrency: usize) -> Vec<DownloadChunk> {
+    let chunk_size = total_size.div_ceil(concurrency.max(1) as u64).max(1);
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < total_size {
+        let end = (start + chunk_size).min(total_size);
+        chunks.push(DownloadChunk {
+            start,
+            end,
+            done: false,
+        });
+        start = end;
+    }
+    chunks
+}
+
+/// Writes to a fixed byte offset within a pre-sized file, so multiple chunks of the same
+/// [`download_concurrent`] run can write their disjoint ranges to the same file concurrently
+/// without sharing a cursor.
+struct OffsetWriter {
+    file: Arc<std::fs::File>,
+    offset: u64,
+}
+
+impl io::AsyncWrite for OffsetWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        use std::os::unix::fs::FileExt;
+        let this = self.get_mut();
+        let n = this.file.write_at(buf, this.offset)?;
+        this.offset += n as u64;
+        std::task::Poll::Ready(Ok(n))
+    }
+
+    fn poll_flush(
+        self: Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+
+    fn poll_shutdown(
+        self: Pin<&mut Self>,
+        _cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::task::Poll::Ready(Ok(()))
+    }
+}
+
+/// Downloads `key` into `output` using up to `concurrency` concurrent range requests, each
+/// writing straight to its offset in the output file, and resumes from `output`'s sidecar
+/// manifest (see [`DownloadManifest`]) if a previous run left one behind.
+///
+/// For encrypted objects, each chunk's plaintext range is expanded to the enclosing whole DARE
+/// packages via [`ciphertext_range_for`] before being fetched undecrypted, then decrypted and
+/// trimmed back down to the chunk's own bytes with [`decrypt_range_writer`] -- so chunks can be
+/// decrypted independently without sharing any decryption state.
+#[allow(clippy::too_many_arguments)]
+async fn download_concurrent(
+    provider: &JsonRpcProvider<RpcClient>,
+    machine: &Bucket,
+    key: &str,
+    output: &Path,
+    height: FvmQueryHeight,
+    decryption_key: Option<[u8; 32]>,
+    total_size: u64,
+    ciphertext_size: u64,
+    concurrency: usize,
+) -> anyhow::Result<()> {
+    let manifest_path = download_manifest_path(output);
+    let mut manifest = match tokio::fs::read(&manifest_path).await {
+        Ok(bytes) => match serde_json::from_slice::<DownloadManifest>(&bytes) {
+            Ok(manifest) if manifest.key == key && manifest.total_size == total_size => manifest,
+            _ => DownloadManifest {
+                key: key.to_string(),
+                total_size,
+                chunks: split_chunks(total_size, concurrency),
+            },
+        },
+        Err(_) => DownloadManifest {
+            key: key.to_string(),
+            total_size,
+            chunks: split_chunks(total_size, concurrency),
+        },
+    };
+
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .open(output)?;
+    file.set_len(total_size)?;
+    let file = Arc::new(file);
+
+    let pending: Vec<(usize, DownloadChunk)> = manifest
+        .chunks
+        .iter()
+        .enumerate()
+        .filter(|(_, chunk)| !chunk.done)
+        .map(|(i, chunk)| (i, chunk.clone()))
+        .collect();
+
+    let mut downloads = stream::iter(pending.into_iter().map(move |(i, chunk)| {
+        let file = file.clone();
+        async move {
+            let result = if let Some(dec_key) = decryption_key {
+                let (cipher_range, filter) =
+                    ciphertext_range_for(chunk.start, chunk.end, ciphertext_size);
+                let writer = decrypt_range_writer(
+                    OffsetWriter {
+                        file,
+                        offset: chunk.start,
+                    },
+                    dec_key,
+                    filter,
+                );
+                machine
+                    .get(
+                        provider,
+                        key,
+                        writer,
+                        GetOptions {
+                            range: Some(format!("{}-{}", cipher_range.start, cipher_range.end - 1)),
+                            height,
+                            decryption_key: None,
+                            verify: true,
+                            show_progress: false,
+                            parallelism: 1,
+                        },
+                    )
+                    .await
+            } else {
+                let writer = OffsetWriter {
+                    file,
+                    offset: chunk.start,
+                };
+                machine
+                    .get(
+                        provider,
+                        key,
+                        writer,
+                        GetOptions {
+                            range: Some(format!("{}-{}", chunk.start, chunk.end - 1)),
+                            height,
+                            decryption_key: None,
+                            verify: true,
+                            show_progress: false,
+                            parallelism: 1,
+                        },
+                    )
+                    .await
+            };
+            (i, result)
+        }
+    }))
+    .buffer_unordered(concurrency.max(1));
+
+    while let Some((i, result)) = downloads.next().await {
+        result?;
+        manifest.chunks[i].done = true;
+        tokio::fs::write(&manifest_path, serde_json::to_vec_pretty(&manifest)?).await?;
+    }
+
+    tokio::fs::remove_file(&manifest_path).await.ok();
+    Ok(())
+}
+
+/// Recursively walks `root`, returning each file's path (joined onto `root`, so relative when
+/// `root` is relative) paired with its path relative to `root` (using `/` separators, suitable for appending to a bucket key prefix).
+async fn walk_dir(root: &Path) -> anyhow::Result<Vec<(PathBuf, String)>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if entry.file_type().await?.is_dir() {
+                dirs.push(path);
+            } else {
+                let rel_key = path
+                    .strip_prefix(root)?
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                files.push((path, rel_key));
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Queries every object under `prefix`, transparently following `next_key` until the bucket is
+/// exhausted, and returns a map of key to [`ObjectState`] for diffing against a local directory.
+async fn query_all(
+    machine: &Bucket,
+    provider: &impl QueryProvider,
+    prefix: &str,
+) -> anyhow::Result<HashMap<String, ObjectState>> {
+    let mut objects = HashMap::new();
+    let mut start_key = None;
+
+    loop {
+        let list = machine
+            .query(
+                provider,
+                QueryOptions {
+                    prefix: prefix.to_string(),
+                    delimiter: "".into(),
+                    start_key,
+                    limit: 0,
+                    height: FvmQueryHeight::Committed,
+                },
+            )
+            .await?;
+
+        for (key, object) in list.objects {
+            objects.insert(String::from_utf8(key)?, object);
+        }
+
+        start_key = list.next_key;
+        if start_key.is_none() {
+            break;
+        }
+    }
+
+    Ok(objects)
+}
+
 fn object_state_to_json(object: &ObjectState) -> Value {
     let mut val = json!({
         "hash": object.hash.to_string(),
@@ -0,0 +1,393 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! A local S3-compatible HTTP gateway in front of a single [`Bucket`], so standard S3 tooling
+//! (`mc`, `rclone`, `aws s3`) can read and write a Recall bucket without knowing about FVM.
+//!
+//! `PutObject`/`GetObject`/`DeleteObject`/`ListObjectsV2` are translated directly onto the
+//! existing [`Bucket`] machine APIs; there's no separate storage layer here.
+
+use std::net::SocketAddr;
+
+use ethers::utils::hex::ToHexExt;
+use futures::TryStreamExt;
+use serde::Deserialize;
+use tokio_util::io::{ReaderStream, StreamReader};
+use warp::http::{HeaderValue, StatusCode};
+use warp::{Filter, Rejection, Reply};
+
+use recall_provider::{
+    fvm_shared::address::Address,
+    json_rpc::{JsonRpcProvider, RpcClient},
+    message::GasParams,
+    query::FvmQueryHeight,
+    tx::BroadcastMode,
+    util::get_eth_address,
+};
+use recall_sdk::machine::bucket::{AddOptions, Bucket, DeleteOptions, GetOptions, QueryOptions};
+use recall_signer::{Signer, Wallet};
+
+/// Everything the gateway needs to serve a single bucket.
+pub struct GatewayConfig {
+    pub provider: JsonRpcProvider<RpcClient>,
+    pub signer: Wallet,
+    pub address: Address,
+    pub listen: SocketAddr,
+    pub broadcast_mode: BroadcastMode,
+    pub gas_params: GasParams,
+}
+
+/// Shared state handed to every route. [`Wallet`] is cheap to clone (its sequence counter is an
+/// `Arc<Mutex<u64>>`), so each request signs with its own clone rather than serializing on a
+/// single signer.
+#[derive(Clone)]
+struct GatewayState {
+    provider: JsonRpcProvider<RpcClient>,
+    signer: Wallet,
+    address: Address,
+    broadcast_mode: BroadcastMode,
+    gas_params: GasParams,
+}
+
+/// Runs the gateway until the process is killed.
+pub async fn run(config: GatewayConfig) -> anyhow::Result<()> {
+    let listen = config.listen;
+    let address = config.address;
+    let state = GatewayState {
+        provider: config.provider,
+        signer: config.signer,
+        address: config.address,
+        broadcast_mode: config.broadcast_mode,
+        gas_params: config.gas_params,
+    };
+
+    let routes = routes(state).recover(handle_rejection);
+
+    log::info!(
+        "Serving bucket {} as an S3-compatible gateway on {}",
+        get_eth_address(address)
+            .map(|a| a.encode_hex_with_prefix())
+            .unwrap_or_default(),
+        listen
+    );
+    warp::serve(routes).run(listen).await;
+    Ok(())
+}
+
+fn routes(state: GatewayState) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
+    let put = warp::put()
+        .and(warp::path::tail())
+        .and(warp::header::<u64>("content-length"))
+        .and(warp::body::stream())
+        .and(with_state(state.clone()))
+        .and_then(handle_put);
+
+    let delete = warp::delete()
+        .and(warp::path::tail())
+        .and(with_state(state.clone()))
+        .and_then(handle_delete);
+
+    // A GET of the bucket root is always a ListObjectsV2; `warp::path::end()` only matches when
+    // there's no key left in the path, so this runs before the by-key `get` route below.
+    let list = warp::get()
+        .and(warp::path::end())
+        .and(warp::query::<ListQuery>())
+        .and(with_state(state.clone()))
+        .and_then(handle_list);
+
+    let get = warp::get()
+        .and(warp::path::tail())
+        .and(warp::header::optional::<String>("range"))
+        .and(with_state(state))
+        .and_then(handle_get);
+
+    put.or(delete).or(list).or(get)
+}
+
+fn with_state(
+    state: GatewayState,
+) -> impl Filter<Extract = (GatewayState,), Error = std::convert::Infallible> + Clone {
+    warp::any().map(move || state.clone())
+}
+
+async fn handle_put<S, B>(
+    tail: warp::path::Tail,
+    content_length: u64,
+    body: S,
+    state: GatewayState,
+) -> Result<impl Reply, Rejection>
+where
+    S: futures::Stream<Item = Result<B, warp::Error>> + Send + Sync + 'static,
+    B: bytes::Buf,
+{
+    let key = tail.as_str().to_string();
+    let reader =
+        StreamReader::new(body.map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e)));
+
+    let machine = Bucket::attach(state.address)
+        .await
+        .map_err(internal_error)?;
+    let mut signer = state.signer.clone();
+    let from = signer.address();
+
+    let tx = machine
+        .add_reader(
+            &state.provider,
+            &mut signer,
+            from,
+            &key,
+            reader,
+            content_length,
+            AddOptions {
+                overwrite: true,
+                broadcast_mode: state.broadcast_mode,
+                gas_params: state.gas_params,
+                show_progress: false,
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let etag = tx
+        .data
+        .as_ref()
+        .map(|object| object.hash.to_string())
+        .unwrap_or_else(|| tx.hash().encode_hex_with_prefix());
+
+    Ok(warp::reply::with_header(
+        StatusCode::OK,
+        "ETag",
+        format!("\"{etag}\""),
+    ))
+}
+
+async fn handle_delete(
+    tail: warp::path::Tail,
+    state: GatewayState,
+) -> Result<impl Reply, Rejection> {
+    let key = tail.as_str().to_string();
+    let machine = Bucket::attach(state.address)
+        .await
+        .map_err(internal_error)?;
+    let mut signer = state.signer.clone();
+    let from = signer.address();
+
+    machine
+        .delete(
+            &state.provider,
+            &mut signer,
+            from,
+            &key,
+            DeleteOptions {
+                broadcast_mode: state.broadcast_mode,
+                gas_params: state.gas_params,
+            },
+        )
+        .await
+        .map_err(internal_error)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+async fn handle_get(
+    tail: warp::path::Tail,
+    range: Option<String>,
+    state: GatewayState,
+) -> Result<warp::reply::Response, Rejection> {
+    let key = tail.as_str().to_string();
+
+    let object = {
+        let machine = Bucket::attach(state.address)
+            .await
+            .map_err(internal_error)?;
+        match machine
+            .head(&state.provider, &key, FvmQueryHeight::Committed)
+            .await
+        {
+            Ok(object) => object,
+            Err(_) => {
+                return Ok(
+                    warp::reply::with_status("object not found", StatusCode::NOT_FOUND)
+                        .into_response(),
+                )
+            }
+        }
+    };
+
+    let has_range = range.is_some();
+    let (writer, reader) = tokio::io::duplex(64 * 1024);
+    let provider = state.provider.clone();
+    let address = state.address;
+    tokio::spawn(async move {
+        let machine = Bucket::attach(address)
+            .await
+            .expect("attaching to a bucket address is infallible");
+        if let Err(err) = machine
+            .get(
+                &provider,
+                &key,
+                writer,
+                GetOptions {
+                    range,
+                    height: FvmQueryHeight::Committed,
+                    decryption_key: None,
+                    verify: true,
+                    show_progress: false,
+                    parallelism: 1,
+                },
+            )
+            .await
+        {
+            log::warn!("gateway: failed to stream object: {err}");
+        }
+    });
+
+    let body = warp::hyper::Body::wrap_stream(ReaderStream::new(reader));
+    let mut response = warp::reply::Response::new(body);
+    *response.status_mut() = if has_range {
+        StatusCode::PARTIAL_CONTENT
+    } else {
+        StatusCode::OK
+    };
+    response.headers_mut().insert(
+        warp::http::header::ETAG,
+        HeaderValue::from_str(&format!("\"{}\"", object.hash))
+            .unwrap_or_else(|_| HeaderValue::from_static("\"\"")),
+    );
+    response
+        .headers_mut()
+        .insert(warp::http::header::CONTENT_LENGTH, object.size.into());
+
+    Ok(response)
+}
+
+/// Query parameters for a `ListObjectsV2` request, using the S3 API's on-the-wire names.
+#[derive(Debug, Deserialize)]
+struct ListQuery {
+    #[serde(rename = "prefix")]
+    prefix: Option<String>,
+    #[serde(rename = "delimiter")]
+    delimiter: Option<String>,
+    #[serde(rename = "start-after")]
+    start_after: Option<String>,
+    #[serde(rename = "continuation-token")]
+    continuation_token: Option<String>,
+    #[serde(rename = "max-keys")]
+    max_keys: Option<u64>,
+}
+
+async fn handle_list(query: ListQuery, state: GatewayState) -> Result<impl Reply, Rejection> {
+    let machine = Bucket::attach(state.address)
+        .await
+        .map_err(internal_error)?;
+
+    let prefix = query.prefix.clone().unwrap_or_default();
+    let delimiter = query.delimiter.clone().unwrap_or_else(|| "/".into());
+    let start_key = query
+        .continuation_token
+        .clone()
+        .or_else(|| query.start_after.clone())
+        .map(String::into_bytes);
+
+    let list = machine
+        .query(
+            &state.provider,
+            QueryOptions {
+                prefix: prefix.clone(),
+                delimiter: delimiter.clone(),
+                start_key,
+                limit: query.max_keys.unwrap_or(0),
+                height: FvmQueryHeight::Committed,
+            },
+        )
+        .await
+        .map_err(internal_error)?;
+
+    let bucket_name = get_eth_address(state.address)
+        .map(|a| a.encode_hex_with_prefix())
+        .unwrap_or_default();
+
+    let mut contents = String::new();
+    for (key_bytes, object) in &list.objects {
+        let key = String::from_utf8_lossy(key_bytes);
+        contents.push_str(&format!(
+            "<Contents><Key>{}</Key><ETag>&quot;{}&quot;</ETag><Size>{}</Size></Contents>",
+            xml_escape(&key),
+            object.hash,
+            object.size
+        ));
+    }
+
+    let mut common_prefixes = String::new();
+    for prefix_bytes in &list.common_prefixes {
+        let prefix = String::from_utf8_lossy(prefix_bytes);
+        common_prefixes.push_str(&format!(
+            "<CommonPrefixes><Prefix>{}</Prefix></CommonPrefixes>",
+            xml_escape(&prefix)
+        ));
+    }
+
+    let next_continuation_token = match &list.next_key {
+        Some(key) => format!(
+            "<NextContinuationToken>{}</NextContinuationToken>",
+            xml_escape(&String::from_utf8_lossy(key))
+        ),
+        None => String::new(),
+    };
+
+    let body = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/"><Name>{}</Name><Prefix>{}</Prefix><Delimiter>{}</Delimiter><KeyCount>{}</KeyCount><IsTruncated>{}</IsTruncated>{}{}{}</ListBucketResult>"#,
+        xml_escape(&bucket_name),
+        xml_escape(&prefix),
+        xml_escape(&delimiter),
+        list.objects.len(),
+        list.next_key.is_some(),
+        next_continuation_token,
+        contents,
+        common_prefixes,
+    );
+
+    Ok(warp::reply::with_header(
+        body,
+        "content-type",
+        "application/xml",
+    ))
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[derive(Debug)]
+struct GatewayError(anyhow::Error);
+
+impl warp::reject::Reject for GatewayError {}
+
+fn internal_error(err: anyhow::Error) -> Rejection {
+    warp::reject::custom(GatewayError(err))
+}
+
+async fn handle_rejection(err: Rejection) -> Result<impl Reply, std::convert::Infallible> {
+    if let Some(GatewayError(err)) = err.find::<GatewayError>() {
+        return Ok(warp::reply::with_status(
+            err.to_string(),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        ));
+    }
+    if err.is_not_found() {
+        return Ok(warp::reply::with_status(
+            "not found".to_string(),
+            StatusCode::NOT_FOUND,
+        ));
+    }
+
+    Ok(warp::reply::with_status(
+        "bad request".to_string(),
+        StatusCode::BAD_REQUEST,
+    ))
+}
@@ -78,6 +78,10 @@ struct QueryArgs {
     /// or a specific block height, e.g., "123".
     #[arg(long, value_parser = parse_query_height, default_value = "committed")]
     height: FvmQueryHeight,
+    /// Print a structured call trace instead of the query result, for debugging a failing
+    /// query (e.g. a SQL syntax error).
+    #[arg(long)]
+    trace: bool,
 }
 
 #[derive(Clone, Debug, Args)]
@@ -100,7 +104,7 @@ struct ExecuteArgs {
 
 /// Sqlite commmands handler.
 pub async fn handle_sqlite(cfg: NetworkConfig, args: &SqliteArgs) -> anyhow::Result<()> {
-    let provider = JsonRpcProvider::new_http(cfg.rpc_url, None, None)?;
+    let provider = JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
     let subnet_id = cfg.subnet_id;
 
     match args.command.clone() {
@@ -108,7 +112,7 @@ pub async fn handle_sqlite(cfg: NetworkConfig, args: &SqliteArgs) -> anyhow::Res
             let TxParams {
                 sequence,
                 gas_params,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
 
             let mut signer =
                 Wallet::new_secp256k1(args.private_key.clone(), AccountKind::Ethereum, subnet_id)?;
@@ -134,6 +138,12 @@ pub async fn handle_sqlite(cfg: NetworkConfig, args: &SqliteArgs) -> anyhow::Res
         }
         SqliteCommands::Query(args) => {
             let machine = Sqlite::attach(args.address).await?;
+            if args.trace {
+                let trace = machine
+                    .trace_query(&provider, args.query, args.height)
+                    .await?;
+                return print_json(&trace);
+            }
             let res: QueryReturn = machine.query(&provider, args.query, args.height).await?;
 
             print_json(&res)
@@ -142,11 +152,11 @@ pub async fn handle_sqlite(cfg: NetworkConfig, args: &SqliteArgs) -> anyhow::Res
             if args.statements.is_empty() {
                 bail!("statement to execute is required");
             }
-            let broadcast_mode = args.broadcast_mode.get();
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
             let TxParams {
                 gas_params,
                 sequence,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
 
             let mut signer =
                 Wallet::new_secp256k1(args.private_key.clone(), AccountKind::Ethereum, subnet_id)?;
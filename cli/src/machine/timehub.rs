@@ -13,26 +13,27 @@ use recall_provider::{
     fvm_shared::address::Address,
     json_rpc::JsonRpcProvider,
     query::FvmQueryHeight,
+    response::Cid as RecallCid,
     tx::TxStatus,
     util::get_eth_address,
     util::{parse_address, parse_metadata, parse_query_height},
 };
 use recall_sdk::{
     machine::{
-        timehub::{PushOptions, Timehub},
+        timehub::{InclusionProof, Leaf, PushOptions, Timehub},
         Machine,
     },
     network::NetworkConfig,
     TxParams,
 };
-use recall_signer::{
-    key::{parse_secret_key, SecretKey},
-    AccountKind, Void, Wallet,
-};
+use recall_signer::{AccountKind, Void, Wallet};
 use serde_json::{json, Value};
 use tokio::io::AsyncReadExt;
+use tokio_stream::StreamExt;
 
-use crate::{get_address, print_json, print_tx_json, AddressArgs, BroadcastMode, TxArgs};
+use crate::{
+    get_address, print_json, print_tx_json, AddressArgs, BroadcastMode, KeySourceArgs, TxArgs,
+};
 
 #[derive(Clone, Debug, Args)]
 pub struct TimehubArgs {
@@ -51,19 +52,27 @@ enum TimehubCommands {
     Push(TimehubPushArgs),
     /// Get leaf at a given index and height.
     Leaf(TimehubLeafArgs),
+    /// Unsupported: always fails. The MMR hash scheme an inclusion proof would need hasn't been
+    /// confirmed against the real timehub actor; see `TimehubError::HashSchemeUnconfirmed`.
+    Proof(TimehubLeafArgs),
+    /// Unsupported: always fails with "valid": false. Verifying an inclusion proof needs the
+    /// same unconfirmed hash scheme `proof` does; see `TimehubError::HashSchemeUnconfirmed`.
+    Verify(TimehubVerifyArgs),
     /// Get leaf count at a given height.
     Count(TimehubQueryArgs),
     /// Get peaks at a given height.
     Peaks(TimehubQueryArgs),
     /// Get root at a given height.
     Root(TimehubQueryArgs),
+    /// Stream newly pushed leaves as they are committed.
+    Subscribe(TimehubSubscribeArgs),
 }
 
 #[derive(Clone, Debug, Args)]
 struct TimehubCreateArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Timehub owner address.
     /// The owner defaults to the signer if not specified.
     #[arg(short, long, value_parser = parse_address)]
@@ -78,8 +87,8 @@ struct TimehubCreateArgs {
 #[derive(Clone, Debug, Args)]
 struct TimehubPushArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Timehub machine address.
     #[arg(short, long, value_parser = parse_address)]
     address: Address,
@@ -123,9 +132,42 @@ struct TimehubLeafArgs {
     height: FvmQueryHeight,
 }
 
+#[derive(Clone, Debug, Args)]
+struct TimehubVerifyArgs {
+    /// Timestamp of the leaf being verified, in seconds since the UNIX epoch.
+    #[arg(long)]
+    timestamp: u64,
+    /// Witnessed root CID of the leaf being verified.
+    #[arg(long, value_parser = parse_cid)]
+    witnessed: RecallCid,
+    /// Expected timehub root to verify the leaf's inclusion against.
+    #[arg(long, value_parser = parse_cid)]
+    root: RecallCid,
+    /// Input file (or stdin) containing the JSON-encoded inclusion proof from `timehub proof`.
+    #[clap(default_value = "-")]
+    proof: FileOrStdin,
+}
+
+#[derive(Clone, Debug, Args)]
+struct TimehubSubscribeArgs {
+    /// Timehub machine address.
+    #[arg(short, long, value_parser = parse_address)]
+    address: Address,
+    /// Leaf index to start streaming from.
+    #[arg(long, default_value_t = 0)]
+    from_index: u64,
+    /// How often to poll for newly pushed leaves, in milliseconds.
+    #[arg(long, default_value_t = 5000)]
+    poll_interval_millis: u64,
+}
+
+fn parse_cid(s: &str) -> anyhow::Result<RecallCid> {
+    RecallCid::from_str(s)
+}
+
 /// Timehub commmands handler.
 pub async fn handle_timehub(cfg: NetworkConfig, args: &TimehubArgs) -> anyhow::Result<()> {
-    let provider = JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+    let provider = JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
     let subnet_id = cfg.subnet_id;
 
     match &args.command {
@@ -133,10 +175,10 @@ pub async fn handle_timehub(cfg: NetworkConfig, args: &TimehubArgs) -> anyhow::R
             let TxParams {
                 sequence,
                 gas_params,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
 
             let mut signer =
-                Wallet::new_secp256k1(args.private_key.clone(), AccountKind::Ethereum, subnet_id)?;
+                Wallet::new_secp256k1(args.key_source.resolve()?, AccountKind::Ethereum, subnet_id)?;
             signer.set_sequence(sequence, &provider).await?;
 
             let metadata: HashMap<String, String> = args.metadata.clone().into_iter().collect();
@@ -167,14 +209,14 @@ pub async fn handle_timehub(cfg: NetworkConfig, args: &TimehubArgs) -> anyhow::R
             print_json(&metadata)
         }
         TimehubCommands::Push(args) => {
-            let broadcast_mode = args.broadcast_mode.get();
+            let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
             let TxParams {
                 gas_params,
                 sequence,
-            } = args.tx_args.to_tx_params();
+            } = args.tx_args.to_tx_params()?;
 
             let mut signer =
-                Wallet::new_secp256k1(args.private_key.clone(), AccountKind::Ethereum, subnet_id)?;
+                Wallet::new_secp256k1(args.key_source.resolve()?, AccountKind::Ethereum, subnet_id)?;
             signer.set_sequence(sequence, &provider).await?;
 
             let mut reader = args.input.into_async_reader().await?;
@@ -224,6 +266,29 @@ pub async fn handle_timehub(cfg: NetworkConfig, args: &TimehubArgs) -> anyhow::R
 
             print_json(&leaf)
         }
+        TimehubCommands::Proof(args) => {
+            let machine = Timehub::attach(args.address).await?;
+            let proof = machine.proof(&provider, args.index, args.height).await?;
+
+            print_json(&proof)
+        }
+        TimehubCommands::Verify(args) => {
+            let mut reader = args.proof.into_async_reader().await?;
+            let mut buf = String::new();
+            reader.read_to_string(&mut buf).await?;
+            let proof: InclusionProof = serde_json::from_str(&buf)
+                .context("proof should be the JSON-encoded output of `timehub proof`")?;
+
+            let leaf = Leaf {
+                timestamp: args.timestamp,
+                witnessed: args.witnessed,
+            };
+
+            match proof.verify_checked(&leaf, &args.root) {
+                Ok(()) => print_json(&json!({"valid": true})),
+                Err(err) => print_json(&json!({"valid": false, "reason": err.to_string()})),
+            }
+        }
         TimehubCommands::Count(args) => {
             let machine = Timehub::attach(args.address).await?;
             let count = machine.count(&provider, args.height).await?;
@@ -242,5 +307,18 @@ pub async fn handle_timehub(cfg: NetworkConfig, args: &TimehubArgs) -> anyhow::R
 
             print_json(&json!({"root": root.to_string()}))
         }
+        TimehubCommands::Subscribe(args) => {
+            let machine = Timehub::attach(args.address).await?;
+            let poll_interval = std::time::Duration::from_millis(args.poll_interval_millis);
+            let mut stream =
+                Box::pin(machine.subscribe(&provider, args.from_index, poll_interval));
+
+            while let Some(item) = stream.next().await {
+                let (index, leaf) = item?;
+                print_json(&json!({"index": index, "leaf": leaf}))?;
+            }
+
+            Ok(())
+        }
     }
 }
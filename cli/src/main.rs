@@ -3,7 +3,10 @@
 
 use std::collections::HashMap;
 use std::fs;
-use std::{collections::HashSet, path::Path};
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
 
 use anyhow::anyhow;
 use clap::{error::ErrorKind, Args, CommandFactory, Parser, Subcommand, ValueEnum};
@@ -12,7 +15,7 @@ use stderrlog::Timestamp;
 
 use recall_provider::{
     fvm_shared::{address::Address, econ::TokenAmount},
-    json_rpc::Url,
+    json_rpc::{RpcEndpoint, Url},
     message::GasParams,
     query::FvmQueryHeight,
     tx::{BroadcastMode as SDKBroadcastMode, TxResult, TxStatus},
@@ -23,11 +26,13 @@ use recall_sdk::{
     TxParams,
 };
 use recall_signer::{
-    key::{parse_secret_key, SecretKey},
+    key::{self, parse_secret_key, SecretKey},
     AccountKind, Signer, SubnetID, Wallet,
 };
 
 use crate::account::{handle_account, AccountArgs};
+use crate::daemon::{handle_daemon, DaemonArgs};
+use crate::key::{handle_key, KeyArgs};
 use crate::machine::{
     bucket::{handle_bucket, BucketArgs},
     handle_machine,
@@ -39,6 +44,8 @@ use crate::subnet::{handle_subnet, SubnetArgs};
 
 mod account;
 mod credit;
+mod daemon;
+mod key;
 mod machine;
 mod storage;
 mod subnet;
@@ -79,9 +86,9 @@ struct Cli {
     #[arg(short, long, env = "RECALL_SUBNET")]
     subnet_id: Option<String>,
 
-    /// Node CometBFT RPC URL.
+    /// Node CometBFT RPC URL. Supports `unix://` paths for a Unix domain socket transport.
     #[arg(long, env = "RECALL_RPC_URL")]
-    rpc_url: Option<Url>,
+    rpc_url: Option<RpcEndpoint>,
 
     /// Node objects RPC URL.
     #[arg(long)]
@@ -135,6 +142,11 @@ enum Commands {
     /// Timehub related commands (alias: th).
     #[clap(alias = "th")]
     Timehub(TimehubArgs),
+    /// Local signing-key management (generate, inspect, brain wallets). Never touches the
+    /// network.
+    Key(KeyArgs),
+    /// Run a long-lived JSON-RPC daemon over HTTP and/or a Unix socket.
+    Daemon(DaemonArgs),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -145,14 +157,19 @@ enum BroadcastMode {
     Sync,
     /// Wait for the delivery results before returning from broadcast.
     Commit,
+    /// Like `commit`, but additionally waits for `--confirmations` further blocks before
+    /// returning, to guard against the including block being orphaned by a reorg.
+    Finalized,
 }
 
 impl BroadcastMode {
-    pub fn get(&self) -> SDKBroadcastMode {
+    /// Converts to the SDK broadcast mode. `confirmations` is only consulted for `Finalized`.
+    pub fn get(&self, confirmations: u32) -> SDKBroadcastMode {
         match self {
             BroadcastMode::Async => SDKBroadcastMode::Async,
             BroadcastMode::Sync => SDKBroadcastMode::Sync,
             BroadcastMode::Commit => SDKBroadcastMode::Commit,
+            BroadcastMode::Finalized => SDKBroadcastMode::Finalized(confirmations),
         }
     }
 }
@@ -167,35 +184,188 @@ struct TxArgs {
     /// 1RECALL = 10**18 attoRECALL.
     #[arg(long, env = "RECALL_GAS_FEE_CAP", value_parser = parse_token_amount_from_atto)]
     gas_fee_cap: Option<TokenAmount>,
-    /// Gas premium for the transaction in attoRECALL.
+    /// Gas premium for the transaction in attoRECALL, or `auto` to sample it from recent blocks'
+    /// base fee (see `--gas-urgency`/`--gas-premium-sample-blocks`). Not every subcommand
+    /// supports `auto`; unsupported ones error rather than silently ignoring it.
     /// The client will enforce a minimum value of 100,000 attoRECALL.
     /// 1RECALL = 10**18 attoRECALL.
-    #[arg(long, env = "RECALL_GAS_PREMIUM", value_parser = parse_token_amount_from_atto)]
-    gas_premium: Option<TokenAmount>,
+    #[arg(long, env = "RECALL_GAS_PREMIUM", value_parser = parse_gas_premium_arg)]
+    gas_premium: Option<GasPremiumArg>,
+    /// How aggressively to price `--gas-premium auto`: `normal` takes the median (p50) of the
+    /// sample, `high` the p75, `urgent` the p90, for faster inclusion during congestion.
+    #[arg(long, env = "RECALL_GAS_URGENCY", value_enum, default_value_t = GasUrgency::Normal)]
+    gas_urgency: GasUrgency,
+    /// Number of past blocks to sample when resolving `--gas-premium auto`.
+    #[arg(long, env = "RECALL_GAS_PREMIUM_SAMPLE_BLOCKS", default_value_t = 20)]
+    gas_premium_sample_blocks: u64,
     /// Sequence for the transaction.
     #[arg(long)]
     sequence: Option<u64>,
+    /// Safety margin multiplier applied to a binary-searched gas limit estimate, e.g. `1.25`
+    /// for a 25% margin. Only consulted when `gas_limit` is not set. Defaults to `1.1`.
+    #[arg(long, env = "RECALL_GAS_SEARCH_SAFETY_MARGIN")]
+    gas_search_safety_margin: Option<f64>,
+    /// Maximum number of binary-search iterations when searching for a safe gas limit.
+    /// Defaults to 20.
+    #[arg(long, env = "RECALL_GAS_SEARCH_MAX_ITERATIONS")]
+    gas_search_max_iterations: Option<u32>,
+    /// Number of additional blocks to wait for on top of the including block before considering
+    /// a transaction final. Only consulted when `--broadcast-mode finalized` is used.
+    #[arg(long, env = "RECALL_CONFIRMATIONS", default_value_t = 0)]
+    confirmations: u32,
+    /// Multiplier applied to the fee cap/premium estimated by a gas oracle, e.g. `1.5` to bias
+    /// the estimate 50% upward during network congestion. Only consulted when `gas_fee_cap`/
+    /// `gas_premium` are not set.
+    #[arg(long, env = "RECALL_GAS_FEE_MULTIPLIER", default_value_t = 1.0)]
+    pub(crate) gas_fee_multiplier: f64,
+}
+
+/// A `--gas-premium` value: either an explicit amount, or `auto` to sample it from the network.
+#[derive(Clone, Debug)]
+enum GasPremiumArg {
+    Explicit(TokenAmount),
+    Auto,
+}
+
+fn parse_gas_premium_arg(s: &str) -> anyhow::Result<GasPremiumArg> {
+    if s.eq_ignore_ascii_case("auto") {
+        Ok(GasPremiumArg::Auto)
+    } else {
+        Ok(GasPremiumArg::Explicit(parse_token_amount_from_atto(s)?))
+    }
+}
+
+/// How aggressively `--gas-premium auto` prices the sampled premium.
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum GasUrgency {
+    Normal,
+    High,
+    Urgent,
+}
+
+impl GasUrgency {
+    /// The percentile (0-100) of the sampled base-fee distribution this urgency picks.
+    fn percentile(&self) -> f64 {
+        match self {
+            GasUrgency::Normal => 50.0,
+            GasUrgency::High => 75.0,
+            GasUrgency::Urgent => 90.0,
+        }
+    }
 }
 
 impl TxArgs {
     /// Creates transaction params from tx related CLI arguments.
-    pub fn to_tx_params(&self) -> TxParams {
-        TxParams {
+    ///
+    /// Errors if `--gas-premium auto` was requested: resolving it needs a network query, which
+    /// this synchronous constructor can't perform. Commands that support `auto` should call
+    /// [`Self::to_tx_params_resolved`] instead.
+    pub fn to_tx_params(&self) -> anyhow::Result<TxParams> {
+        let gas_premium = match &self.gas_premium {
+            Some(GasPremiumArg::Explicit(amount)) => amount.clone(),
+            Some(GasPremiumArg::Auto) => {
+                anyhow::bail!(
+                    "--gas-premium auto is not supported by this subcommand; pass an explicit amount instead"
+                )
+            }
+            None => TokenAmount::default(),
+        };
+        let default_gas_params = GasParams::default();
+        Ok(TxParams {
             sequence: self.sequence,
             gas_params: GasParams {
                 gas_limit: self.gas_limit.unwrap_or_default(),
                 gas_fee_cap: self.gas_fee_cap.clone().unwrap_or_default(),
-                gas_premium: self.gas_premium.clone().unwrap_or_default(),
+                gas_premium,
+                gas_search_safety_margin: self
+                    .gas_search_safety_margin
+                    .unwrap_or(default_gas_params.gas_search_safety_margin),
+                gas_search_max_iterations: self
+                    .gas_search_max_iterations
+                    .unwrap_or(default_gas_params.gas_search_max_iterations),
             },
-        }
+        })
+    }
+
+    /// Like [`Self::to_tx_params`], but resolves `--gas-premium auto` by sampling the last
+    /// `--gas-premium-sample-blocks` blocks' base fee via `provider` and taking the percentile
+    /// selected by `--gas-urgency`.
+    pub async fn to_tx_params_resolved(
+        &self,
+        provider: &impl recall_provider::query::QueryProvider,
+    ) -> anyhow::Result<TxParams> {
+        let Some(GasPremiumArg::Auto) = &self.gas_premium else {
+            return self.to_tx_params();
+        };
+
+        let (_, gas_premium) = recall_provider::gas_oracle::sample_priority_fee(
+            provider,
+            self.gas_premium_sample_blocks,
+            self.gas_urgency.percentile(),
+        )
+        .await?;
+
+        let default_gas_params = GasParams::default();
+        Ok(TxParams {
+            sequence: self.sequence,
+            gas_params: GasParams {
+                gas_limit: self.gas_limit.unwrap_or_default(),
+                gas_fee_cap: self.gas_fee_cap.clone().unwrap_or_default(),
+                gas_premium,
+                gas_search_safety_margin: self
+                    .gas_search_safety_margin
+                    .unwrap_or(default_gas_params.gas_search_safety_margin),
+                gas_search_max_iterations: self
+                    .gas_search_max_iterations
+                    .unwrap_or(default_gas_params.gas_search_max_iterations),
+            },
+        })
     }
 }
 
+/// A wallet private key, given either directly or via a Web3 Secret Storage (v3) keystore file.
+/// Flattened into subcommands that need a signer.
 #[derive(Clone, Debug, Args)]
-struct AddressArgs {
-    /// Wallet private key (ECDSA, secp256k1) for signing transactions.
+pub(crate) struct KeySourceArgs {
+    /// Wallet private key (ECDSA, secp256k1) for signing transactions. Alternative to
+    /// `--keystore`.
     #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
     private_key: Option<SecretKey>,
+    /// Path to a Web3 Secret Storage (v3) keystore file, as an alternative to `--private-key`.
+    #[arg(long, env = "RECALL_KEYSTORE")]
+    keystore: Option<PathBuf>,
+    /// Passphrase for `--keystore`. Prompted for on stdin if not given.
+    #[arg(long, env = "RECALL_KEYSTORE_PASSWORD", hide_env_values = true)]
+    keystore_password: Option<String>,
+}
+
+impl KeySourceArgs {
+    /// Resolves `--private-key` or `--keystore` (prompting for its passphrase on stdin if
+    /// `--keystore-password` wasn't given) to a signing [`SecretKey`].
+    pub(crate) fn resolve(&self) -> anyhow::Result<SecretKey> {
+        if let Some(sk) = &self.private_key {
+            Ok(sk.clone())
+        } else if let Some(path) = &self.keystore {
+            let password = match &self.keystore_password {
+                Some(p) => p.clone(),
+                None => rpassword::prompt_password("Keystore passphrase: ")?,
+            };
+            key::load_keystore(path, &password)
+        } else {
+            Cli::command()
+                .error(
+                    ErrorKind::MissingRequiredArgument,
+                    "the following required arguments were not provided: --private-key OR --keystore",
+                )
+                .exit();
+        }
+    }
+}
+
+#[derive(Clone, Debug, Args)]
+struct AddressArgs {
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// Account address. The signer address is used if no address is given.
     #[arg(short, long, value_parser = parse_address)]
     address: Option<Address>,
@@ -231,6 +401,8 @@ async fn main() -> anyhow::Result<()> {
         Commands::Bucket(args) => handle_bucket(cfg, !cli.quiet, args).await,
         Commands::Timehub(args) => handle_timehub(cfg, args).await,
         Commands::Machine(args) => handle_machine(cfg, args).await,
+        Commands::Key(args) => handle_key(args),
+        Commands::Daemon(args) => handle_daemon(cfg, args).await,
     }
 }
 
@@ -275,7 +447,7 @@ fn apply_flags_on_network_spec(mut spec: NetworkSpec, cli: &Cli) -> NetworkSpec
         spec.subnet_config.object_api_url = x.clone();
     }
     if let Some(ref x) = cli.evm_rpc_url {
-        spec.subnet_config.evm_rpc_url = x.clone();
+        spec.subnet_config.evm_rpc_url = vec![x.clone()];
     }
     if let Some(x) = cli.evm_gateway_address {
         spec.subnet_config.evm_gateway_address = x;
@@ -286,7 +458,7 @@ fn apply_flags_on_network_spec(mut spec: NetworkSpec, cli: &Cli) -> NetworkSpec
 
     if let Some(parent) = spec.parent_network_config.as_mut() {
         if let Some(ref x) = cli.parent_evm_rpc_url {
-            parent.evm_rpc_url = x.clone();
+            parent.evm_rpc_url = vec![x.clone()];
         }
         if let Some(ref x) = cli.parent_evm_gateway_address {
             parent.evm_gateway_address = *x;
@@ -305,14 +477,15 @@ fn apply_flags_on_network_spec(mut spec: NetworkSpec, cli: &Cli) -> NetworkSpec
 fn get_address(args: AddressArgs, subnet_id: &SubnetID) -> anyhow::Result<Address> {
     let address = if let Some(addr) = args.address {
         addr
-    } else if let Some(sk) = args.private_key.clone() {
+    } else if args.key_source.private_key.is_some() || args.key_source.keystore.is_some() {
+        let sk = args.key_source.resolve()?;
         let signer = Wallet::new_secp256k1(sk, AccountKind::Ethereum, subnet_id.clone())?;
         signer.address()
     } else {
         Cli::command()
             .error(
                 ErrorKind::MissingRequiredArgument,
-                "the following required arguments were not provided: --private-key OR --address",
+                "the following required arguments were not provided: --private-key OR --keystore OR --address",
             )
             .exit();
     };
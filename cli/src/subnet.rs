@@ -1,26 +1,36 @@
 // Copyright 2025 Recall Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
-use clap::{Args, Subcommand};
+use std::time::Duration;
+
+use anyhow::anyhow;
+use clap::{Args, Subcommand, ValueEnum};
 use ethers::utils::hex::ToHexExt;
 use recall_provider::util::get_eth_address;
 use recall_provider::{
     fvm_shared::{address::Address, clock::ChainEpoch},
-    json_rpc::JsonRpcProvider,
+    json_rpc::{JsonRpcProvider, Url},
+    query::FvmQueryHeight,
     util::parse_token_credit_rate,
 };
+use sha3::{Digest, Keccak256};
+use tokio_stream::StreamExt;
+
 use recall_sdk::subnet::SetConfigAdminOptions;
 use recall_sdk::{
-    credits::TokenCreditRate,
+    credits::{Credits, TokenCreditRate},
     network::NetworkConfig,
-    subnet::{SetConfigOptions, Subnet},
+    subnet::{LoadTestOptions, SetConfigOptions, Subnet, SubnetEventKind},
     TxParams,
 };
-use recall_signer::{key::SecretKey, AccountKind, Wallet};
+use recall_signer::{
+    key::{parse_secret_key, SecretKey},
+    AccountKind, Wallet,
+};
 use serde_json::json;
 
 use crate::{
-    parse_address, parse_secret_key, print_json, print_tx_json, AddressArgs, BroadcastMode, TxArgs,
+    parse_address, print_json, print_tx_json, AddressArgs, BroadcastMode, KeySourceArgs, TxArgs,
 };
 
 #[derive(Clone, Debug, Args)]
@@ -37,6 +47,109 @@ enum SubnetCommands {
     /// Get and set the subnet configuration.
     #[command(subcommand)]
     Config(ConfigCommands),
+    /// Get the current validator set and each validator's voting power.
+    Validators,
+    /// Report block/proposer production stats over a recent window of blocks.
+    BlockProduction(BlockProductionArgs),
+    /// Rank the top accounts by credit balance and by committed blob capacity.
+    LargestAccounts(LargestAccountsArgs),
+    /// Report total credits issued vs. debited, and free blob storage capacity.
+    Supply(GetConfigArgs),
+    /// Stream committed config and credit-debit events as newline-delimited JSON.
+    Subscribe(SubscribeArgs),
+    /// Drive synthetic traffic to measure sustained TPS and latency under the current config.
+    LoadTest(LoadTestArgs),
+}
+
+/// Which family of subnet events [`SubnetCommands::Subscribe`] should emit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum SubscribeEventKind {
+    /// Config-admin changes and `SetConfig` updates.
+    Config,
+    /// The batched credit-debit sweeps driven by `blob_credit_debit_interval`/
+    /// `account_debit_batch_size`.
+    CreditDebit,
+}
+
+#[derive(Clone, Debug, Args)]
+struct SubscribeArgs {
+    /// WebSocket endpoint to subscribe on, e.g. `ws://localhost:26657/websocket`.
+    #[arg(long)]
+    ws_url: Url,
+    /// Only emit events of these kinds. Defaults to both.
+    #[arg(long, value_enum, num_args = 1.., default_values_t = [SubscribeEventKind::Config, SubscribeEventKind::CreditDebit])]
+    kind: Vec<SubscribeEventKind>,
+    /// Only emit events mentioning this address.
+    #[arg(long, value_parser = parse_address)]
+    address: Option<Address>,
+    /// Seconds between heartbeat lines, so a long-lived monitor can tell a quiet connection from
+    /// a dropped one.
+    #[arg(long, default_value_t = 30)]
+    heartbeat_secs: u64,
+}
+
+#[derive(Clone, Debug, Args)]
+struct BlockProductionArgs {
+    /// Number of recent blocks (roughly, epochs) to report production stats over.
+    #[arg(long, default_value_t = 100)]
+    epochs: u64,
+}
+
+#[derive(Clone, Debug, Args)]
+struct LargestAccountsArgs {
+    /// Maximum number of accounts to return.
+    #[arg(long, default_value_t = 10)]
+    limit: usize,
+    #[command(flatten)]
+    address: AddressArgs,
+}
+
+#[derive(Clone, Debug, Args)]
+struct LoadTestArgs {
+    /// Master wallet private key used to deterministically derive `--accounts` disjoint worker
+    /// wallets, one per nonce sequence. Alternative to `--keys`.
+    #[arg(long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true, conflicts_with = "keys")]
+    master_key: Option<SecretKey>,
+    /// Comma-separated worker wallet private keys, as an alternative to deriving `--accounts`
+    /// workers from `--master-key`.
+    #[arg(long, value_parser = parse_secret_key_list, hide_env_values = true, conflicts_with = "master_key")]
+    keys: Option<Vec<SecretKey>>,
+    /// Number of worker wallets to derive from `--master-key`. Ignored when `--keys` is given.
+    #[arg(long, default_value_t = 4)]
+    accounts: usize,
+    /// Aggregate target transactions per second across all workers. The limiter backs off
+    /// rather than queuing when the node falls behind.
+    #[arg(long, default_value_t = 10.0)]
+    tps: f64,
+    /// How long to drive traffic for, in seconds.
+    #[arg(long, default_value_t = 30)]
+    duration_secs: u64,
+    /// Broadcast mode for each synthetic transaction. `commit` (the default) is required for
+    /// submit-to-commit latency to be measurable.
+    #[arg(short, long, value_enum, env = "RECALL_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
+    broadcast_mode: BroadcastMode,
+    #[command(flatten)]
+    tx_args: TxArgs,
+}
+
+/// Parses a comma-separated list of hex-encoded secp256k1 private keys.
+fn parse_secret_key_list(s: &str) -> anyhow::Result<Vec<SecretKey>> {
+    s.split(',').map(parse_secret_key).collect()
+}
+
+/// Deterministically derives a worker's secp256k1 key from `master` and its `index`, the same
+/// way the `key brain` command derives a key from a passphrase: hash the input with keccak256
+/// and reduce the digest into a secret key scalar. Unlike a brain wallet this isn't meant to be
+/// memorable or secret-managed long-term -- it just gives `--accounts` disjoint, reproducible
+/// worker wallets from a single `--master-key` without needing a key file per worker.
+fn derive_worker_key(master: &SecretKey, index: usize) -> anyhow::Result<SecretKey> {
+    let mut hasher = Keccak256::new();
+    hasher.update(master.serialize());
+    hasher.update(index.to_le_bytes());
+    let digest = hasher.finalize().to_vec();
+    SecretKey::try_from(digest).map_err(|e| {
+        anyhow!("derived worker key at index {index} is not a valid secp256k1 scalar: {e}")
+    })
 }
 
 #[derive(Clone, Debug, Subcommand)]
@@ -55,8 +168,8 @@ enum ConfigCommands {
 #[derive(Clone, Debug, Args)]
 struct SetConfigAdminArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
+    #[command(flatten)]
+    key_source: KeySourceArgs,
     /// The address of the new config admin to set.
     #[arg(value_parser = parse_address)]
     admin_address: Address,
@@ -76,29 +189,39 @@ struct GetConfigAdminArgs {
 #[derive(Clone, Debug, Args)]
 struct SetConfigArgs {
     /// Wallet private key (ECDSA, secp256k1) for signing transactions.
-    #[arg(short, long, env = "RECALL_PRIVATE_KEY", value_parser = parse_secret_key, hide_env_values = true)]
-    private_key: SecretKey,
-    /// The total storage capacity of the subnet.
+    #[command(flatten)]
+    key_source: KeySourceArgs,
+    /// The total storage capacity of the subnet. Required unless `--patch` is set.
     #[arg(long)]
-    blob_capacity: u64,
-    /// The token to credit rate. The amount of atto credits that 1 atto buys.
+    blob_capacity: Option<u64>,
+    /// The token to credit rate. The amount of atto credits that 1 atto buys. Required unless
+    /// `--patch` is set.
     #[arg(long, value_parser = parse_token_credit_rate)]
-    token_credit_rate: TokenCreditRate,
-    /// Block interval at which to debit all credit accounts.
+    token_credit_rate: Option<TokenCreditRate>,
+    /// Block interval at which to debit all credit accounts. Required unless `--patch` is set.
+    #[arg(long)]
+    blob_credit_debit_interval: Option<ChainEpoch>,
+    /// The minimum epoch duration a blob can be stored. Required unless `--patch` is set.
     #[arg(long)]
-    blob_credit_debit_interval: ChainEpoch,
-    /// The minimum epoch duration a blob can be stored.
+    blob_min_ttl: Option<ChainEpoch>,
+    /// The default epoch duration a blob is stored. Required unless `--patch` is set.
     #[arg(long)]
-    blob_min_ttl: ChainEpoch,
-    /// The default epoch duration a blob is stored.
+    blob_default_ttl: Option<ChainEpoch>,
+    /// Maximum number of expired blobs to delete in a single batch during debit. Required
+    /// unless `--patch` is set.
     #[arg(long)]
-    blob_default_ttl: ChainEpoch,
-    /// Maximum number of expired blobs to delete in a single batch during debit.
+    blob_delete_batch_size: Option<u64>,
+    /// Maximum number of accounts to process in a single batch during debit. Required unless
+    /// `--patch` is set.
     #[arg(long)]
-    blob_delete_batch_size: u64,
-    /// Maximum number of accounts to process in a single batch during debit.
+    account_debit_batch_size: Option<u64>,
+    /// Only overlay the flags actually given onto the current on-chain config, instead of
+    /// requiring every field on every call.
     #[arg(long)]
-    account_debit_batch_size: u64,
+    patch: bool,
+    /// Print the resulting merged config as JSON instead of broadcasting it.
+    #[arg(long)]
+    dry_run: bool,
     /// Broadcast mode for the transaction.
     #[arg(short, long, value_enum, env = "RECALL_BROADCAST_MODE", default_value_t = BroadcastMode::Commit)]
     broadcast_mode: BroadcastMode,
@@ -114,23 +237,114 @@ struct GetConfigArgs {
 
 /// Subnet commands handler.
 pub async fn handle_subnet(cfg: NetworkConfig, args: &SubnetArgs) -> anyhow::Result<()> {
-    let provider = JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+    let provider = JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
 
     match &args.command {
         SubnetCommands::ChainId => {
             let chain_id = Subnet::chain_id(provider).await?;
             print_json(&json!({"chain_id": chain_id}))
         }
+        SubnetCommands::Validators => {
+            let validators = Subnet::validators(provider).await?;
+            print_json(&json!(validators))
+        }
+        SubnetCommands::BlockProduction(args) => {
+            let stats = Subnet::block_production(provider, args.epochs).await?;
+            print_json(&json!(stats))
+        }
+        SubnetCommands::LargestAccounts(args) => {
+            let accounts = Credits::largest(&provider, args.limit, args.address.height).await?;
+            print_json(&json!(accounts))
+        }
+        SubnetCommands::Supply(args) => {
+            let supply = Subnet::supply(&provider, args.address.height).await?;
+            print_json(&json!(supply))
+        }
+        SubnetCommands::Subscribe(args) => {
+            let kinds = args
+                .kind
+                .iter()
+                .map(|k| match k {
+                    SubscribeEventKind::Config => SubnetEventKind::Config,
+                    SubscribeEventKind::CreditDebit => SubnetEventKind::CreditDebit,
+                })
+                .collect();
+
+            let events =
+                Subnet::subscribe(&provider, args.ws_url.clone(), kinds, args.address).await?;
+            let mut events = Box::pin(events);
+
+            let mut heartbeat =
+                tokio::time::interval(std::time::Duration::from_secs(args.heartbeat_secs));
+            heartbeat.tick().await; // first tick fires immediately
+
+            loop {
+                tokio::select! {
+                    event = events.next() => {
+                        match event {
+                            Some(Ok(event)) => println!("{}", serde_json::to_string(&event)?),
+                            Some(Err(e)) => println!(
+                                "{}",
+                                serde_json::to_string(&json!({"type": "error", "message": e.to_string()}))?
+                            ),
+                            None => break,
+                        }
+                    }
+                    _ = heartbeat.tick() => {
+                        println!("{}", serde_json::to_string(&json!({"type": "heartbeat"}))?);
+                    }
+                }
+            }
+
+            Ok(())
+        }
+        SubnetCommands::LoadTest(args) => {
+            let secret_keys: Vec<SecretKey> = if let Some(keys) = &args.keys {
+                keys.clone()
+            } else {
+                let master = args
+                    .master_key
+                    .clone()
+                    .ok_or_else(|| anyhow!("either --master-key or --keys is required"))?;
+                (0..args.accounts)
+                    .map(|i| derive_worker_key(&master, i))
+                    .collect::<anyhow::Result<Vec<_>>>()?
+            };
+
+            let mut wallets = Vec::with_capacity(secret_keys.len());
+            for sk in secret_keys {
+                let mut wallet =
+                    Wallet::new_secp256k1(sk, AccountKind::Ethereum, cfg.subnet_id.clone())?;
+                // Each wallet's sequence is primed independently from its own on-chain state, so
+                // workers never share (and thus never collide on) a nonce sequence.
+                wallet.set_sequence(None, &provider).await?;
+                wallets.push(wallet);
+            }
+
+            let report = Subnet::load_test(
+                &provider,
+                wallets,
+                LoadTestOptions {
+                    tps: args.tps,
+                    duration: Duration::from_secs(args.duration_secs),
+                    broadcast_mode: args.broadcast_mode.get(args.tx_args.confirmations),
+                    gas_params: args.tx_args.to_tx_params_resolved(&provider).await?.gas_params,
+                },
+            )
+            .await?;
+
+            print_json(&json!(report))
+        }
         SubnetCommands::Config(cmd) => match &cmd {
             ConfigCommands::SetAdmin(args) => {
-                let broadcast_mode = args.broadcast_mode.get();
+                let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
                 let TxParams {
                     gas_params,
                     sequence,
-                } = args.tx_args.to_tx_params();
+                } = args.tx_args.to_tx_params_resolved(&provider).await?;
 
                 let mut signer = Wallet::new_secp256k1(
-                    args.private_key.clone(),
+                    args.key_source.resolve()?,
                     AccountKind::Ethereum,
                     cfg.subnet_id,
                 )?;
@@ -162,35 +376,82 @@ pub async fn handle_subnet(cfg: NetworkConfig, args: &SubnetArgs) -> anyhow::Res
                 }))
             }
             ConfigCommands::Set(args) => {
-                let broadcast_mode = args.broadcast_mode.get();
+                let broadcast_mode = args.broadcast_mode.get(args.tx_args.confirmations);
                 let TxParams {
                     gas_params,
                     sequence,
-                } = args.tx_args.to_tx_params();
+                } = args.tx_args.to_tx_params_resolved(&provider).await?;
+
+                let options = if args.patch {
+                    let current = Subnet::get_config(&provider, FvmQueryHeight::Committed).await?;
+                    SetConfigOptions {
+                        blob_capacity: args.blob_capacity.unwrap_or(current.blob_capacity),
+                        token_credit_rate: args
+                            .token_credit_rate
+                            .clone()
+                            .unwrap_or(current.token_credit_rate),
+                        blob_credit_debit_interval: args
+                            .blob_credit_debit_interval
+                            .unwrap_or(current.blob_credit_debit_interval),
+                        blob_min_ttl: args.blob_min_ttl.unwrap_or(current.blob_min_ttl),
+                        blob_default_ttl: args.blob_default_ttl.unwrap_or(current.blob_default_ttl),
+                        blob_delete_batch_size: args
+                            .blob_delete_batch_size
+                            .unwrap_or(current.blob_delete_batch_size),
+                        account_debit_batch_size: args
+                            .account_debit_batch_size
+                            .unwrap_or(current.account_debit_batch_size),
+                        broadcast_mode,
+                        gas_params,
+                    }
+                } else {
+                    SetConfigOptions {
+                        blob_capacity: args
+                            .blob_capacity
+                            .ok_or_else(|| anyhow!("--blob-capacity is required unless --patch is set"))?,
+                        token_credit_rate: args.token_credit_rate.clone().ok_or_else(|| {
+                            anyhow!("--token-credit-rate is required unless --patch is set")
+                        })?,
+                        blob_credit_debit_interval: args.blob_credit_debit_interval.ok_or_else(
+                            || anyhow!("--blob-credit-debit-interval is required unless --patch is set"),
+                        )?,
+                        blob_min_ttl: args
+                            .blob_min_ttl
+                            .ok_or_else(|| anyhow!("--blob-min-ttl is required unless --patch is set"))?,
+                        blob_default_ttl: args.blob_default_ttl.ok_or_else(|| {
+                            anyhow!("--blob-default-ttl is required unless --patch is set")
+                        })?,
+                        blob_delete_batch_size: args.blob_delete_batch_size.ok_or_else(|| {
+                            anyhow!("--blob-delete-batch-size is required unless --patch is set")
+                        })?,
+                        account_debit_batch_size: args.account_debit_batch_size.ok_or_else(|| {
+                            anyhow!("--account-debit-batch-size is required unless --patch is set")
+                        })?,
+                        broadcast_mode,
+                        gas_params,
+                    }
+                };
+
+                if args.dry_run {
+                    return print_json(&json!({
+                        "blob_capacity": options.blob_capacity,
+                        "token_credit_rate": options.token_credit_rate.to_string(),
+                        "blob_credit_debit_interval": options.blob_credit_debit_interval,
+                        "blob_min_ttl": options.blob_min_ttl,
+                        "blob_default_ttl": options.blob_default_ttl,
+                        "blob_delete_batch_size": options.blob_delete_batch_size,
+                        "account_debit_batch_size": options.account_debit_batch_size,
+                    }));
+                }
 
                 let mut signer = Wallet::new_secp256k1(
-                    args.private_key.clone(),
+                    args.key_source.resolve()?,
                     AccountKind::Ethereum,
                     cfg.subnet_id,
                 )?;
                 signer.set_sequence(sequence, &provider).await?;
 
-                let tx = Subnet::set_config(
-                    &provider,
-                    &mut signer,
-                    SetConfigOptions {
-                        blob_capacity: args.blob_capacity,
-                        token_credit_rate: args.token_credit_rate.clone(),
-                        blob_credit_debit_interval: args.blob_credit_debit_interval,
-                        blob_min_ttl: args.blob_min_ttl,
-                        blob_default_ttl: args.blob_default_ttl,
-                        blob_delete_batch_size: args.blob_delete_batch_size,
-                        account_debit_batch_size: args.account_debit_batch_size,
-                        broadcast_mode,
-                        gas_params,
-                    },
-                )
-                .await?;
+                let tx = Subnet::set_config(&provider, &mut signer, options).await?;
 
                 print_tx_json(&tx)
             }
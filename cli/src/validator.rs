@@ -1,7 +1,12 @@
 // Copyright 2024 Hoku Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::{Mutex as StdMutex, OnceLock};
+
+use futures::future::try_join_all;
+use tokio::sync::Mutex as TokioMutex;
 
 //use crate::commands::validator::batch_claim::{BatchClaim, BatchClaimArgs};
 //use crate::commands::validator::list::{ListActivities, ListActivitiesArgs};
@@ -13,11 +18,11 @@ use clap::{Args, Subcommand};
 use ethers::middleware::Middleware;
 use ethers::prelude::{Signer, SignerMiddleware};
 use ethers::providers::{Authorization, Http, Provider};
-use ethers::signers::{LocalWallet, Wallet};
-use ethers::types::{Eip1559TransactionRequest, ValueOrArray, H256, U256};
+use ethers::signers::{HDPath, Ledger, LocalWallet, Wallet};
+use ethers::types::{BlockNumber, Eip1559TransactionRequest, ValueOrArray, H256, U256};
 use ethers::{
     core::types::{transaction::eip2718::TypedTransaction, BlockId},
-    providers::{MiddlewareError, PendingTransaction, ProviderError},
+    providers::{MiddlewareError, ProviderError},
 };
 use ethers_contract::{ContractError, EthLogDecode, LogMeta};
 use hoku_provider::fvm_shared::{address::Address, address::Payload, clock::ChainEpoch};
@@ -53,9 +58,79 @@ use super::gas_estimator_middleware::Eip1559GasEstimatorMiddleware;
 use ethers::prelude::k256::ecdsa::SigningKey;
 pub type SignerWithFeeEstimatorMiddleware =
     Eip1559GasEstimatorMiddleware<SignerMiddleware<Provider<Http>, Wallet<SigningKey>>>;
+pub type LedgerSignerWithFeeEstimatorMiddleware =
+    Eip1559GasEstimatorMiddleware<SignerMiddleware<Provider<Http>, Ledger>>;
 
 use url::Url;
 
+/// Per-account nonce cache, so that the claim transactions [`batch_subnet_claim`] submits for
+/// each height can be assigned nonces and broadcast concurrently instead of serially waiting on
+/// one another. Fetches the account's pending nonce once from the chain and hands out
+/// monotonically increasing values after that; mirrors the `NonceManager` pattern already used
+/// for EVM transaction submission in `hoku_sdk::ipc::manager`, parameterized over a plain
+/// `Provider<Http>` rather than a `SignerMiddleware` since the signer here is wrapped in
+/// [`SignerWithFeeEstimatorMiddleware`] before it reaches call sites.
+#[derive(Clone)]
+struct NonceManager {
+    nonce: Arc<TokioMutex<Option<U256>>>,
+}
+
+/// Registry of [`NonceManager`]s, one per account, so repeated calls for the same address share
+/// the same cached nonce.
+static NONCE_MANAGERS: OnceLock<StdMutex<HashMap<ethers::types::Address, NonceManager>>> =
+    OnceLock::new();
+
+impl NonceManager {
+    /// Returns the shared nonce manager for `address`, creating one on first use.
+    fn for_address(address: ethers::types::Address) -> NonceManager {
+        let mut managers = NONCE_MANAGERS
+            .get_or_init(|| StdMutex::new(HashMap::new()))
+            .lock()
+            .unwrap();
+        managers
+            .entry(address)
+            .or_insert_with(|| NonceManager {
+                nonce: Arc::new(TokioMutex::new(None)),
+            })
+            .clone()
+    }
+
+    /// Returns the next nonce to use, initializing the cache from the chain's pending
+    /// transaction count on first use.
+    async fn next(
+        &self,
+        provider: &Provider<Http>,
+        address: ethers::types::Address,
+    ) -> Result<U256> {
+        let mut guard = self.nonce.lock().await;
+        let nonce = match *guard {
+            Some(nonce) => nonce,
+            None => {
+                provider
+                    .get_transaction_count(address, Some(BlockNumber::Pending.into()))
+                    .await?
+            }
+        };
+        *guard = Some(nonce + 1);
+        Ok(nonce)
+    }
+
+    /// Forces a resync with the chain's pending nonce, discarding the cached value. Should be
+    /// called after a broadcast is rejected for a stale nonce, since the cached value may have
+    /// drifted from the chain (e.g. a transaction submitted outside this process).
+    async fn reset(
+        &self,
+        provider: &Provider<Http>,
+        address: ethers::types::Address,
+    ) -> Result<()> {
+        let nonce = provider
+            .get_transaction_count(address, Some(BlockNumber::Pending.into()))
+            .await?;
+        *self.nonce.lock().await = Some(nonce);
+        Ok(())
+    }
+}
+
 use hoku_signer::EthAddress;
 /*use hoku_signer::{
     key::{random_secretkey, SecretKey},
@@ -91,6 +166,25 @@ pub(crate) struct BatchClaimArgs {
     pub from: ChainEpoch,
     #[arg(long, help = "The checkpoint height to claim to")]
     pub to: ChainEpoch,
+    #[arg(
+        long,
+        help = "Sign claim transactions with a Ledger hardware wallet at this account index, \
+                instead of the HOKU_PRIVATE_KEY env var"
+    )]
+    pub ledger_account_index: Option<usize>,
+    #[arg(
+        long,
+        default_value_t = DEFAULT_LOG_WINDOW_SIZE,
+        help = "Initial number of blocks to scan per eth_getLogs call when looking for reward \
+                claims; halved automatically if the RPC endpoint rejects the range"
+    )]
+    pub log_window_size: u64,
+    #[arg(
+        long,
+        help = "Minimum block window to fall back to before giving up on a range the RPC \
+                endpoint keeps rejecting"
+    )]
+    pub min_log_window_size: Option<u64>,
 }
 
 /// Validator commands handler.
@@ -142,7 +236,15 @@ pub async fn handle_validator(cfg: NetworkConfig, args: &ValidatorArgs) -> anyho
             //let claims = child_manager
             //.query_reward_claims(&validator, args.from, args.to)
             //.await?;
-            let claims = query_reward_claims(&validator, args.from, args.to, &subnet).await?;
+            let claims = query_reward_claims(
+                &validator,
+                args.from,
+                args.to,
+                &subnet,
+                args.log_window_size,
+                args.min_log_window_size.unwrap_or(MIN_LOG_WINDOW_SIZE),
+            )
+            .await?;
 
             print!(">>> claims: {:?}\n", claims);
 
@@ -174,9 +276,22 @@ pub async fn handle_validator(cfg: NetworkConfig, args: &ValidatorArgs) -> anyho
 
             print!(">>> parent_manager\n");
 
-            batch_subnet_claim(&validator, &subnet.id, &subnet.id, claims, &subnet).await?;
+            let statuses = match args.ledger_account_index {
+                Some(account_index) => {
+                    let signer = Arc::new(
+                        get_ledger_signer_with_fee_estimator(account_index, &subnet).await?,
+                    );
+                    batch_subnet_claim(signer, &validator, &subnet.id, &subnet.id, claims, &subnet)
+                        .await?
+                }
+                None => {
+                    let signer = Arc::new(get_signer_with_fee_estimator(&validator, &subnet)?);
+                    batch_subnet_claim(signer, &validator, &subnet.id, &subnet.id, claims, &subnet)
+                        .await?
+                }
+            };
 
-            print_json(&json!("rewards claimed"))
+            print_json(&statuses)
         } /*Commands::ListValidatorActivities(args) => {
               let res = ListActivities::handle(global, args).await?;
               print_json(&json!(res))
@@ -193,11 +308,21 @@ fn create_provider(subnet: &config::Subnet) -> Result<Provider<Http>> {
     Ok(Provider::new(provider))
 }
 
+/// Default span of the first `eth_getLogs` window [`query_reward_claims`] tries; most public RPC
+/// endpoints reject ranges much wider than this.
+const DEFAULT_LOG_WINDOW_SIZE: u64 = 2000;
+
+/// Smallest window [`query_with_meta_windowed`] will back off to before giving up on a range the
+/// provider keeps rejecting.
+const MIN_LOG_WINDOW_SIZE: u64 = 1;
+
 async fn query_reward_claims(
     validator_addr: &Address,
     from_checkpoint: ChainEpoch,
     to_checkpoint: ChainEpoch,
     subnet: &config::Subnet,
+    log_window_size: u64,
+    min_log_window_size: u64,
 ) -> Result<Vec<(u64, ValidatorClaim)>> {
     let provider = create_provider(subnet)?;
 
@@ -208,14 +333,21 @@ async fn query_reward_claims(
 
     let ev = contract
         .event::<checkpointing_facet::ActivityRollupRecordedFilter>()
-        .from_block(from_checkpoint as u64)
-        .to_block(to_checkpoint as u64)
         .address(ValueOrArray::Value(contract.address()));
 
     let validator_eth_addr = payload_to_evm_address(validator_addr.payload())?;
 
     let mut claims = vec![];
-    for (event, meta) in query_with_meta(ev, contract.client()).await? {
+    let logs = query_with_meta_windowed(
+        ev,
+        contract.client(),
+        from_checkpoint as u64,
+        to_checkpoint as u64,
+        log_window_size,
+        min_log_window_size,
+    )
+    .await?;
+    for (event, meta) in logs {
         tracing::debug!(
             "found activity bundle published at height: {}",
             meta.block_number
@@ -238,7 +370,9 @@ async fn query_reward_claims(
             continue;
         };
 
-        let proof = gen_merkle_proof(&event.rollup.consensus.data, data)?;
+        let proof =
+            gen_merkle_proof(subnet, event.checkpoint_height, &event.rollup.consensus.data, data)
+                .await?;
 
         // Construct the claim and add it to the list.
         let claim = ValidatorClaim {
@@ -294,7 +428,65 @@ where
     Ok(events)
 }
 
-fn gen_merkle_proof(
+/// Runs `event` over `[from_block, to_block]` a window at a time instead of in one `eth_getLogs`
+/// call, since most providers cap either the block span or the result count of a single query.
+/// Starts at `window_size` blocks and halves the window (down to `min_window_size`) whenever the
+/// provider rejects a sub-range as too large, retrying that same sub-range rather than skipping
+/// it.
+async fn query_with_meta_windowed<B, M, D>(
+    event: ethers::contract::Event<B, M, D>,
+    client: B,
+    from_block: u64,
+    to_block: u64,
+    window_size: u64,
+    min_window_size: u64,
+) -> Result<Vec<(D, LogMeta)>, ContractError<M>>
+where
+    B: Borrow<M> + Clone,
+    M: Middleware,
+    D: EthLogDecode,
+    ethers::contract::Event<B, M, D>: Clone,
+{
+    let mut window = window_size.max(min_window_size).max(1);
+    let mut cursor = from_block;
+    let mut matches = vec![];
+
+    while cursor <= to_block {
+        let window_end = cursor.saturating_add(window - 1).min(to_block);
+        let windowed = event.clone().from_block(cursor).to_block(window_end);
+
+        match query_with_meta(windowed, client.clone()).await {
+            Ok(found) => {
+                matches.extend(found);
+                cursor = window_end + 1;
+            }
+            Err(e) if window > min_window_size && is_range_too_large_error(&e) => {
+                window = (window / 2).max(min_window_size);
+                tracing::warn!(
+                    "eth_getLogs rejected range {cursor}..={window_end}, retrying with a \
+                     {window}-block window"
+                );
+            }
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(matches)
+}
+
+/// Whether `err` looks like an RPC provider rejecting a block range as too wide or too
+/// result-heavy, rather than some other failure that retrying with a smaller window won't fix.
+fn is_range_too_large_error<M: Middleware>(err: &ContractError<M>) -> bool {
+    let message = err.to_string().to_lowercase();
+    message.contains("query returned more than")
+        || message.contains("range too large")
+        || message.contains("block range")
+        || message.contains("limit exceeded")
+}
+
+async fn gen_merkle_proof(
+    subnet: &config::Subnet,
+    checkpoint_height: u64,
     validator_data: &[checkpointing_facet::ValidatorData],
     validator: &checkpointing_facet::ValidatorData,
 ) -> anyhow::Result<Vec<H256>> {
@@ -306,9 +498,54 @@ fn gen_merkle_proof(
     let leaves = order_validator_data(validator_data)?;
     let tree = MerkleGen::new(pack_validator_data, &leaves, &VALIDATOR_REWARD_FIELDS)?;
 
+    // Corroborate the event we rebuilt this tree from against the subnet actor's own committed
+    // root before handing back a proof, rather than trusting the gateway event alone.
+    verify_onchain_commitment(subnet, checkpoint_height, tree.root()).await?;
+
     tree.get_proof(validator)
 }
 
+/// Reads the bottom-up checkpoint the subnet actor committed for `checkpoint_height` via
+/// [`subnet_actor_checkpointing_facet`] and checks that its activity commitment matches
+/// `tree_root`, the root we just rebuilt locally from the gateway's `ActivityRollupRecordedFilter`
+/// event. Mirrors Serai's pattern of corroborating an event against an independent on-chain read
+/// before acting on it, rather than generating a proof the contract would revert on (e.g. because
+/// a reorg made the event we read stale).
+async fn verify_onchain_commitment(
+    subnet: &config::Subnet,
+    checkpoint_height: u64,
+    tree_root: H256,
+) -> anyhow::Result<()> {
+    let provider = create_provider(subnet)?;
+    let contract_addr = contract_address_from_subnet(&subnet.id)?;
+    let checkpointing = subnet_actor_checkpointing_facet::SubnetActorCheckpointingFacet::new(
+        contract_addr,
+        Arc::new(provider),
+    );
+
+    let (exists, checkpoint) = checkpointing
+        .bottom_up_checkpoint_at_epoch(checkpoint_height)
+        .call()
+        .await?;
+    if !exists {
+        return Err(anyhow!(
+            "subnet actor has no committed checkpoint at height {checkpoint_height}; the \
+             activity event we read may be stale (e.g. from a reorged block)"
+        ));
+    }
+
+    let committed_root = H256::from(checkpoint.activity.summary.commitment);
+    if committed_root != tree_root {
+        return Err(anyhow!(
+            "activity Merkle root rebuilt locally for height {checkpoint_height} ({tree_root:?}) \
+             does not match the subnet actor's committed root ({committed_root:?}); refusing to \
+             submit a claim the contract would revert"
+        ));
+    }
+
+    Ok(())
+}
+
 fn order_validator_data(
     validator_data: &[checkpointing_facet::ValidatorData],
 ) -> anyhow::Result<Vec<checkpointing_facet::ValidatorData>> {
@@ -339,35 +576,173 @@ fn order_validator_data(
         .collect::<Result<Vec<_>, _>>()
 }
 
-async fn batch_subnet_claim(
+/// Confirmations required before a claim transaction is reported as landed, rather than merely
+/// broadcast.
+const CLAIM_CONFIRMATIONS: u64 = 1;
+
+/// How often to poll the chain while waiting for a claim transaction to confirm or to be
+/// displaced by a reorg.
+const CONFIRMATION_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3);
+
+/// Fee multiplier (as a numerator/denominator pair, to stay in integer `U256` arithmetic) applied
+/// to the freshly estimated fees when resubmitting a dropped claim at a new nonce.
+const FEE_BUMP_NUMERATOR: u64 = 5;
+const FEE_BUMP_DENOMINATOR: u64 = 4;
+
+/// Outcome of one claim transaction, as resolved by [`confirm_claim`].
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ClaimOutcome {
+    /// Reached [`CLAIM_CONFIRMATIONS`] confirmations under `tx_hash`.
+    Confirmed,
+    /// Never confirmed under `tx_hash`; the submitter's nonce moved past it anyway (e.g. a reorg
+    /// evicted it, or another transaction took its nonce). `batch_subnet_claim` resubmits the
+    /// height at a new nonce with bumped fees on this outcome, so it never returns it.
+    Dropped,
+}
+
+/// Per-claim status reported back to the caller. `batch_subnet_claim` returns these only once
+/// every claim is confirmed; if any claim fails with an error, the whole call fails and no
+/// per-claim statuses are returned.
+#[derive(Debug, Clone, serde::Serialize)]
+struct ClaimStatus {
+    height: u64,
+    tx_hash: H256,
+    confirmations: u64,
+    status: ClaimOutcome,
+}
+
+/// What a submitted claim transaction is expected to do: land under `tx_hash` at the nonce it was
+/// broadcast with. Named after the Eventuality/Completion split in Serai's Ethereum integration —
+/// an eventuality here is the expectation; [`confirm_claim`] resolves it into a [`ClaimStatus`]
+/// once the chain either confirms it or moves on without it.
+struct ClaimEventuality {
+    height: u64,
+    tx_hash: H256,
+    nonce: U256,
+}
+
+/// Polls `provider` until the claim transaction described by `eventuality` either reaches
+/// [`CLAIM_CONFIRMATIONS`] confirmations, or the submitter's nonce advances past it without a
+/// receipt ever showing up, meaning it was dropped or replaced.
+async fn confirm_claim(
+    provider: &Provider<Http>,
+    submitter: ethers::types::Address,
+    eventuality: ClaimEventuality,
+) -> Result<ClaimStatus> {
+    loop {
+        if let Some(receipt) = provider
+            .get_transaction_receipt(eventuality.tx_hash)
+            .await?
+        {
+            if let Some(receipt_block) = receipt.block_number {
+                let latest = provider.get_block_number().await?;
+                let confirmations = latest.saturating_sub(receipt_block).as_u64() + 1;
+                if confirmations >= CLAIM_CONFIRMATIONS {
+                    return Ok(ClaimStatus {
+                        height: eventuality.height,
+                        tx_hash: eventuality.tx_hash,
+                        confirmations,
+                        status: ClaimOutcome::Confirmed,
+                    });
+                }
+            }
+        } else {
+            let current_nonce = provider
+                .get_transaction_count(submitter, Some(BlockNumber::Latest.into()))
+                .await?;
+            if current_nonce > eventuality.nonce {
+                return Ok(ClaimStatus {
+                    height: eventuality.height,
+                    tx_hash: eventuality.tx_hash,
+                    confirmations: 0,
+                    status: ClaimOutcome::Dropped,
+                });
+            }
+        }
+
+        tokio::time::sleep(CONFIRMATION_POLL_INTERVAL).await;
+    }
+}
+
+async fn batch_subnet_claim<M: Middleware + 'static>(
+    signer: Arc<M>,
     submitter: &Address,
     reward_claim_subnet: &SubnetID,
     reward_origin_subnet: &SubnetID,
     claims: Vec<(u64, ValidatorClaim)>,
     subnet: &config::Subnet,
-) -> Result<()> {
-    let signer = Arc::new(get_signer_with_fee_estimator(submitter, subnet)?);
-    print!(">>> signer: {:?}\n", signer);
+) -> Result<Vec<ClaimStatus>> {
+    print!(">>> signer: built\n");
     let contract = subnet_actor_activity_facet::SubnetActorActivityFacet::new(
         contract_address_from_subnet(reward_claim_subnet)?,
         signer.clone(),
     );
     print!(">>> contract: {:?}\n", contract);
 
-    // separate the Vec of tuples claims into two Vecs of Height and Claim
-    let (heights, claims): (Vec<u64>, Vec<ValidatorClaim>) = claims.into_iter().unzip();
-    print!(">>> heights: {:?}\n", heights);
-    print!(">>> claims: {:?}\n", claims);
+    let submitter_addr = payload_to_evm_address(submitter.payload())?;
+    let nonce_provider = create_provider(subnet)?;
+    let nonce_manager = NonceManager::for_address(submitter_addr);
+    let origin = reward_origin_subnet.try_into()?;
 
-    let call = {
-        let call = contract.batch_subnet_claim(reward_origin_subnet.try_into()?, heights, claims);
-        print!(">>> call: {:?}\n", call);
-        extend_call_with_pending_block(call).await?
-    };
+    print!(">>> claims: {:?}\n", claims);
 
-    call.send().await?;
+    // Submit one claim transaction per height, rather than a single call aggregating the whole
+    // range, so they can be pipelined: each claim grabs the next nonce from `nonce_manager` and
+    // is broadcast without waiting for the previous one to land. Confirmation is tracked per
+    // claim too, concurrently; the statuses are returned together once every claim confirms.
+    let submits = claims.into_iter().map(|(height, claim)| {
+        let contract = &contract;
+        let nonce_manager = &nonce_manager;
+        let nonce_provider = &nonce_provider;
+        let origin = &origin;
+        async move {
+            let mut fee_bump = 0u32;
+            loop {
+                let nonce = nonce_manager.next(nonce_provider, submitter_addr).await?;
+                let call =
+                    contract.batch_subnet_claim(origin.clone(), vec![height], vec![claim.clone()]);
+                let mut call = extend_call_with_pending_block(call).await?.nonce(nonce);
+
+                if fee_bump > 0 {
+                    contract.client().fill_transaction(&mut call.tx, None).await?;
+                    if let Some(eip1559) = call.tx.as_eip1559_mut() {
+                        if let Some(max_fee) = eip1559.max_fee_per_gas {
+                            eip1559.max_fee_per_gas =
+                                Some(max_fee * FEE_BUMP_NUMERATOR / FEE_BUMP_DENOMINATOR);
+                        }
+                        if let Some(priority_fee) = eip1559.max_priority_fee_per_gas {
+                            eip1559.max_priority_fee_per_gas =
+                                Some(priority_fee * FEE_BUMP_NUMERATOR / FEE_BUMP_DENOMINATOR);
+                        }
+                    }
+                }
+
+                match call.send().await {
+                    Ok(pending) => {
+                        let eventuality = ClaimEventuality {
+                            height,
+                            tx_hash: pending.tx_hash(),
+                            nonce,
+                        };
+                        let status =
+                            confirm_claim(nonce_provider, submitter_addr, eventuality).await?;
+                        if matches!(status.status, ClaimOutcome::Confirmed) {
+                            return Ok(status);
+                        }
+                        // Dropped: go around again at a new nonce, with bumped fees.
+                        fee_bump += 1;
+                    }
+                    Err(e) if e.to_string().contains("nonce too low") => {
+                        nonce_manager.reset(nonce_provider, submitter_addr).await?;
+                    }
+                    Err(e) => return Err(anyhow!(e)),
+                }
+            }
+        }
+    });
 
-    Ok(())
+    try_join_all(submits).await
 }
 
 fn get_signer_with_fee_estimator(
@@ -395,6 +770,22 @@ fn get_signer_with_fee_estimator(
     Ok(Eip1559GasEstimatorMiddleware::new(signer))
 }
 
+/// Connects to a Ledger hardware wallet at `account_index` instead of reading a raw private key
+/// from `HOKU_PRIVATE_KEY`, so the validator key submitting claim transactions never touches disk.
+async fn get_ledger_signer_with_fee_estimator(
+    account_index: usize,
+    subnet: &config::Subnet,
+) -> Result<LedgerSignerWithFeeEstimatorMiddleware> {
+    let ledger = Ledger::new(HDPath::LedgerLive, account_index)
+        .await
+        .map_err(|e| anyhow!("failed to connect to Ledger device: {e}"))?
+        .with_chain_id(subnet.id.chain_id());
+
+    let provider = create_provider(subnet)?;
+    let signer = SignerMiddleware::new(provider, ledger);
+    Ok(Eip1559GasEstimatorMiddleware::new(signer))
+}
+
 pub(crate) fn contract_address_from_subnet(subnet: &SubnetID) -> Result<ethers::types::Address> {
     let children = subnet.children();
     let ipc_addr = children
@@ -17,7 +17,13 @@ pub async fn run(cli: Cli) -> anyhow::Result<()> {
     let token_address = cli.token_address;
 
     let register_route = register::register_route(faucet_pk.clone());
-    let send_route = send::send_route(faucet_pk.clone(), token_address);
+    let send_route = send::send_route(
+        faucet_pk.clone(),
+        token_address,
+        send::FaucetFeeConfig::default(),
+        send::WithdrawalLimits::default(),
+        send::ClaimTracker::new(),
+    );
 
     let log_request_details = warp::log::custom(log_request_details);
 
@@ -1,20 +1,29 @@
 use adm_sdk::network::Network as SdkNetwork;
 use ethers::prelude::{
-    abigen, Address, Http, LocalWallet, Middleware, Provider, Signer, SignerMiddleware, TxHash,
+    abigen, Address, BlockNumber, Http, LocalWallet, Middleware, Provider, RetryClient, Signer,
+    SignerMiddleware, TxHash, U256,
 };
+use ethers::utils::parse_units;
 use fendermint_crypto::SecretKey;
 use reqwest::Url;
 use serde_json::json;
-use std::convert::TryFrom;
+use std::collections::HashMap;
 use std::error::Error;
-use std::sync::Arc;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use warp::{Filter, Rejection, Reply};
 
 use crate::server::{
     shared::{with_private_key, with_token_address, BadRequest, BaseRequest},
-    util::log_request_body,
+    util::{build_retrying_http_provider, log_request_body},
 };
 
+/// Max retry attempts for transient RPC errors when talking to the parent chain.
+const RPC_MAX_RETRY: u32 = 5;
+/// Initial backoff before the first retry; later retries back off exponentially with jitter.
+const RPC_INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+
 abigen!(
     tHoku,
     r#"[{"inputs":[{"internalType":"address","name":"to","type":"address"},{"internalType":"uint256","name":"amount","type":"uint256"}],"name":"mint","outputs":[],"stateMutability":"nonpayable","type":"function"}]"#
@@ -23,10 +32,124 @@ abigen!(
 /// Amount to send from the faucet to the user.
 const FAUCET_AMOUNT: u64 = 5_000_000_000_000_000_000;
 
+/// Tunables for EIP-1559 fee estimation on the `mint` transaction, so operators can trade off
+/// confirmation speed against cost on a congested parent chain.
+#[derive(Clone, Debug)]
+pub struct FaucetFeeConfig {
+    /// Number of trailing blocks to sample fee history from.
+    pub blocks: u64,
+    /// Reward percentile used to derive `maxPriorityFeePerGas` from recent tips.
+    pub reward_percentile: f64,
+    /// Multiplier applied to the latest base fee when computing `maxFeePerGas`.
+    pub base_fee_multiplier: f64,
+}
+
+impl Default for FaucetFeeConfig {
+    fn default() -> Self {
+        Self {
+            blocks: 10,
+            reward_percentile: 50.0,
+            base_fee_multiplier: 2.0,
+        }
+    }
+}
+
+/// Withdrawal policy enforced by [`handle_send`] on top of the fixed [`FAUCET_AMOUNT`], so a
+/// single address can't repeatedly hit `/send` and drain the faucet.
+#[derive(Clone, Debug)]
+pub struct WithdrawalLimits {
+    /// Maximum amount a single request may mint. Caps the [`FAUCET_AMOUNT`] default down to this
+    /// value when it's smaller, so operators can shrink payouts without recompiling.
+    pub max_amount_per_request: U256,
+    /// Maximum total amount a single address may claim within `window`.
+    pub per_address_limit: U256,
+    /// Rolling window the per-address limit is measured over, e.g. 24 hours.
+    pub window: Duration,
+}
+
+impl WithdrawalLimits {
+    /// Builds limits from human-readable whole-token amounts (e.g. `"50"` for 50 whole tokens),
+    /// converting to base units via `decimals` with [`parse_units`] -- a naive string-to-integer
+    /// parse that ignores decimals would mis-enforce the cap by orders of magnitude (the bug
+    /// fixed upstream in the Namada faucet).
+    pub fn from_human_readable(
+        max_amount_per_request: &str,
+        per_address_limit: &str,
+        window: Duration,
+        decimals: u32,
+    ) -> anyhow::Result<Self> {
+        Ok(WithdrawalLimits {
+            max_amount_per_request: parse_units(max_amount_per_request, decimals)?.into(),
+            per_address_limit: parse_units(per_address_limit, decimals)?.into(),
+            window,
+        })
+    }
+}
+
+impl Default for WithdrawalLimits {
+    fn default() -> Self {
+        WithdrawalLimits {
+            max_amount_per_request: U256::from(FAUCET_AMOUNT),
+            per_address_limit: U256::from(FAUCET_AMOUNT) * 10,
+            window: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+/// Tracks each address' recent claims so [`handle_send`] can enforce
+/// [`WithdrawalLimits::per_address_limit`] as a rolling window rather than a lifetime cap.
+#[derive(Clone, Default)]
+pub struct ClaimTracker {
+    claims: Arc<Mutex<HashMap<Address, Vec<(Instant, U256)>>>>,
+}
+
+impl ClaimTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Prunes `address`'s claims older than `limits.window`, and either records `amount` as a
+    /// new claim and returns `Ok(())`, or leaves the history untouched and returns the address'
+    /// current rolling total as `Err` if adding `amount` would exceed `limits.per_address_limit`.
+    fn try_claim(&self, address: Address, amount: U256, limits: &WithdrawalLimits) -> Result<(), U256> {
+        let now = Instant::now();
+        let mut claims = self.claims.lock().expect("claim tracker lock poisoned");
+        let history = claims.entry(address).or_default();
+        history.retain(|(claimed_at, _)| now.duration_since(*claimed_at) < limits.window);
+
+        let total = history
+            .iter()
+            .fold(U256::zero(), |acc, (_, claimed)| acc + claimed);
+        if total + amount > limits.per_address_limit {
+            return Err(total);
+        }
+        history.push((now, amount));
+        Ok(())
+    }
+}
+
+/// Rejection returned when a request would exceed [`WithdrawalLimits`]. Needs a matching arm in
+/// `shared::handle_rejection` to render as an HTTP 429.
+#[derive(Debug)]
+pub struct RateLimitExceeded {
+    pub message: String,
+}
+
+impl fmt::Display for RateLimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl warp::reject::Reject for RateLimitExceeded {}
+
 /// Route filter for `/send` endpoint.
 pub fn send_route(
     private_key: SecretKey,
     token_address: Address,
+    fee_config: FaucetFeeConfig,
+    limits: WithdrawalLimits,
+    tracker: ClaimTracker,
 ) -> impl Filter<Extract = impl Reply, Error = Rejection> + Clone {
     warp::path("send")
         .and(warp::post())
@@ -34,6 +157,9 @@ pub fn send_route(
         .and(warp::body::json())
         .and(with_private_key(private_key.clone()))
         .and(with_token_address(token_address.clone()))
+        .and(warp::any().map(move || fee_config.clone()))
+        .and(warp::any().map(move || limits.clone()))
+        .and(warp::any().map(move || tracker.clone()))
         .and_then(handle_send)
 }
 
@@ -42,45 +168,106 @@ pub async fn handle_send(
     req: BaseRequest,
     private_key: SecretKey,
     token_address: Address,
+    fee_config: FaucetFeeConfig,
+    limits: WithdrawalLimits,
+    tracker: ClaimTracker,
 ) -> anyhow::Result<impl Reply, Rejection> {
     log_request_body("send", &format!("{}", req));
     req.network.init();
     let address_bytes = req.address.into_payload().to_raw_bytes();
     let eth_address = Address::from_slice(&address_bytes[1..]);
-    let res = send(req.network, eth_address, private_key, token_address)
-        .await
-        .map_err(|e| {
-            Rejection::from(BadRequest {
-                message: format!("send error: {}", e),
-            })
-        })?;
+
+    let amount = U256::from(FAUCET_AMOUNT).min(limits.max_amount_per_request);
+    if let Err(claimed) = tracker.try_claim(eth_address, amount, &limits) {
+        return Err(Rejection::from(RateLimitExceeded {
+            message: format!(
+                "address {:?} already claimed {} of {} allowed within the current window",
+                eth_address, claimed, limits.per_address_limit
+            ),
+        }));
+    }
+
+    let res = send(
+        req.network,
+        eth_address,
+        amount,
+        private_key,
+        token_address,
+        fee_config,
+    )
+    .await
+    .map_err(|e| {
+        Rejection::from(BadRequest {
+            message: format!("send error: {}", e),
+        })
+    })?;
     let json = json!(res);
     Ok(warp::reply::json(&json))
 }
 
+/// Estimates `(max_fee_per_gas, max_priority_fee_per_gas)` from recent fee history, so `mint`
+/// transactions clear promptly under parent chain congestion instead of relying on the client's
+/// hard-coded defaults.
+async fn estimate_fees(
+    provider: &Provider<RetryClient<Http>>,
+    fee_config: &FaucetFeeConfig,
+) -> anyhow::Result<(U256, U256), Box<dyn Error>> {
+    let history = provider
+        .fee_history(
+            fee_config.blocks,
+            BlockNumber::Latest,
+            &[fee_config.reward_percentile],
+        )
+        .await?;
+
+    let base_fee = *history
+        .base_fee_per_gas
+        .last()
+        .ok_or("fee history returned no base fees")?;
+
+    let rewards: Vec<U256> = history
+        .reward
+        .iter()
+        .filter_map(|percentiles| percentiles.first().copied())
+        .collect();
+    let priority_fee = if rewards.is_empty() {
+        U256::zero()
+    } else {
+        rewards.iter().fold(U256::zero(), |acc, r| acc + r) / U256::from(rewards.len())
+    };
+
+    let base_fee_multiplier = U256::from((fee_config.base_fee_multiplier * 1000.0) as u64);
+    let max_fee_per_gas = (base_fee * base_fee_multiplier) / U256::from(1000u64) + priority_fee;
+
+    Ok((max_fee_per_gas, priority_fee))
+}
+
 /// Sends a transaction on the subnet.
 pub async fn send(
     network: SdkNetwork,
     address: Address,
+    amount: U256,
     private_key: SecretKey,
     token_address: Address,
+    fee_config: FaucetFeeConfig,
 ) -> anyhow::Result<TxHash, Box<dyn Error>> {
     let node_url = network
         .parent_evm_rpc_url()
         .unwrap_or(Url::parse("http://127.0.0.1:8545")?);
 
-    let provider = Provider::<Http>::try_from(node_url.to_string())?;
+    let provider = build_retrying_http_provider(node_url, RPC_MAX_RETRY, RPC_INITIAL_BACKOFF);
     let chain_id = provider.get_chainid().await?.as_u64();
+    let (max_fee_per_gas, max_priority_fee_per_gas) = estimate_fees(&provider, &fee_config).await?;
     let private_key = private_key.serialize();
     let wallet = LocalWallet::from_bytes(private_key.as_slice())?.with_chain_id(chain_id);
 
     let client = SignerMiddleware::new(provider, wallet);
     let contract = tHoku::new(token_address, Arc::new(client));
-    let receipt = contract
-        .mint(address, FAUCET_AMOUNT.into())
-        .send()
-        .await?
-        .clone();
+    let mut call = contract.mint(address, amount);
+    call.tx.set_max_fee_per_gas(max_fee_per_gas);
+    call.tx
+        .set_max_priority_fee_per_gas(max_priority_fee_per_gas);
+    let receipt = call.send().await?.clone();
 
     Ok(receipt)
 }
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use ethers::providers::{
+    Http, HttpRateLimitRetryPolicy, Provider, RetryClient, RetryClientBuilder,
+};
+use reqwest::Url;
+
+/// Builds a [`Provider`] that retries transient RPC errors instead of failing the caller on the
+/// first dropped connection or rate limit, using ethers' `RetryClient` with its stock
+/// `HttpRateLimitRetryPolicy`. Rate-limit and timeout retries are each capped at `max_retry`,
+/// and backoff starts from `initial_backoff`. Which errors are retried, and how long to wait, is
+/// left entirely to that policy and `RetryClient`: this builder adds no classification of its own
+/// (no separate handling of HTTP 503 and no `Retry-After` parsing).
+///
+/// Shared by the faucet's `send` path; `QueryProvider`/`Client` callers on the Timehub paths can
+/// opt in the same way by wrapping their endpoint's `Http` transport with this before handing it
+/// to `JsonRpcProvider`.
+pub fn build_retrying_http_provider(
+    url: Url,
+    max_retry: u32,
+    initial_backoff: Duration,
+) -> Provider<RetryClient<Http>> {
+    let http = Http::new(url);
+    let client = RetryClientBuilder::new()
+        .rate_limit_retries(max_retry)
+        .timeout_retries(max_retry)
+        .initial_backoff(initial_backoff)
+        .build(http, Box::new(HttpRateLimitRetryPolicy));
+
+    Provider::new(client)
+}
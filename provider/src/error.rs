@@ -0,0 +1,87 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! A structured, code-carrying error type.
+//!
+//! The rest of the provider (and its callers) historically collapsed every failure into an
+//! `anyhow::Error` built from a format string, which gives callers no machine-readable way to
+//! distinguish a failed transport call from an actor revert, an IPLD decode failure, or a
+//! validation error. [`RecallError`] carries a stable numeric `code()` so programmatic callers
+//! (e.g. the JSON-RPC layer) can branch on the failure kind instead of substring-matching
+//! messages.
+
+use std::fmt;
+
+use fvm_shared::error::ExitCode;
+
+/// A structured error produced by the provider.
+#[derive(Debug, Clone)]
+pub enum RecallError {
+    /// The underlying transport (HTTP/WebSocket/IPC RPC client) failed.
+    Transport(String),
+    /// An actor aborted the message; `exit_code` is the FVM [`ExitCode`] of the abort.
+    ActorReverted { exit_code: ExitCode, message: String },
+    /// A response could not be decoded into the expected type.
+    Decode(String),
+    /// The caller supplied invalid parameters.
+    InvalidParams(String),
+}
+
+impl RecallError {
+    /// A stable numeric code identifying the error kind, for programmatic callers.
+    pub fn code(&self) -> i32 {
+        match self {
+            RecallError::Transport(_) => 1,
+            RecallError::ActorReverted { .. } => 2,
+            RecallError::Decode(_) => 3,
+            RecallError::InvalidParams(_) => 4,
+        }
+    }
+
+    /// The FVM exit code of the actor abort, if this is an [`RecallError::ActorReverted`].
+    pub fn exit_code(&self) -> Option<ExitCode> {
+        match self {
+            RecallError::ActorReverted { exit_code, .. } => Some(*exit_code),
+            _ => None,
+        }
+    }
+
+    pub fn transport(message: impl Into<String>) -> Self {
+        RecallError::Transport(message.into())
+    }
+
+    pub fn actor_reverted(exit_code: ExitCode, message: impl Into<String>) -> Self {
+        RecallError::ActorReverted {
+            exit_code,
+            message: message.into(),
+        }
+    }
+
+    pub fn decode(message: impl Into<String>) -> Self {
+        RecallError::Decode(message.into())
+    }
+
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        RecallError::InvalidParams(message.into())
+    }
+}
+
+impl fmt::Display for RecallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RecallError::Transport(msg) => write!(f, "transport error (code={}): {msg}", self.code()),
+            RecallError::ActorReverted { exit_code, message } => write!(
+                f,
+                "actor reverted (code={}): exit_code={}; {message}",
+                self.code(),
+                exit_code.value()
+            ),
+            RecallError::Decode(msg) => write!(f, "decode error (code={}): {msg}", self.code()),
+            RecallError::InvalidParams(msg) => {
+                write!(f, "invalid params (code={}): {msg}", self.code())
+            }
+        }
+    }
+}
+
+impl std::error::Error for RecallError {}
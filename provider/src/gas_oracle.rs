@@ -0,0 +1,249 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Pluggable gas estimation, adapted from ethers' gas-oracle middleware.
+//!
+//! Callers building a [`crate::message::GasParams`] by hand almost always leave it at
+//! [`Default`], which ships a zero `gas_limit` and the bare minimum fee cap/premium -- fine for
+//! a read-only call, but likely to underprice or fail a real transaction. A [`GasOracle`] lets
+//! the signer crate's `Signer::transaction`/`Signer::send_transaction` fill in a zeroed
+//! `gas_limit` with a real estimate instead.
+
+use async_trait::async_trait;
+use fvm_shared::econ::TokenAmount;
+
+use crate::message::{GasParams, Message};
+use crate::query::{FvmQueryHeight, GasSearchParams, QueryProvider};
+
+/// Estimates the [`GasParams`] a [`Message`] should be sent with.
+#[async_trait]
+pub trait GasOracle: Send + Sync {
+    /// Returns the gas parameters to use for `message`.
+    async fn estimate(&self, message: &Message) -> anyhow::Result<GasParams>;
+}
+
+/// A [`GasOracle`] that binary-searches a real gas limit out of the provider's
+/// `eth_estimateGas`-equivalent query, scaled by a configurable safety margin.
+///
+/// The fee cap and premium are left at [`GasParams::default`]'s enforced minimums; this oracle
+/// only replaces the zeroed `gas_limit`.
+pub struct SimulatedGasOracle<P> {
+    provider: P,
+    safety_margin: f64,
+}
+
+impl<P: QueryProvider> SimulatedGasOracle<P> {
+    /// Estimates against `provider`, margining the converged limit by `safety_margin`, e.g.
+    /// `1.1` for a 10% safety margin.
+    pub fn new(provider: P, safety_margin: f64) -> Self {
+        SimulatedGasOracle {
+            provider,
+            safety_margin,
+        }
+    }
+}
+
+#[async_trait]
+impl<P: QueryProvider> GasOracle for SimulatedGasOracle<P> {
+    async fn estimate(&self, message: &Message) -> anyhow::Result<GasParams> {
+        let mut gas_params = GasParams::default();
+        gas_params.gas_limit = self
+            .provider
+            .estimate_gas_limit_searched(
+                message.clone(),
+                FvmQueryHeight::Committed,
+                GasSearchParams {
+                    safety_margin: self.safety_margin,
+                    ..Default::default()
+                },
+            )
+            .await?;
+        gas_params.set_limits();
+        Ok(gas_params)
+    }
+}
+
+/// A [`GasOracle`] that always returns the same, caller-supplied [`GasParams`], ignoring the
+/// message entirely. Useful in tests, where a real simulation query isn't available and a
+/// deterministic gas limit is preferable anyway.
+#[derive(Clone, Debug)]
+pub struct FixedGasOracle {
+    gas_params: GasParams,
+}
+
+impl FixedGasOracle {
+    /// Always estimates to `gas_params`.
+    pub fn new(gas_params: GasParams) -> Self {
+        FixedGasOracle { gas_params }
+    }
+}
+
+impl Default for FixedGasOracle {
+    fn default() -> Self {
+        FixedGasOracle::new(GasParams::default())
+    }
+}
+
+#[async_trait]
+impl GasOracle for FixedGasOracle {
+    async fn estimate(&self, _message: &Message) -> anyhow::Result<GasParams> {
+        Ok(self.gas_params.clone())
+    }
+}
+
+/// A [`GasOracle`] that estimates EIP-1559-style fee parameters from the node's own fee history,
+/// the way `ethers`' default estimator prices a transaction against an EVM node: the base fee
+/// charged over the last `past_blocks` blocks is sampled, `max_priority_fee` is taken as the
+/// `reward_percentile` (0-100) of that sample, and `max_fee = base_fee * 2 + max_priority_fee`.
+/// The gas limit is estimated the same way as [`SimulatedGasOracle`].
+pub struct NodeGasOracle<P> {
+    provider: P,
+    past_blocks: u64,
+    reward_percentile: f64,
+    safety_margin: f64,
+}
+
+impl<P: QueryProvider> NodeGasOracle<P> {
+    /// Estimates against `provider`, sampling `past_blocks` blocks of base-fee history (e.g.
+    /// `20`) and taking `reward_percentile` (e.g. `50.0` for the median) as the priority fee,
+    /// margining the gas limit estimate by `safety_margin`, e.g. `1.1` for a 10% safety margin.
+    pub fn new(provider: P, past_blocks: u64, reward_percentile: f64, safety_margin: f64) -> Self {
+        NodeGasOracle {
+            provider,
+            past_blocks: past_blocks.max(1),
+            reward_percentile,
+            safety_margin,
+        }
+    }
+}
+
+#[async_trait]
+impl<P: QueryProvider> GasOracle for NodeGasOracle<P> {
+    async fn estimate(&self, message: &Message) -> anyhow::Result<GasParams> {
+        let mut gas_params = GasParams::default();
+        gas_params.gas_limit = self
+            .provider
+            .estimate_gas_limit_searched(
+                message.clone(),
+                FvmQueryHeight::Committed,
+                GasSearchParams {
+                    safety_margin: self.safety_margin,
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        let (base_fee, priority_fee) =
+            sample_priority_fee(&self.provider, self.past_blocks, self.reward_percentile).await?;
+
+        gas_params.gas_fee_cap = TokenAmount::from_atto(base_fee.atto() * 2 + priority_fee.atto());
+        gas_params.gas_premium = priority_fee;
+        gas_params.set_limits();
+
+        Ok(gas_params)
+    }
+}
+
+/// Samples the base fee over the last `past_blocks` blocks (falling back to just the current
+/// tip's base fee if older blocks can't be queried) and returns `(tip_base_fee,
+/// reward_percentile-th base fee)`. The percentile stands in for a priority fee the way
+/// [`NodeGasOracle`] uses it: when the sample is empty the tip's own base fee is returned as the
+/// floor instead.
+pub async fn sample_priority_fee<P: QueryProvider>(
+    provider: &P,
+    past_blocks: u64,
+    reward_percentile: f64,
+) -> anyhow::Result<(TokenAmount, TokenAmount)> {
+    let tip = provider.state_params(FvmQueryHeight::Committed).await?;
+    let tip_height = tip.height.value();
+
+    let mut base_fees = Vec::with_capacity(past_blocks as usize);
+    for i in 0..past_blocks {
+        let Some(height) = tip_height.checked_sub(i).filter(|h| *h > 0) else {
+            break;
+        };
+        if let Ok(res) = provider.state_params(FvmQueryHeight::Height(height)).await {
+            base_fees.push(res.value.base_fee);
+        }
+    }
+    base_fees.sort();
+
+    let base_fee = tip.value.base_fee;
+    let priority_fee =
+        percentile(&base_fees, reward_percentile).unwrap_or_else(|| base_fee.clone());
+
+    Ok((base_fee, priority_fee))
+}
+
+/// Returns the value at `percentile` (0-100) of an ascending-sorted sample, or `None` if `sample`
+/// is empty.
+fn percentile(sample: &[TokenAmount], percentile: f64) -> Option<TokenAmount> {
+    if sample.is_empty() {
+        return None;
+    }
+    let rank = ((percentile / 100.0) * (sample.len() - 1) as f64).round() as usize;
+    Some(sample[rank.min(sample.len() - 1)].clone())
+}
+
+/// A [`GasOracle`] that wraps another oracle and scales its fee-cap/premium estimate by a fixed
+/// multiplier, e.g. `1.5` to bias the estimate 50% upward during network congestion. The gas
+/// limit is passed through unchanged.
+pub struct MultiplierGasOracle<O> {
+    inner: O,
+    multiplier: f64,
+}
+
+impl<O: GasOracle> MultiplierGasOracle<O> {
+    /// Scales `inner`'s fee estimate by `multiplier`.
+    pub fn new(inner: O, multiplier: f64) -> Self {
+        MultiplierGasOracle { inner, multiplier }
+    }
+}
+
+#[async_trait]
+impl<O: GasOracle> GasOracle for MultiplierGasOracle<O> {
+    async fn estimate(&self, message: &Message) -> anyhow::Result<GasParams> {
+        let mut gas_params = self.inner.estimate(message).await?;
+        gas_params.gas_fee_cap = scale(&gas_params.gas_fee_cap, self.multiplier);
+        gas_params.gas_premium = scale(&gas_params.gas_premium, self.multiplier);
+        gas_params.set_limits();
+        Ok(gas_params)
+    }
+}
+
+/// A [`GasOracle`] that tries a list of oracles in order, falling through to the next one if a
+/// source errors (e.g. the node's fee-history query is unavailable), and erroring only if every
+/// source does. Each source is queried at most once per [`Self::estimate`] call; once one
+/// succeeds, the rest aren't tried.
+pub struct FallbackGasOracle {
+    sources: Vec<Box<dyn GasOracle + Sync>>,
+}
+
+impl FallbackGasOracle {
+    /// Tries `sources` in order, returning the first successful estimate.
+    pub fn new(sources: Vec<Box<dyn GasOracle + Sync>>) -> Self {
+        FallbackGasOracle { sources }
+    }
+}
+
+#[async_trait]
+impl GasOracle for FallbackGasOracle {
+    async fn estimate(&self, message: &Message) -> anyhow::Result<GasParams> {
+        let mut last_err = None;
+        for source in &self.sources {
+            match source.estimate(message).await {
+                Ok(gas_params) => return Ok(gas_params),
+                Err(err) => last_err = Some(err),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| anyhow::anyhow!("no gas oracle sources configured")))
+    }
+}
+
+/// Scales a [`TokenAmount`] by a floating-point multiplier using fixed-point arithmetic, to
+/// avoid rounding a potentially large atto amount through `f64`.
+fn scale(amount: &TokenAmount, multiplier: f64) -> TokenAmount {
+    const PRECISION: i64 = 1_000_000;
+    let multiplier_scaled = (multiplier * PRECISION as f64).round() as i64;
+    TokenAmount::from_atto((amount.atto() * multiplier_scaled) / PRECISION)
+}
@@ -3,6 +3,8 @@
 // SPDX-License-Identifier: Apache-2.0, MIT
 
 use std::fmt::Display;
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::time::Duration;
 
 use anyhow::{anyhow, Context};
@@ -15,17 +17,145 @@ use fendermint_eth_api::conv::from_tm::{
 };
 use fvm_shared::{address::Address, chainid::ChainID};
 use reqwest::multipart::Form;
+use serde::{Deserialize, Serialize};
 use tendermint::{abci::response::DeliverTx, block::Height, hash::Hash};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, SeekFrom};
+use tokio_util::io::ReaderStream;
 use tendermint_rpc::{
-    endpoint::abci_query::AbciQuery, endpoint::block_results, Client, Scheme, WebSocketClient,
-    WebSocketClientDriver, WebSocketClientUrl,
+    endpoint::abci_query::AbciQuery, endpoint::block_results, request::Wrapper as RpcRequestWrapper,
+    Client, Response as TmResponse, Scheme, SimpleRequest, WebSocketClient, WebSocketClientDriver,
+    WebSocketClientUrl,
 };
 
 pub use tendermint_rpc::{HttpClient, Url};
 
+/// A node RPC endpoint: either an HTTP(S) URL or a local Unix-domain socket path, for
+/// co-located node deployments that want credential-free, lower-latency local calls without
+/// exposing an HTTP port. Parses the `unix:///path/to/socket` scheme; everything else is
+/// delegated to the regular [`Url`] parser.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
+pub enum RpcEndpoint {
+    Http(Url),
+    Unix(PathBuf),
+}
+
+impl FromStr for RpcEndpoint {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix("unix://") {
+            Some(path) => Ok(RpcEndpoint::Unix(PathBuf::from(path))),
+            None => Ok(RpcEndpoint::Http(
+                Url::from_str(s).context("invalid RPC URL")?,
+            )),
+        }
+    }
+}
+
+impl Display for RpcEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RpcEndpoint::Http(url) => write!(f, "{url}"),
+            RpcEndpoint::Unix(path) => write!(f, "unix://{}", path.display()),
+        }
+    }
+}
+
+impl From<RpcEndpoint> for String {
+    fn from(endpoint: RpcEndpoint) -> Self {
+        endpoint.to_string()
+    }
+}
+
+impl TryFrom<String> for RpcEndpoint {
+    type Error = anyhow::Error;
+
+    fn try_from(s: String) -> anyhow::Result<Self> {
+        RpcEndpoint::from_str(&s)
+    }
+}
+
+/// A [`Client`] that speaks the same CometBFT JSON-RPC protocol as [`HttpClient`], but over a
+/// Unix-domain socket instead of HTTP: one connection per request, writing a single
+/// newline-terminated JSON-RPC request and reading the JSON-RPC response until the server closes
+/// the connection.
+#[derive(Clone)]
+pub struct UnixSocketClient {
+    path: PathBuf,
+}
+
+impl UnixSocketClient {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        UnixSocketClient { path: path.into() }
+    }
+}
+
+#[async_trait]
+impl Client for UnixSocketClient {
+    async fn perform<R>(&self, request: R) -> Result<R::Response, tendermint_rpc::Error>
+    where
+        R: SimpleRequest,
+    {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let wrapped = RpcRequestWrapper::new(request);
+        let mut body = serde_json::to_vec(&wrapped)
+            .map_err(|e| tendermint_rpc::Error::parse_error(e.to_string()))?;
+        body.push(b'\n');
+
+        let mut stream = tokio::net::UnixStream::connect(&self.path)
+            .await
+            .map_err(|e| {
+                tendermint_rpc::Error::io_error(format!(
+                    "failed to connect to IPC socket at {}: {e}",
+                    self.path.display()
+                ))
+            })?;
+        stream.write_all(&body).await.map_err(|e| {
+            tendermint_rpc::Error::io_error(format!("failed to write to IPC socket: {e}"))
+        })?;
+        stream.flush().await.map_err(|e| {
+            tendermint_rpc::Error::io_error(format!("failed to flush IPC socket: {e}"))
+        })?;
+
+        let mut raw = Vec::new();
+        stream.read_to_end(&mut raw).await.map_err(|e| {
+            tendermint_rpc::Error::io_error(format!("failed to read from IPC socket: {e}"))
+        })?;
+        let raw = String::from_utf8_lossy(&raw);
+
+        R::Response::from_string(raw.trim())
+    }
+}
+
+/// A [`Client`] that dispatches to either an HTTP or a Unix-domain-socket transport, so
+/// [`JsonRpcProvider::new_auto`] has a single concrete type to return regardless of which
+/// transport `rpc_url` resolves to.
+#[derive(Clone)]
+pub enum RpcClient {
+    Http(HttpClient),
+    Unix(UnixSocketClient),
+}
+
+#[async_trait]
+impl Client for RpcClient {
+    async fn perform<R>(&self, request: R) -> Result<R::Response, tendermint_rpc::Error>
+    where
+        R: SimpleRequest,
+    {
+        match self {
+            RpcClient::Http(client) => client.perform(request).await,
+            RpcClient::Unix(client) => client.perform(request).await,
+        }
+    }
+}
+
+use crate::error::RecallError;
 use crate::message::{serialize, ChainMessage};
-use crate::object::{NodeAddr, ObjectProvider, UploadResponse};
+use crate::object::{ChunkedUploadStatus, NodeAddr, ObjectProvider, UploadResponse};
 use crate::query::{FvmQuery, FvmQueryHeight, QueryProvider};
+use crate::retry::{RetryClient, RetryPolicy};
 use crate::tx::{BroadcastMode, TxProvider, TxResult};
 use crate::{Provider, TendermintClient};
 
@@ -73,6 +203,84 @@ impl JsonRpcProvider<HttpClient> {
     }
 }
 
+impl JsonRpcProvider<RetryClient<HttpClient>> {
+    /// Like [`JsonRpcProvider::new_http`], but every call -- `query`, `broadcast_tx_*`,
+    /// `block_results`, `header`, and anything else built on [`Client::perform`] -- transparently
+    /// retries a transient RPC failure per `policy` instead of failing on the first blip.
+    pub fn new_http_with_retry(
+        url: Url,
+        chain_id: ChainID,
+        proxy_url: Option<Url>,
+        object_url: Option<Url>,
+        policy: RetryPolicy,
+    ) -> anyhow::Result<Self> {
+        let inner = RetryClient::with_policy(http_client(url, proxy_url)?, policy);
+        let objects = object_url.map(|url| ObjectClient {
+            inner: reqwest::Client::new(),
+            url,
+        });
+        Ok(Self {
+            inner,
+            chain_id,
+            objects,
+        })
+    }
+}
+
+impl JsonRpcProvider<WebSocketClient> {
+    /// Creates a provider whose transport is a WebSocket connection instead of HTTP, routed
+    /// through an HTTP CONNECT proxy per [`ws_client`]'s precedence and (for `wss://`) trusting
+    /// any extra roots in `tls`. Unlike [`JsonRpcProvider::new_http`], the returned driver must
+    /// be spawned by the caller (e.g. `tokio::spawn(driver.run())`) before any call through the
+    /// provider will make progress.
+    pub async fn new_ws(
+        ws_url: Url,
+        chain_id: ChainID,
+        proxy_url: Option<Url>,
+        tls: Option<WsTlsConfig>,
+        object_url: Option<Url>,
+    ) -> anyhow::Result<(Self, WebSocketClientDriver)> {
+        let (inner, driver) = ws_client(ws_url, proxy_url, tls).await?;
+        let objects = object_url.map(|url| ObjectClient {
+            inner: reqwest::Client::new(),
+            url,
+        });
+        Ok((
+            Self {
+                inner,
+                chain_id,
+                objects,
+            },
+            driver,
+        ))
+    }
+}
+
+impl JsonRpcProvider<RpcClient> {
+    /// Creates a provider whose transport is selected by `endpoint`: HTTP(S) as before, or a
+    /// Unix-domain socket if `endpoint` parsed as `unix:///path/to/socket`.
+    pub fn new_auto(
+        endpoint: RpcEndpoint,
+        chain_id: ChainID,
+        proxy_url: Option<Url>,
+        object_url: Option<Url>,
+    ) -> anyhow::Result<Self> {
+        let inner = match endpoint {
+            RpcEndpoint::Http(url) => RpcClient::Http(http_client(url, proxy_url)?),
+            RpcEndpoint::Unix(path) => RpcClient::Unix(UnixSocketClient::new(path)),
+        };
+        let objects = object_url.map(|url| ObjectClient {
+            inner: reqwest::Client::new(),
+            url,
+        });
+        Ok(Self {
+            inner,
+            chain_id,
+            objects,
+        })
+    }
+}
+
 impl<C> Provider<C> for JsonRpcProvider<C> where C: Client + Send + Sync {}
 
 impl<C> TendermintClient<C> for JsonRpcProvider<C>
@@ -129,27 +337,41 @@ where
                 };
 
                 if matches!(broadcast_mode, BroadcastMode::Async) {
-                    self.inner.broadcast_tx_async(data).await?;
+                    self.inner
+                        .broadcast_tx_async(data)
+                        .await
+                        .map_err(|e| anyhow!(RecallError::transport(e.to_string())))?;
                     Ok(TxResult::pending(tx))
                 } else {
-                    let response = self.inner.broadcast_tx_sync(data).await?;
+                    let response = self
+                        .inner
+                        .broadcast_tx_sync(data)
+                        .await
+                        .map_err(|e| anyhow!(RecallError::transport(e.to_string())))?;
                     if response.code.is_err() {
-                        return Err(anyhow!(format_err("", &response.log)));
+                        return Err(anyhow!(RecallError::actor_reverted(
+                            crate::fvm_shared::error::ExitCode::new(response.code.value()),
+                            format_err("", &response.log),
+                        )));
                     }
                     Ok(TxResult::pending(tx))
                 }
             }
             BroadcastMode::Commit => {
-                let response = self.inner.broadcast_tx_commit(data).await?;
+                let response = self
+                    .inner
+                    .broadcast_tx_commit(data)
+                    .await
+                    .map_err(|e| anyhow!(RecallError::transport(e.to_string())))?;
                 if response.check_tx.code.is_err() {
-                    return Err(anyhow!(format_err(
-                        &response.check_tx.info,
-                        &response.check_tx.log
+                    return Err(anyhow!(RecallError::actor_reverted(
+                        crate::fvm_shared::error::ExitCode::new(response.check_tx.code.value()),
+                        format_err(&response.check_tx.info, &response.check_tx.log),
                     )));
                 } else if response.deliver_tx.code.is_err() {
-                    return Err(anyhow!(format_err(
-                        &response.deliver_tx.info,
-                        &response.deliver_tx.log
+                    return Err(anyhow!(RecallError::actor_reverted(
+                        crate::fvm_shared::error::ExitCode::new(response.deliver_tx.code.value()),
+                        format_err(&response.deliver_tx.info, &response.deliver_tx.log),
                     )));
                 }
 
@@ -158,6 +380,52 @@ where
 
                 let receipt = self.eth_tx_receipt(response.hash, false).await?;
 
+                Ok(TxResult::committed(receipt, Some(return_data)))
+            }
+            BroadcastMode::Finalized(confirmations) => {
+                let response = self
+                    .inner
+                    .broadcast_tx_commit(data)
+                    .await
+                    .map_err(|e| anyhow!(RecallError::transport(e.to_string())))?;
+                if response.check_tx.code.is_err() {
+                    return Err(anyhow!(RecallError::actor_reverted(
+                        crate::fvm_shared::error::ExitCode::new(response.check_tx.code.value()),
+                        format_err(&response.check_tx.info, &response.check_tx.log),
+                    )));
+                } else if response.deliver_tx.code.is_err() {
+                    return Err(anyhow!(RecallError::actor_reverted(
+                        crate::fvm_shared::error::ExitCode::new(response.deliver_tx.code.value()),
+                        format_err(&response.deliver_tx.info, &response.deliver_tx.log),
+                    )));
+                }
+
+                let return_data = f(&response.deliver_tx)
+                    .context("error decoding data from deliver_tx in commit")?;
+
+                let mut receipt = self.eth_tx_receipt(response.hash, false).await?;
+                loop {
+                    let tx_height = receipt
+                        .block_number
+                        .ok_or_else(|| anyhow!("committed receipt is missing a block number"))?
+                        .as_u64();
+                    let latest_height = self
+                        .inner
+                        .status()
+                        .await
+                        .map_err(|e| anyhow!(RecallError::transport(e.to_string())))?
+                        .sync_info
+                        .latest_block_height
+                        .value();
+                    if tx_height + confirmations as u64 <= latest_height {
+                        break;
+                    }
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    // Re-query the receipt on every poll so a reorg that drops the transaction
+                    // surfaces here as an error rather than a false success.
+                    receipt = self.eth_tx_receipt(response.hash, false).await?;
+                }
+
                 Ok(TxResult::committed(receipt, Some(return_data)))
             }
         }
@@ -268,6 +536,51 @@ where
         Ok(upload_response)
     }
 
+    async fn upload_chunked<R>(
+        &self,
+        upload_id: &str,
+        mut reader: R,
+        size: u64,
+        chunk_size: u64,
+    ) -> anyhow::Result<UploadResponse>
+    where
+        R: AsyncRead + AsyncSeek + Unpin + Send + 'static,
+    {
+        if size <= chunk_size {
+            let stream = ReaderStream::with_capacity(reader, 64 * 1024);
+            return self.upload(reqwest::Body::wrap_stream(stream), size).await;
+        }
+
+        let client = self
+            .objects
+            .clone()
+            .ok_or_else(|| anyhow!("object provider is required"))?;
+
+        let status = self.chunked_upload_status(&client, upload_id).await?;
+
+        let mut offset = 0u64;
+        while offset < size {
+            let len = chunk_size.min(size - offset);
+            if status.received_offsets.contains(&offset) {
+                reader
+                    .seek(SeekFrom::Current(len as i64))
+                    .await
+                    .context("failed to seek past an already-uploaded chunk")?;
+            } else {
+                let mut buf = vec![0u8; len as usize];
+                reader
+                    .read_exact(&mut buf)
+                    .await
+                    .context("failed to read object chunk for upload")?;
+                self.upload_chunk(&client, upload_id, offset, size, buf)
+                    .await?;
+            }
+            offset += len;
+        }
+
+        self.complete_chunked_upload(&client, upload_id).await
+    }
+
     async fn download(
         &self,
         address: Address,
@@ -332,6 +645,93 @@ where
     }
 }
 
+impl<C> JsonRpcProvider<C>
+where
+    C: Client + Sync + Send,
+{
+    /// Asks the node which offsets of `upload_id` it already has, so
+    /// [`ObjectProvider::upload_chunked`] can skip re-sending them. A fresh (never-started)
+    /// upload is reported by the node as a 404, which is treated the same as an empty status
+    /// rather than an error.
+    async fn chunked_upload_status(
+        &self,
+        client: &ObjectClient,
+        upload_id: &str,
+    ) -> anyhow::Result<ChunkedUploadStatus> {
+        let url = format!("{}v1/objects/chunked/{upload_id}", client.url);
+        let response = client.inner.get(url).send().await?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(ChunkedUploadStatus::default());
+        }
+        if !response.status().is_success() {
+            return Err(anyhow!(format!(
+                "failed to check chunked upload status: {}",
+                response.text().await?
+            )));
+        }
+        Ok(response.json().await?)
+    }
+
+    /// Uploads a single chunk, retrying the chunk itself (not the whole transfer) on a transient
+    /// failure.
+    async fn upload_chunk(
+        &self,
+        client: &ObjectClient,
+        upload_id: &str,
+        offset: u64,
+        total_size: u64,
+        data: Vec<u8>,
+    ) -> anyhow::Result<()> {
+        let url = format!("{}v1/objects/chunked/{upload_id}/{offset}", client.url);
+        retry(new_backoff_policy(30), || async {
+            let form = Form::new()
+                .text("offset", offset.to_string())
+                .text("total_size", total_size.to_string())
+                .part(
+                    "data",
+                    reqwest::multipart::Part::bytes(data.clone())
+                        .mime_str("application/octet-stream")
+                        .map_err(|e| backoff::Error::permanent(anyhow!(e)))?,
+                );
+            let response = client
+                .inner
+                .post(&url)
+                .multipart(form)
+                .send()
+                .await
+                .map_err(|e| backoff::Error::transient(anyhow!(e)))?;
+            if !response.status().is_success() {
+                let body = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "<no response body>".to_string());
+                return Err(backoff::Error::transient(anyhow!(
+                    "failed to upload chunk at offset {offset}: {body}"
+                )));
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    /// Tells the node every chunk has been sent and it should reassemble the object.
+    async fn complete_chunked_upload(
+        &self,
+        client: &ObjectClient,
+        upload_id: &str,
+    ) -> anyhow::Result<UploadResponse> {
+        let url = format!("{}v1/objects/chunked/{upload_id}/complete", client.url);
+        let response = client.inner.post(url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(format!(
+                "failed to complete chunked upload: {}",
+                response.text().await?
+            )));
+        }
+        Ok(response.json().await?)
+    }
+}
+
 /// Format transaction receipt errors.
 fn format_err(info: &str, log: &str) -> String {
     let mut output = String::new();
@@ -354,21 +754,21 @@ fn format_err(info: &str, log: &str) -> String {
 //
 // Copied from `tendermint_rpc`.
 fn get_http_proxy_url(url_scheme: Scheme, proxy_url: Option<Url>) -> anyhow::Result<Option<Url>> {
+    get_proxy_url(matches!(url_scheme, Scheme::Https), proxy_url)
+}
+
+/// Same precedence as [`get_http_proxy_url`], generalized to a plain "is this connection
+/// encrypted" flag so it also covers `wss://`/`ws://` WebSocket URLs, whose scheme
+/// `tendermint_rpc::Scheme` doesn't have a variant for.
+fn get_proxy_url(is_secure_scheme: bool, proxy_url: Option<Url>) -> anyhow::Result<Option<Url>> {
     match proxy_url {
         Some(u) => Ok(Some(u)),
-        None => match url_scheme {
-            Scheme::Http => std::env::var("HTTP_PROXY").ok(),
-            Scheme::Https => std::env::var("HTTPS_PROXY")
+        None => if is_secure_scheme {
+            std::env::var("HTTPS_PROXY")
                 .ok()
-                .or_else(|| std::env::var("HTTP_PROXY").ok()),
-            _ => {
-                if std::env::var("HTTP_PROXY").is_ok() || std::env::var("HTTPS_PROXY").is_ok() {
-                    tracing::warn!(
-                        "Ignoring HTTP proxy environment variables for non-HTTP client connection"
-                    );
-                }
-                None
-            }
+                .or_else(|| std::env::var("HTTP_PROXY").ok())
+        } else {
+            std::env::var("HTTP_PROXY").ok()
         }
         .map(|u| u.parse::<Url>().map_err(|e| anyhow!(e)))
         .transpose(),
@@ -395,17 +795,55 @@ pub fn http_client(url: Url, proxy_url: Option<Url>) -> anyhow::Result<HttpClien
     Ok(client)
 }
 
-/// Create a Tendermint WebSocket client.
+/// Root certificates trusted for a `wss://` WebSocket connection, letting a caller trust a
+/// private/internal CA (e.g. for a node behind a corporate proxy) instead of only the system
+/// root store [`WebSocketClient`] uses by default.
+#[derive(Debug, Clone, Default)]
+pub struct WsTlsConfig {
+    /// Additional PEM-encoded root certificates to trust, on top of the system store.
+    pub extra_root_certs_pem: Vec<Vec<u8>>,
+}
+
+/// Create a Tendermint WebSocket client, routed through an HTTP CONNECT proxy when `proxy_url`
+/// is given or the `HTTP_PROXY`/`HTTPS_PROXY` environment variables are set (`wss://` preferring
+/// `HTTPS_PROXY`, same precedence as [`http_client`]), and with `tls` letting the caller extend
+/// the trusted root store for a `wss://` connection.
 ///
 /// The caller must start the driver in a background task.
-pub async fn ws_client<U>(url: U) -> anyhow::Result<(WebSocketClient, WebSocketClientDriver)>
+pub async fn ws_client<U>(
+    url: U,
+    proxy_url: Option<Url>,
+    tls: Option<WsTlsConfig>,
+) -> anyhow::Result<(WebSocketClient, WebSocketClientDriver)>
 where
     U: TryInto<WebSocketClientUrl, Error = tendermint_rpc::Error> + Display + Clone,
 {
-    // TODO: Doesn't handle proxy.
-    tracing::debug!("Using WS client to submit request to: {}", url);
-    let (client, driver) = WebSocketClient::new(url.clone())
+    let is_secure = url.to_string().starts_with("wss://");
+    let proxy_url = get_proxy_url(is_secure, proxy_url)?;
+
+    let mut builder = WebSocketClient::builder(
+        url.clone()
+            .try_into()
+            .with_context(|| format!("invalid WebSocket URL: {}", url))?,
+    );
+    if let Some(proxy_url) = &proxy_url {
+        tracing::debug!(
+            "Using WS client with proxy {} to submit request to {}",
+            proxy_url,
+            url
+        );
+        builder = builder.proxy_url(proxy_url.clone());
+    } else {
+        tracing::debug!("Using WS client to submit request to: {}", url);
+    }
+    if let Some(tls) = tls {
+        if !tls.extra_root_certs_pem.is_empty() {
+            builder = builder.tls_config(tls.extra_root_certs_pem);
+        }
+    }
+
+    builder
+        .build()
         .await
-        .with_context(|| format!("failed to create WS client to: {}", url))?;
-    Ok((client, driver))
+        .with_context(|| format!("failed to create WS client to: {}", url))
 }
@@ -5,12 +5,18 @@
 //!
 //! A chain and object provider for Recall.
 
+pub mod error;
+pub mod gas_oracle;
 pub mod json_rpc;
 pub mod message;
 pub mod object;
 mod provider;
 pub mod query;
+pub mod quorum;
 pub mod response;
+pub mod retry;
+pub mod subscribe;
+pub mod trace;
 pub mod tx;
 pub mod util;
 
@@ -32,6 +32,12 @@ pub struct GasParams {
     ///
     /// The client will enforce a minimum value of 100,000 attoFIL.
     pub gas_premium: TokenAmount,
+    /// Safety margin multiplier applied to a binary-searched gas limit estimate to absorb
+    /// state drift between estimation and execution, e.g. `1.1` for a 10% margin. Only
+    /// consulted when `gas_limit` is left unset so the signer knows to estimate.
+    pub gas_search_safety_margin: f64,
+    /// Maximum number of binary-search iterations when searching for a safe gas limit.
+    pub gas_search_max_iterations: u32,
 }
 
 impl Default for GasParams {
@@ -40,6 +46,8 @@ impl Default for GasParams {
             gas_limit: 0,
             gas_fee_cap: TokenAmount::from_atto(MIN_GAS_FEE_CAP),
             gas_premium: TokenAmount::from_atto(MIN_GAS_PREMIUM),
+            gas_search_safety_margin: 1.1,
+            gas_search_max_iterations: 20,
         }
     }
 }
@@ -1,11 +1,21 @@
 // Copyright 2024 Hoku Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
 use async_trait::async_trait;
 use fvm_shared::address::Address;
 pub use iroh::net::NodeAddr;
 use reqwest::multipart::Form;
-use serde::Deserialize;
+use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize, Serializer};
+use tokio::io::{AsyncRead, AsyncSeek};
+
+/// Default chunk size for [`ObjectProvider::upload_chunked`]: objects at or below this size go
+/// through [`ObjectProvider::upload`]'s single-shot path instead.
+pub const DEFAULT_CHUNK_SIZE: u64 = 8 * 1024 * 1024;
 
 /// Provider for object interactions.
 #[async_trait]
@@ -16,6 +26,24 @@ pub trait ObjectProvider: Send + Sync {
     /// Upload an object using multipart form data.
     async fn upload(&self, body: reqwest::Body, size: u64) -> anyhow::Result<UploadResponse>;
 
+    /// Uploads an object in fixed-size chunks, retrying only the chunk that failed (with
+    /// exponential backoff) instead of the whole transfer, and resuming an interrupted upload by
+    /// asking the node which offsets of `upload_id` it already has and seeking `reader` past
+    /// them. Falls back to [`ObjectProvider::upload`] when `size` is at or below `chunk_size`.
+    ///
+    /// `upload_id` identifies the upload across retries/process restarts -- callers that want
+    /// resumability should derive it deterministically from what they're uploading (e.g. a file
+    /// path) rather than generating a fresh one per attempt.
+    async fn upload_chunked<R>(
+        &self,
+        upload_id: &str,
+        reader: R,
+        size: u64,
+        chunk_size: u64,
+    ) -> anyhow::Result<UploadResponse>
+    where
+        R: AsyncRead + AsyncSeek + Unpin + Send + 'static;
+
     /// Download an object.
     async fn download(
         &self,
@@ -27,10 +55,208 @@ pub trait ObjectProvider: Send + Sync {
 
     /// Gets the object size.
     async fn size(&self, address: Address, key: &str, height: u64) -> anyhow::Result<u64>;
+
+    /// Returns which of `parts` the node already holds for the manifest-based multipart upload
+    /// identified by `upload_id`, so a resuming caller ([`UploadManifest`]) can skip re-staging
+    /// them. Unlike [`ObjectProvider::upload_chunked`]'s offset-based resume check, parts are
+    /// addressed by content hash, so a part already staged under a different upload is
+    /// recognized too (cross-upload dedup), not just a byte-identical retry of the same one.
+    async fn staged_parts(
+        &self,
+        upload_id: &str,
+        parts: &[Blake3Hash],
+    ) -> anyhow::Result<HashSet<Blake3Hash>>;
+
+    /// Stages one content-addressed part of a manifest-based multipart upload. The node verifies
+    /// `body` hashes to `part.hash` before recording it as staged.
+    async fn upload_part(
+        &self,
+        upload_id: &str,
+        part: UploadPart,
+        body: reqwest::Body,
+    ) -> anyhow::Result<()>;
+
+    /// Finalizes a manifest-based multipart upload: the node assembles every part in `parts`
+    /// order into the final object and returns its hash, in the same shape
+    /// [`ObjectProvider::upload`] returns for a single-shot upload. Fails if any part hasn't been
+    /// staged yet (check [`ObjectProvider::staged_parts`] first).
+    async fn finalize_multipart(
+        &self,
+        upload_id: &str,
+        parts: &[UploadPart],
+        size: u64,
+    ) -> anyhow::Result<UploadResponse>;
 }
 
 #[derive(Deserialize)]
 pub struct UploadResponse {
-    pub hash: String,
-    pub metadata_hash: String,
+    pub hash: Blake3Hash,
+    pub metadata_hash: Blake3Hash,
+}
+
+/// The chunk offsets a node has already acknowledged for a chunked upload in progress, as
+/// reported by [`ObjectProvider::upload_chunked`]'s resume check.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ChunkedUploadStatus {
+    pub received_offsets: Vec<u64>,
+}
+
+/// One content-addressed part of a manifest-based multipart upload (see [`UploadManifest`]).
+/// `hash` is the BLAKE3 hash of exactly `size` bytes; only the object's final part is expected
+/// to be shorter than the manifest's `chunk_size`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UploadPart {
+    pub hash: Blake3Hash,
+    pub size: u64,
+}
+
+/// The in-progress state of a manifest-based, content-addressed multipart upload.
+///
+/// Unlike [`ChunkedUploadStatus`]'s offset-based resume (same process, same reader position),
+/// this manifest is serialized to disk after every completed part, so a process restarted
+/// mid-upload of the same object can reload it, re-derive which parts are still missing, and
+/// resume from there -- the caller only needs to be able to re-open the source (e.g. from a file
+/// path) and seek to each remaining part's offset.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadManifest {
+    pub object_key: String,
+    pub total_size: u64,
+    pub chunk_size: u64,
+    pub parts: Vec<UploadPart>,
+    pub completed: HashSet<Blake3Hash>,
+}
+
+impl UploadManifest {
+    /// Starts a new, empty manifest for an object of `total_size` bytes, split into
+    /// `chunk_size`-sized parts. Callers fill in `parts` as each part's hash is computed.
+    pub fn new(object_key: impl Into<String>, total_size: u64, chunk_size: u64) -> Self {
+        UploadManifest {
+            object_key: object_key.into(),
+            total_size,
+            chunk_size,
+            parts: Vec::new(),
+            completed: HashSet::new(),
+        }
+    }
+
+    /// The path a manifest for `object_key` is persisted to within `dir`.
+    pub fn manifest_path(dir: &Path, object_key: &str) -> PathBuf {
+        let digest = blake3::hash(object_key.as_bytes());
+        dir.join(format!("{}.upload.json", digest.to_hex()))
+    }
+
+    /// Whether every part in the manifest has been staged.
+    pub fn is_complete(&self) -> bool {
+        !self.parts.is_empty()
+            && self
+                .parts
+                .iter()
+                .all(|part| self.completed.contains(&part.hash))
+    }
+
+    /// Loads a previously persisted manifest, if one exists at `path` and matches
+    /// `object_key`/`total_size` (a mismatch means the object changed since the last attempt, so
+    /// the caller should start a fresh manifest instead of resuming a stale one).
+    pub async fn load(
+        path: &Path,
+        object_key: &str,
+        total_size: u64,
+    ) -> anyhow::Result<Option<Self>> {
+        let bytes = match tokio::fs::read(path).await {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let manifest: UploadManifest = serde_json::from_slice(&bytes)?;
+        if manifest.object_key != object_key || manifest.total_size != total_size {
+            return Ok(None);
+        }
+        Ok(Some(manifest))
+    }
+
+    /// Persists the manifest to `path`, overwriting any previous attempt.
+    pub async fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let bytes = serde_json::to_vec(self)?;
+        tokio::fs::write(path, bytes).await?;
+        Ok(())
+    }
+
+    /// Removes a persisted manifest once its upload has finalized successfully.
+    pub async fn remove(path: &Path) -> anyhow::Result<()> {
+        match tokio::fs::remove_file(path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+/// A BLAKE3 object/metadata hash, as returned by the Iroh object store.
+///
+/// Validates on [`Deserialize`] that the value is exactly 64 lowercase or uppercase hex
+/// characters (32 bytes), so a malformed or truncated hash fails to parse at the boundary
+/// instead of silently round-tripping as an opaque string. Still serializes to the same
+/// plain hex-string JSON shape for backwards compatibility.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Blake3Hash([u8; 32]);
+
+impl Blake3Hash {
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+}
+
+impl FromStr for Blake3Hash {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = hex::decode(s).map_err(|e| anyhow::anyhow!("invalid hex blake3 hash: {e}"))?;
+        let bytes: [u8; 32] = bytes
+            .try_into()
+            .map_err(|_| anyhow::anyhow!("blake3 hash must be 32 bytes (64 hex characters)"))?;
+        Ok(Blake3Hash(bytes))
+    }
+}
+
+impl fmt::Display for Blake3Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", hex::encode(self.0))
+    }
+}
+
+impl fmt::Debug for Blake3Hash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Blake3Hash({self})")
+    }
+}
+
+impl<'de> Deserialize<'de> for Blake3Hash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <&str>::deserialize(deserializer)?;
+        Self::from_str(s).map_err(DeError::custom)
+    }
+}
+
+impl Serialize for Blake3Hash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_string().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_valid_blake3_hash() {
+        let hex = "a".repeat(64);
+        let hash: Blake3Hash = hex.parse().unwrap();
+        assert_eq!(hash.to_string(), hex);
+    }
+
+    #[test]
+    fn rejects_wrong_length_or_non_hex() {
+        assert!("a".repeat(63).parse::<Blake3Hash>().is_err());
+        assert!("z".repeat(64).parse::<Blake3Hash>().is_err());
+    }
 }
@@ -11,13 +11,38 @@ use tendermint::{abci::response::DeliverTx, block::Height};
 use tendermint_proto::abci::ResponseDeliverTx;
 use tendermint_rpc::endpoint::abci_query::AbciQuery;
 
-use crate::fvm_shared::{address::Address, error::ExitCode, message::Message, ActorID};
+use crate::error::RecallError;
+use crate::fvm_shared::{address::Address, error::ExitCode, message::Message, ActorID, BLOCK_GAS_LIMIT};
 use crate::response::encode_data;
+use crate::trace::CallFrame;
 
 pub use fendermint_vm_message::query::{
     ActorState, BuiltinActors, FvmQuery, FvmQueryHeight, GasEstimate, StateParams,
 };
 
+/// Parameters controlling [`QueryProvider::estimate_gas_limit_searched`]'s binary search.
+#[derive(Debug, Clone, Copy)]
+pub struct GasSearchParams {
+    /// Multiplier applied to the converged estimate to absorb state drift between estimation
+    /// and execution, e.g. `1.1` for a 10% safety margin.
+    pub safety_margin: f64,
+    /// Stop the search once the bounds are within this fraction of the lower bound, e.g. `0.01`
+    /// for 1%.
+    pub tolerance: f64,
+    /// Maximum number of binary-search iterations before settling on the tightest bound found.
+    pub max_iterations: u32,
+}
+
+impl Default for GasSearchParams {
+    fn default() -> Self {
+        GasSearchParams {
+            safety_margin: 1.1,
+            tolerance: 0.01,
+            max_iterations: 20,
+        }
+    }
+}
+
 /// The parsed query response.
 #[derive(Debug, Clone, Serialize)]
 pub struct QueryResponse<T> {
@@ -50,6 +75,33 @@ pub trait QueryProvider: Send + Sync {
         Ok(QueryResponse { height, value })
     }
 
+    /// Run a message in a read-only fashion like [`QueryProvider::call`], but return a
+    /// structured call trace instead of the flat backtrace string, for a call-tracer-style view
+    /// of a failed (or successful) machine/bucket operation. See [`crate::trace`] for the shape
+    /// of the returned frame tree and how it's reconstructed from the node's backtrace.
+    async fn trace_call(
+        &self,
+        message: Message,
+        height: FvmQueryHeight,
+    ) -> anyhow::Result<QueryResponse<CallFrame>> {
+        let actor = message.to.to_string();
+        let method_num = Some(message.method_num);
+
+        let res = self
+            .query(FvmQuery::Call(Box::new(message)), height)
+            .await?;
+        let height = res.height;
+        let tx = extract(res, parse_deliver_tx)?;
+
+        let value = if tx.code.is_err() {
+            crate::trace::parse_backtrace(&tx.info)
+                .unwrap_or_else(|| crate::trace::success_root(actor, method_num))
+        } else {
+            crate::trace::success_root(actor, method_num)
+        };
+        Ok(QueryResponse { height, value })
+    }
+
     /// Estimate the gas limit of a message.
     async fn estimate_gas_limit(
         &self,
@@ -70,14 +122,65 @@ pub trait QueryProvider: Send + Sync {
         if estimate.exit_code.is_success() {
             Ok(estimate.gas_limit)
         } else {
-            Err(anyhow!(
-                "estimate gas returned non-zero exit code: {}; {}",
-                estimate.exit_code.value(),
+            Err(anyhow!(RecallError::actor_reverted(
+                estimate.exit_code,
                 estimate.info,
-            ))
+            )))
         }
     }
 
+    /// Binary-search for a gas limit between the node's raw [`QueryProvider::estimate_gas_limit`]
+    /// (a lower bound known to succeed) and the block gas limit (the upper bound), re-running the
+    /// call at the midpoint and narrowing the bounds based on whether it fits, until they converge
+    /// within `params.tolerance`. The converged value is scaled by `params.safety_margin` to
+    /// absorb state drift between estimation and execution. Never returns below the raw estimate,
+    /// and never above the block gas limit.
+    async fn estimate_gas_limit_searched(
+        &self,
+        message: Message,
+        height: FvmQueryHeight,
+        params: GasSearchParams,
+    ) -> anyhow::Result<u64> {
+        let lower = self.estimate_gas_limit(message.clone(), height).await?;
+        let upper = BLOCK_GAS_LIMIT;
+        if lower >= upper {
+            return Ok(lower);
+        }
+
+        let mut lo = lower;
+        let mut hi = upper;
+        for _ in 0..params.max_iterations.max(1) {
+            if hi <= lo || (hi - lo) as f64 <= lo as f64 * params.tolerance {
+                break;
+            }
+            let mid = lo + (hi - lo) / 2;
+
+            let mut probe = message.clone();
+            probe.sequence = 0;
+            probe.gas_limit = mid;
+
+            let fits = match self.query(FvmQuery::EstimateGas(Box::new(probe)), height).await {
+                Ok(res) => match extract::<GasEstimate, _>(res, |res| {
+                    fvm_ipld_encoding::from_slice(&res.value)
+                        .context("failed to decode GasEstimate from query")
+                }) {
+                    Ok(estimate) => estimate.exit_code.is_success(),
+                    Err(_) => false,
+                },
+                Err(_) => false,
+            };
+
+            if fits {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+
+        let margined = (hi as f64 * params.safety_margin).ceil() as u64;
+        Ok(margined.clamp(lower, upper))
+    }
+
     /// Query the state of an actor.
     async fn actor_state(
         &self,
@@ -149,11 +252,10 @@ where
     F: FnOnce(AbciQuery) -> anyhow::Result<T>,
 {
     if res.code.is_err() {
-        Err(anyhow!(
-            "query returned non-zero exit code: {}; {}",
-            res.code.value(),
+        Err(anyhow!(RecallError::actor_reverted(
+            ExitCode::new(res.code.value()),
             res.info,
-        ))
+        )))
     } else {
         f(res)
     }
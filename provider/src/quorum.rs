@@ -0,0 +1,452 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Multi-endpoint redundancy at two points in the stack, modeled on ethers-providers'
+//! `QuorumProvider`.
+//!
+//! A single [`JsonRpcProvider`] is only as reliable as the one node it talks to: a stale or
+//! misbehaving endpoint answers every `call`/`actor_state` query with whatever it has, and
+//! nothing catches it. [`QuorumProvider`] holds several weighted [`JsonRpcProvider`]s, queries
+//! them in turn, and only returns a response once enough combined weight agrees on it, surfacing
+//! a divergence error otherwise. Writes are simpler: there's only one copy of a broadcast
+//! transaction to submit, so [`QuorumProvider::perform`] just picks the first endpoint that
+//! answers a liveness check, starting with the designated primary.
+//!
+//! [`QuorumClient`] does the same job one layer lower, at the `tendermint_rpc::Client` transport
+//! itself, so a single [`JsonRpcProvider`] can be built directly over a set of redundant
+//! endpoints. It additionally distinguishes broadcasts (`broadcast_tx_*`, which only need one
+//! endpoint to accept) from everything else, which is either failed over across endpoints in
+//! priority order or fanned out and required to agree, per [`ClientMode`].
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use ethers::core::types as et;
+use serde::Serialize;
+use tendermint::{abci::response::DeliverTx, block::Height};
+use tendermint_rpc::{endpoint::abci_query::AbciQuery, Client, Method, Request, SimpleRequest};
+
+use crate::json_rpc::JsonRpcProvider;
+use crate::message::ChainMessage;
+use crate::query::{FvmQuery, FvmQueryHeight, QueryProvider};
+use crate::tx::{BroadcastMode, Hash, TxProvider, TxResult};
+use crate::{Provider, TendermintClient};
+
+/// A single endpoint in a [`QuorumProvider`], along with the weight its response carries when
+/// tallying agreement.
+#[derive(Clone)]
+pub struct WeightedProvider<C> {
+    pub provider: JsonRpcProvider<C>,
+    pub weight: u32,
+}
+
+impl<C> WeightedProvider<C> {
+    pub fn new(provider: JsonRpcProvider<C>, weight: u32) -> Self {
+        WeightedProvider { provider, weight }
+    }
+}
+
+/// How much combined weight has to agree on a response before [`QuorumProvider`] accepts it.
+#[derive(Debug, Clone, Copy)]
+pub enum Quorum {
+    /// More than half of the total weight must agree.
+    Majority,
+    /// Every member must agree.
+    All,
+    /// At least this much weight must agree.
+    Weighted(u32),
+}
+
+impl Quorum {
+    fn is_met(self, agreeing_weight: u32, total_weight: u32) -> bool {
+        match self {
+            Quorum::Majority => agreeing_weight * 2 > total_weight,
+            Quorum::All => agreeing_weight == total_weight,
+            Quorum::Weighted(threshold) => agreeing_weight >= threshold,
+        }
+    }
+}
+
+/// The parts of an [`AbciQuery`] response that determine whether two endpoints agree, since
+/// `AbciQuery` itself doesn't implement `PartialEq`.
+#[derive(PartialEq, Eq, Clone)]
+struct QueryFingerprint {
+    code: u32,
+    key: Vec<u8>,
+    value: Vec<u8>,
+    height: Height,
+}
+
+impl From<&AbciQuery> for QueryFingerprint {
+    fn from(res: &AbciQuery) -> Self {
+        QueryFingerprint {
+            code: res.code.value(),
+            key: res.key.clone(),
+            value: res.value.clone(),
+            height: res.height,
+        }
+    }
+}
+
+/// A [`QueryProvider`]/[`TxProvider`] backed by several weighted [`JsonRpcProvider`] endpoints.
+///
+/// Queries ([`QueryProvider::query`]) go to the members in turn; a response is accepted once
+/// enough weight agrees on it per `quorum`, and an error is returned if the members diverge.
+/// [`TxProvider::eth_tx_receipt`] returns the first receipt any member produces, without
+/// comparing it against the others. Writes ([`TxProvider::perform`]) can only be
+/// submitted once -- the decode callback the caller supplies is `FnOnce`, so it can't be retried
+/// against a second endpoint after being handed to the first -- so instead of retrying after a
+/// failed broadcast, [`QuorumProvider`] picks the first endpoint (starting with `primary`) that
+/// answers a cheap liveness check, and submits there.
+#[derive(Clone)]
+pub struct QuorumProvider<C> {
+    members: Vec<WeightedProvider<C>>,
+    quorum: Quorum,
+    primary: usize,
+}
+
+impl<C> QuorumProvider<C> {
+    /// Builds a provider over `members`, broadcasting writes to `members[0]` by default. Use
+    /// [`QuorumProvider::with_primary`] to pick a different write target.
+    pub fn new(members: Vec<WeightedProvider<C>>, quorum: Quorum) -> anyhow::Result<Self> {
+        if members.is_empty() {
+            return Err(anyhow!("QuorumProvider needs at least one member"));
+        }
+        Ok(QuorumProvider {
+            members,
+            quorum,
+            primary: 0,
+        })
+    }
+
+    /// Selects which member writes are broadcast to first.
+    pub fn with_primary(mut self, primary: usize) -> anyhow::Result<Self> {
+        if primary >= self.members.len() {
+            return Err(anyhow!(
+                "primary index {primary} out of range (have {} members)",
+                self.members.len()
+            ));
+        }
+        self.primary = primary;
+        Ok(self)
+    }
+
+    fn total_weight(&self) -> u32 {
+        self.members.iter().map(|m| m.weight).sum()
+    }
+
+    /// Order in which members are tried for a write: the primary first, then the rest.
+    fn write_order(&self) -> impl Iterator<Item = usize> + '_ {
+        std::iter::once(self.primary).chain((0..self.members.len()).filter(|&i| i != self.primary))
+    }
+}
+
+#[async_trait]
+impl<C> QueryProvider for QuorumProvider<C>
+where
+    C: Client + Send + Sync,
+{
+    async fn query(&self, query: FvmQuery, height: FvmQueryHeight) -> anyhow::Result<AbciQuery> {
+        let total_weight = self.total_weight();
+        let mut agreeing: Vec<(QueryFingerprint, u32, AbciQuery)> = Vec::new();
+        let mut errors = Vec::new();
+
+        for member in &self.members {
+            match member.provider.query(query.clone(), height).await {
+                Ok(res) => {
+                    let fingerprint = QueryFingerprint::from(&res);
+                    match agreeing.iter_mut().find(|(f, _, _)| *f == fingerprint) {
+                        Some((_, weight, _)) => *weight += member.weight,
+                        None => agreeing.push((fingerprint, member.weight, res)),
+                    }
+                }
+                Err(e) => errors.push(e.to_string()),
+            }
+            if let Some((_, _, res)) = agreeing
+                .iter()
+                .find(|(_, weight, _)| self.quorum.is_met(*weight, total_weight))
+            {
+                return Ok(res.clone());
+            }
+        }
+
+        match agreeing.into_iter().max_by_key(|(_, weight, _)| *weight) {
+            Some((_, weight, _)) => Err(anyhow!(
+                "no quorum on query response: best-agreeing endpoints only carried weight \
+                 {weight}/{total_weight}{}",
+                describe_errors(&errors)
+            )),
+            None => Err(anyhow!(
+                "all {} endpoint(s) failed{}",
+                self.members.len(),
+                describe_errors(&errors)
+            )),
+        }
+    }
+}
+
+#[async_trait]
+impl<C> TxProvider for QuorumProvider<C>
+where
+    C: Client + Send + Sync,
+{
+    async fn perform<F, T>(
+        &self,
+        message: ChainMessage,
+        broadcast_mode: BroadcastMode,
+        f: F,
+    ) -> anyhow::Result<TxResult<T>>
+    where
+        F: FnOnce(&DeliverTx) -> anyhow::Result<T> + Sync + Send,
+        T: Sync + Send,
+    {
+        let target = self.healthy_write_target().await?;
+        target.perform(message, broadcast_mode, f).await
+    }
+
+    async fn eth_tx_receipt(&self, hash: Hash, prove: bool) -> anyhow::Result<et::TransactionReceipt> {
+        let mut errors = Vec::new();
+        for member in &self.members {
+            match member.provider.eth_tx_receipt(hash, prove).await {
+                Ok(receipt) => return Ok(receipt),
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+        Err(anyhow!(
+            "all {} endpoint(s) failed to produce a receipt{}",
+            self.members.len(),
+            describe_errors(&errors)
+        ))
+    }
+}
+
+impl<C> QuorumProvider<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Returns the first member (starting with `primary`) that answers a `status` call, i.e. has
+    /// a live connection to submit a broadcast to.
+    async fn healthy_write_target(&self) -> anyhow::Result<&JsonRpcProvider<C>> {
+        let mut errors = Vec::new();
+        for idx in self.write_order() {
+            let member = &self.members[idx];
+            match member.provider.underlying().status().await {
+                Ok(_) => return Ok(&member.provider),
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+        Err(anyhow!(
+            "no healthy endpoint available to broadcast to{}",
+            describe_errors(&errors)
+        ))
+    }
+}
+
+impl<C> TendermintClient<C> for QuorumProvider<C>
+where
+    C: Client + Send + Sync,
+{
+    fn underlying(&self) -> &C {
+        self.members[self.primary].provider.underlying()
+    }
+}
+
+impl<C> Provider<C> for QuorumProvider<C> where C: Client + Send + Sync {}
+
+fn describe_errors(errors: &[String]) -> String {
+    if errors.is_empty() {
+        String::new()
+    } else {
+        format!(" ({} error(s): {})", errors.len(), errors.join("; "))
+    }
+}
+
+/// A single endpoint in a [`QuorumClient`], along with the weight its response carries when
+/// tallying agreement and its priority when failing over (higher weight is preferred).
+#[derive(Clone)]
+pub struct WeightedClient<C> {
+    pub client: C,
+    pub weight: u32,
+}
+
+impl<C> WeightedClient<C> {
+    pub fn new(client: C, weight: u32) -> Self {
+        WeightedClient { client, weight }
+    }
+}
+
+/// How [`QuorumClient`] dispatches a non-broadcast request across its members.
+#[derive(Debug, Clone, Copy)]
+pub enum ClientMode {
+    /// Try members in priority order (highest weight first), advancing to the next one on
+    /// error. Cheapest, but trusts whichever endpoint answers first.
+    Failover,
+    /// Fan out to every member and only return a response once this much weight agrees on it
+    /// byte-for-byte, rejecting the request if the members diverge.
+    Quorum(u32),
+}
+
+/// A `tendermint_rpc::`[`Client`] backed by several weighted inner clients, so a single
+/// [`JsonRpcProvider`] can be built directly over a redundant set of endpoints instead of each
+/// caller hand-rolling client rotation.
+///
+/// [`Client::perform`] is generic over any request type, including broadcasts, so `QuorumClient`
+/// has to tell them apart itself: a `broadcast_tx_async`/`_sync`/`_commit` request is sent to
+/// every member in turn and succeeds if at least one accepts it, since there's only one copy of the
+/// transaction to submit and a single acceptance is all that matters. Every other request (an
+/// `abci_query`, `block_results`, `header`, `status`, ...) goes through `mode` instead.
+///
+/// `Client::perform`'s request type `R` isn't required to be `Clone`, so sending the same logical
+/// request to more than one member reuses the JSON-snapshot-and-reconstruct approach from
+/// [`crate::retry::RetryClient`].
+#[derive(Clone)]
+pub struct QuorumClient<C> {
+    members: Vec<WeightedClient<C>>,
+    mode: ClientMode,
+}
+
+impl<C> QuorumClient<C> {
+    pub fn new(members: Vec<WeightedClient<C>>, mode: ClientMode) -> anyhow::Result<Self> {
+        if members.is_empty() {
+            return Err(anyhow!("QuorumClient needs at least one member"));
+        }
+        Ok(QuorumClient { members, mode })
+    }
+
+    fn total_weight(&self) -> u32 {
+        self.members.iter().map(|m| m.weight).sum()
+    }
+
+    /// Indices into `members`, highest weight first, ties broken by original order.
+    fn priority_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.members.len()).collect();
+        order.sort_by(|&a, &b| self.members[b].weight.cmp(&self.members[a].weight));
+        order
+    }
+}
+
+/// A `broadcast_tx_async`/`_sync`/`_commit` only needs one endpoint to accept it; everything else
+/// goes through [`ClientMode`].
+fn is_broadcast<R: Request>(request: &R) -> bool {
+    matches!(
+        request.method(),
+        Method::BroadcastTxAsync | Method::BroadcastTxSync | Method::BroadcastTxCommit
+    )
+}
+
+fn snapshot<R: SimpleRequest>(request: &R) -> Result<Vec<u8>, tendermint_rpc::Error> {
+    serde_json::to_vec(request).map_err(|e| tendermint_rpc::Error::parse_error(e.to_string()))
+}
+
+fn rebuild<R: SimpleRequest>(snapshot: &[u8]) -> Result<R, tendermint_rpc::Error> {
+    serde_json::from_slice(snapshot).map_err(|e| tendermint_rpc::Error::parse_error(e.to_string()))
+}
+
+impl<C> QuorumClient<C>
+where
+    C: Client + Send + Sync,
+{
+    async fn broadcast<R>(&self, request: R) -> Result<R::Response, tendermint_rpc::Error>
+    where
+        R: SimpleRequest,
+    {
+        let snapshot = snapshot(&request)?;
+        drop(request);
+        let mut first_ok = None;
+        let mut errors = Vec::new();
+        for member in &self.members {
+            let request: R = rebuild(&snapshot)?;
+            match member.client.perform(request).await {
+                Ok(res) => {
+                    if first_ok.is_none() {
+                        first_ok = Some(res);
+                    }
+                }
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+        first_ok.ok_or_else(|| {
+            tendermint_rpc::Error::io_error(format!(
+                "QuorumClient: broadcast rejected by every member{}",
+                describe_errors(&errors)
+            ))
+        })
+    }
+
+    async fn failover<R>(&self, request: R) -> Result<R::Response, tendermint_rpc::Error>
+    where
+        R: SimpleRequest,
+    {
+        let snapshot = snapshot(&request)?;
+        drop(request);
+        let mut errors = Vec::new();
+        for idx in self.priority_order() {
+            let request: R = rebuild(&snapshot)?;
+            match self.members[idx].client.perform(request).await {
+                Ok(res) => return Ok(res),
+                Err(e) => errors.push(e.to_string()),
+            }
+        }
+        Err(tendermint_rpc::Error::io_error(format!(
+            "QuorumClient: all {} endpoint(s) failed{}",
+            self.members.len(),
+            describe_errors(&errors)
+        )))
+    }
+
+    async fn quorum<R>(&self, request: R, threshold: u32) -> Result<R::Response, tendermint_rpc::Error>
+    where
+        R: SimpleRequest,
+        R::Response: Serialize,
+    {
+        let snapshot = snapshot(&request)?;
+        drop(request);
+        let total_weight = self.total_weight();
+        let mut agreeing: Vec<(Vec<u8>, u32, R::Response)> = Vec::new();
+        let mut errors = Vec::new();
+
+        for member in &self.members {
+            let request: R = rebuild(&snapshot)?;
+            match member.client.perform(request).await {
+                Ok(res) => {
+                    let fingerprint = serde_json::to_vec(&res)
+                        .map_err(|e| tendermint_rpc::Error::parse_error(e.to_string()))?;
+                    match agreeing.iter_mut().find(|(f, _, _)| *f == fingerprint) {
+                        Some((_, weight, _)) => *weight += member.weight,
+                        None => agreeing.push((fingerprint, member.weight, res)),
+                    }
+                }
+                Err(e) => errors.push(e.to_string()),
+            }
+            if agreeing.iter().any(|(_, weight, _)| *weight >= threshold) {
+                break;
+            }
+        }
+
+        match agreeing.into_iter().find(|(_, weight, _)| *weight >= threshold) {
+            Some((_, _, res)) => Ok(res),
+            None => Err(tendermint_rpc::Error::io_error(format!(
+                "QuorumClient: no {threshold}/{total_weight} agreement on response{}",
+                describe_errors(&errors)
+            ))),
+        }
+    }
+}
+
+#[async_trait]
+impl<C> Client for QuorumClient<C>
+where
+    C: Client + Send + Sync,
+{
+    async fn perform<R>(&self, request: R) -> Result<R::Response, tendermint_rpc::Error>
+    where
+        R: SimpleRequest,
+    {
+        if is_broadcast(&request) {
+            return self.broadcast(request).await;
+        }
+        match self.mode {
+            ClientMode::Failover => self.failover(request).await,
+            ClientMode::Quorum(threshold) => self.quorum(request, threshold).await,
+        }
+    }
+}
@@ -10,6 +10,7 @@ use base64::Engine;
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 use tendermint::abci::Code;
 
+use crate::error::RecallError;
 use crate::fvm_ipld_encoding::RawBytes;
 
 pub use bytes::Bytes;
@@ -38,11 +39,10 @@ pub fn decode_bytes(deliver_tx: &DeliverTx) -> anyhow::Result<RawBytes> {
     match deliver_tx.code {
         Code::Ok => decode_data(&deliver_tx.data),
         Code::Err(code) => {
-            bail!(
-                "error executing request: code={} message={}",
-                code,
-                deliver_tx.info
-            )
+            bail!(RecallError::actor_reverted(
+                crate::fvm_shared::error::ExitCode::new(code.into()),
+                deliver_tx.info.clone(),
+            ))
         }
     }
 }
@@ -58,8 +58,12 @@ where
     T: for<'de> Deserialize<'de> + Into<T>,
 {
     let data = decode_data(&deliver_tx.data)?;
-    fvm_ipld_encoding::from_slice::<T>(&data)
-        .map_err(|e| anyhow!("error parsing data as {}: {e}", std::any::type_name::<T>()))
+    fvm_ipld_encoding::from_slice::<T>(&data).map_err(|e| {
+        anyhow!(RecallError::decode(format!(
+            "error parsing data as {}: {e}",
+            std::any::type_name::<T>()
+        )))
+    })
 }
 
 /// JSON serialization friendly version of [`cid::Cid`].
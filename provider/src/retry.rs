@@ -0,0 +1,302 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Retry-with-backoff layers, modeled on ethers-providers' `RetryClient`, at two points in the
+//! stack.
+//!
+//! A long-running flow like `Bucket::add_from_path` or a deposit's balance-polling loop makes
+//! many RPC calls in a row, and a single rate limit (HTTP 429) or momentary 5xx in the middle of
+//! it currently fails the whole flow -- today only [`JsonRpcProvider::eth_tx_receipt`] guards
+//! against this, with an ad-hoc `retry(new_backoff_policy(...))` call. [`RetryProvider`] wraps a
+//! [`JsonRpcProvider`] at the [`QueryProvider`]/[`TxProvider`] level and retries only the errors
+//! worth retrying -- a transport failure, not a decode failure or an actor revert -- with
+//! exponential backoff and jitter, up to a configurable attempt count and time budget.
+//! [`RetryClient`] does the same thing one layer lower, at the `tendermint_rpc::Client`
+//! transport itself, so every call through it -- `query`, `broadcast_tx_*`, `block_results`,
+//! `header`, and anything else built on [`Client::perform`] -- gets the same backoff instead of
+//! each call site having to opt in individually.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use backoff::{future::retry, ExponentialBackoff};
+use ethers::core::types as et;
+use tendermint::abci::response::DeliverTx;
+use tendermint_rpc::{endpoint::abci_query::AbciQuery, Client, SimpleRequest};
+
+use crate::error::RecallError;
+use crate::json_rpc::JsonRpcProvider;
+use crate::message::ChainMessage;
+use crate::query::{FvmQuery, FvmQueryHeight, QueryProvider};
+use crate::tx::{BroadcastMode, Hash, TxProvider, TxResult};
+use crate::{Provider, TendermintClient};
+
+/// Tuning knobs for [`RetryProvider`]'s backoff.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Delay before the first retry.
+    pub initial_interval: Duration,
+    /// Delay is multiplied by this after every retry, up to `max_interval`.
+    pub multiplier: f64,
+    /// Upper bound on the delay between retries.
+    pub max_interval: Duration,
+    /// Stop retrying once this much time has passed since the first attempt.
+    pub max_elapsed: Duration,
+    /// Stop retrying once this many attempts have been made, regardless of `max_elapsed`.
+    pub max_attempts: u32,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            initial_interval: Duration::from_millis(200),
+            multiplier: 2.0,
+            max_interval: Duration::from_secs(10),
+            max_elapsed: Duration::from_secs(30),
+            max_attempts: 5,
+        }
+    }
+}
+
+impl RetryPolicy {
+    fn backoff(&self) -> ExponentialBackoff {
+        let mut eb = ExponentialBackoff {
+            initial_interval: self.initial_interval,
+            multiplier: self.multiplier,
+            max_interval: self.max_interval,
+            max_elapsed_time: Some(self.max_elapsed),
+            ..Default::default()
+        };
+        eb.reset();
+        eb
+    }
+}
+
+/// A [`QueryProvider`]/[`TxProvider`] that retries a [`JsonRpcProvider`]'s retryable failures
+/// (rate limits, timeouts, connection resets) with exponential backoff, and lets terminal
+/// failures (a decode error, an actor revert, an invalid-params error) through immediately.
+#[derive(Clone)]
+pub struct RetryProvider<C> {
+    inner: JsonRpcProvider<C>,
+    policy: RetryPolicy,
+}
+
+impl<C> RetryProvider<C> {
+    /// Wraps `inner` with [`RetryPolicy::default`].
+    pub fn new(inner: JsonRpcProvider<C>) -> Self {
+        RetryProvider::with_policy(inner, RetryPolicy::default())
+    }
+
+    /// Wraps `inner` with a custom retry policy.
+    pub fn with_policy(inner: JsonRpcProvider<C>, policy: RetryPolicy) -> Self {
+        RetryProvider { inner, policy }
+    }
+}
+
+/// Sorts an error from the wrapped provider into retryable vs. terminal, counting `attempts`
+/// against the policy's cap so a endlessly-transient failure still gives up eventually.
+fn classify(err: anyhow::Error, attempts: &mut u32, policy: &RetryPolicy) -> backoff::Error<anyhow::Error> {
+    *attempts += 1;
+    if *attempts >= policy.max_attempts {
+        return backoff::Error::permanent(err);
+    }
+    match err.downcast_ref::<RecallError>() {
+        // Deserialization failures and actor/validation errors are the node telling us
+        // definitively what happened; retrying won't change the answer.
+        Some(RecallError::Decode(_))
+        | Some(RecallError::ActorReverted { .. })
+        | Some(RecallError::InvalidParams(_)) => backoff::Error::permanent(err),
+        // Everything else -- most importantly `RecallError::Transport`, but also a raw
+        // connection/timeout error that never made it into a `RecallError` -- is worth a retry.
+        _ => {
+            let retry_after = parse_retry_after(&err.to_string());
+            backoff::Error::Transient { err, retry_after }
+        }
+    }
+}
+
+/// Looks for a `Retry-After: <seconds>` rate-limit hint in an error's message. The HTTP
+/// response's headers aren't threaded through `RecallError::Transport`, only its stringified
+/// body/status, so this is a best-effort scrape rather than a real header read.
+fn parse_retry_after(message: &str) -> Option<Duration> {
+    let lower = message.to_ascii_lowercase();
+    let after = lower.find("retry-after")?;
+    let digits: String = lower[after + "retry-after".len()..]
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+    digits.parse().ok().map(Duration::from_secs)
+}
+
+#[async_trait]
+impl<C> QueryProvider for RetryProvider<C>
+where
+    C: Client + Send + Sync,
+{
+    async fn query(&self, query: FvmQuery, height: FvmQueryHeight) -> anyhow::Result<AbciQuery> {
+        let mut attempts = 0u32;
+        retry(self.policy.backoff(), || {
+            let attempts = &mut attempts;
+            async move {
+                self.inner
+                    .query(query.clone(), height)
+                    .await
+                    .map_err(|e| classify(e, attempts, &self.policy))
+            }
+        })
+        .await
+        .map_err(Into::into)
+    }
+}
+
+#[async_trait]
+impl<C> TxProvider for RetryProvider<C>
+where
+    C: Client + Send + Sync,
+{
+    async fn perform<F, T>(
+        &self,
+        message: ChainMessage,
+        broadcast_mode: BroadcastMode,
+        f: F,
+    ) -> anyhow::Result<TxResult<T>>
+    where
+        F: FnOnce(&DeliverTx) -> anyhow::Result<T> + Sync + Send,
+        T: Sync + Send,
+    {
+        // `f` decodes the `DeliverTx` exactly once, on the attempt whose broadcast actually
+        // committed -- once that's happened the message is on chain and must not be rebroadcast,
+        // so `decode` below turns a second invocation into a (permanent) error instead of
+        // retrying the whole send again.
+        let f = std::sync::Mutex::new(Some(f));
+        let mut attempts = 0u32;
+        retry(self.policy.backoff(), || {
+            let attempts = &mut attempts;
+            let f = &f;
+            async move {
+                let decode = |deliver_tx: &DeliverTx| match f.lock().unwrap().take() {
+                    Some(f) => f(deliver_tx),
+                    None => Err(anyhow!(RecallError::decode(
+                        "broadcast already committed on an earlier attempt"
+                    ))),
+                };
+                self.inner
+                    .perform(message.clone(), broadcast_mode, decode)
+                    .await
+                    .map_err(|e| classify(e, attempts, &self.policy))
+            }
+        })
+        .await
+        .map_err(Into::into)
+    }
+
+    async fn eth_tx_receipt(&self, hash: Hash, prove: bool) -> anyhow::Result<et::TransactionReceipt> {
+        let mut attempts = 0u32;
+        retry(self.policy.backoff(), || {
+            let attempts = &mut attempts;
+            async move {
+                self.inner
+                    .eth_tx_receipt(hash, prove)
+                    .await
+                    .map_err(|e| classify(e, attempts, &self.policy))
+            }
+        })
+        .await
+        .map_err(Into::into)
+    }
+}
+
+impl<C> TendermintClient<C> for RetryProvider<C>
+where
+    C: Client + Send + Sync,
+{
+    fn underlying(&self) -> &C {
+        self.inner.underlying()
+    }
+}
+
+impl<C> Provider<C> for RetryProvider<C> where C: Client + Send + Sync {}
+
+/// A `tendermint_rpc::`[`Client`] that wraps any inner transport (e.g. `HttpClient`,
+/// `UnixSocketClient`) and retries a transient failure -- a connection reset, a timeout, an HTTP
+/// 429/5xx -- with exponential backoff, instead of leaving every caller built on
+/// [`Client::perform`] to fail on the first blip.
+///
+/// `Client::perform`'s request type `R` isn't required to be `Clone`, so a retried attempt can't
+/// just re-send the same value; instead each attempt rebuilds `R` from a JSON snapshot taken up
+/// front, using only the `Serialize + DeserializeOwned` bounds `R` already carries as a
+/// `tendermint_rpc::Request`.
+#[derive(Clone)]
+pub struct RetryClient<C> {
+    inner: C,
+    policy: RetryPolicy,
+}
+
+impl<C> RetryClient<C> {
+    /// Wraps `inner` with [`RetryPolicy::default`].
+    pub fn new(inner: C) -> Self {
+        RetryClient::with_policy(inner, RetryPolicy::default())
+    }
+
+    /// Wraps `inner` with a custom retry policy.
+    pub fn with_policy(inner: C, policy: RetryPolicy) -> Self {
+        RetryClient { inner, policy }
+    }
+}
+
+/// Sorts a transport-level error into retryable vs. fatal by scanning its message for known
+/// markers, since `tendermint_rpc::Error`'s variants don't expose a stable, matchable HTTP status
+/// code. Connection resets, timeouts, 429s and 5xx responses are retried; anything else (a 4xx, a
+/// decode/parse error) is treated as fatal.
+fn classify_client_error(
+    err: tendermint_rpc::Error,
+    attempts: &mut u32,
+    policy: &RetryPolicy,
+) -> backoff::Error<tendermint_rpc::Error> {
+    *attempts += 1;
+    if *attempts >= policy.max_attempts {
+        return backoff::Error::permanent(err);
+    }
+    let message = err.to_string();
+    let lower = message.to_ascii_lowercase();
+    let retryable = ["connection reset", "timed out", "timeout", "429", "too many requests"]
+        .iter()
+        .any(|marker| lower.contains(marker))
+        || (500..600).any(|code| lower.contains(&code.to_string()));
+    if !retryable {
+        return backoff::Error::permanent(err);
+    }
+    let retry_after = parse_retry_after(&message);
+    backoff::Error::Transient { err, retry_after }
+}
+
+#[async_trait]
+impl<C> Client for RetryClient<C>
+where
+    C: Client + Send + Sync,
+{
+    async fn perform<R>(&self, request: R) -> Result<R::Response, tendermint_rpc::Error>
+    where
+        R: SimpleRequest,
+    {
+        let snapshot = serde_json::to_vec(&request)
+            .map_err(|e| tendermint_rpc::Error::parse_error(e.to_string()))?;
+        let mut attempts = 0u32;
+        retry(self.policy.backoff(), || {
+            let attempts = &mut attempts;
+            let snapshot = &snapshot;
+            async move {
+                let request: R = serde_json::from_slice(snapshot).map_err(|e| {
+                    backoff::Error::permanent(tendermint_rpc::Error::parse_error(e.to_string()))
+                })?;
+                self.inner
+                    .perform(request)
+                    .await
+                    .map_err(|e| classify_client_error(e, attempts, &self.policy))
+            }
+        })
+        .await
+    }
+}
@@ -0,0 +1,328 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Live WebSocket subscriptions for newly finalized blocks and committed transactions.
+//!
+//! [`JsonRpcProvider`] queries and broadcasts over HTTP (or a Unix socket), which has no way to
+//! push events to a caller -- watching for a transaction today means polling
+//! [`TxProvider::eth_tx_receipt`] in a loop. [`JsonRpcProvider::subscribe_blocks`] and
+//! [`JsonRpcProvider::subscribe_txs`] open a dedicated `tendermint_rpc::WebSocketClient`
+//! connection instead, spawn its driver on a background task, and turn the raw CometBFT
+//! `NewBlock`/`Tx` events into a [`Stream`] of the same Ethereum-shaped types
+//! [`TxProvider::eth_tx_receipt`] already returns.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use anyhow::{anyhow, Context as _};
+use ethers::core::types as et;
+use futures_core::{ready, Stream};
+use pin_project::pin_project;
+use tendermint::block::Header;
+use tendermint_rpc::{
+    event::{Event, EventData},
+    query::{EventType, Query},
+    Client, Subscription, SubscriptionClient, WebSocketClient, WebSocketClientUrl,
+};
+use tokio::task::JoinHandle;
+
+use crate::json_rpc::{ws_client, JsonRpcProvider, WsTlsConfig};
+use crate::tx::{Hash, TxProvider};
+
+impl<C> JsonRpcProvider<C>
+where
+    C: Client + Send + Sync,
+{
+    /// Opens a dedicated WebSocket connection to `ws_url` (through `proxy_url`/the
+    /// `HTTP_PROXY`/`HTTPS_PROXY` environment variables, and trusting any extra roots in `tls`,
+    /// per [`ws_client`]) and subscribes to every newly finalized block's header.
+    pub async fn subscribe_blocks<U>(
+        &self,
+        ws_url: U,
+        proxy_url: Option<tendermint_rpc::Url>,
+        tls: Option<WsTlsConfig>,
+    ) -> anyhow::Result<BlockSubscription>
+    where
+        U: TryInto<WebSocketClientUrl, Error = tendermint_rpc::Error> + std::fmt::Display + Clone,
+    {
+        let (client, driver_handle) = connect(ws_url, proxy_url, tls).await?;
+        let subscription = client
+            .subscribe(Query::from(EventType::NewBlock))
+            .await
+            .context("failed to subscribe to new blocks")?;
+        Ok(BlockSubscription {
+            subscription,
+            client,
+            driver_handle: Some(driver_handle),
+        })
+    }
+
+    /// Opens a dedicated WebSocket connection to `ws_url` (through `proxy_url`/the
+    /// `HTTP_PROXY`/`HTTPS_PROXY` environment variables, and trusting any extra roots in `tls`,
+    /// per [`ws_client`]) and subscribes to committed transactions matching `query` (e.g.
+    /// `Query::from(EventType::Tx)` for every transaction), decoding each into the same
+    /// [`et::TransactionReceipt`] shape [`TxProvider::eth_tx_receipt`] returns.
+    pub async fn subscribe_txs<U>(
+        &self,
+        ws_url: U,
+        query: Query,
+        proxy_url: Option<tendermint_rpc::Url>,
+        tls: Option<WsTlsConfig>,
+    ) -> anyhow::Result<TxSubscription<C>>
+    where
+        U: TryInto<WebSocketClientUrl, Error = tendermint_rpc::Error> + std::fmt::Display + Clone,
+    {
+        let (client, driver_handle) = connect(ws_url, proxy_url, tls).await?;
+        let subscription = client
+            .subscribe(query)
+            .await
+            .context("failed to subscribe to transactions")?;
+        Ok(TxSubscription {
+            subscription,
+            client,
+            driver_handle: Some(driver_handle),
+            provider: self.clone(),
+            pending: None,
+        })
+    }
+
+    /// Like [`Self::subscribe_txs`], but yields each matching transaction's raw indexed CometBFT
+    /// attributes instead of decoding it into an Ethereum-shaped receipt. Use this for
+    /// FVM-native actor calls -- e.g. a `recall subnet config set` or a batched credit-debit
+    /// sweep -- that have no `eth_getTransactionReceipt` counterpart for
+    /// [`Self::subscribe_txs`] to fetch.
+    pub async fn subscribe_tx_events<U>(
+        &self,
+        ws_url: U,
+        query: Query,
+        proxy_url: Option<tendermint_rpc::Url>,
+        tls: Option<WsTlsConfig>,
+    ) -> anyhow::Result<TxEventSubscription>
+    where
+        U: TryInto<WebSocketClientUrl, Error = tendermint_rpc::Error> + std::fmt::Display + Clone,
+    {
+        let (client, driver_handle) = connect(ws_url, proxy_url, tls).await?;
+        let subscription = client
+            .subscribe(query)
+            .await
+            .context("failed to subscribe to transaction events")?;
+        Ok(TxEventSubscription {
+            subscription,
+            client,
+            driver_handle: Some(driver_handle),
+        })
+    }
+}
+
+async fn connect<U>(
+    ws_url: U,
+    proxy_url: Option<tendermint_rpc::Url>,
+    tls: Option<WsTlsConfig>,
+) -> anyhow::Result<(
+    WebSocketClient,
+    JoinHandle<Result<(), tendermint_rpc::Error>>,
+)>
+where
+    U: TryInto<WebSocketClientUrl, Error = tendermint_rpc::Error> + std::fmt::Display + Clone,
+{
+    let (client, driver) = ws_client(ws_url, proxy_url, tls).await?;
+    let driver_handle = tokio::spawn(driver.run());
+    Ok((client, driver_handle))
+}
+
+/// Tears down a subscription's background WebSocket driver task, surfacing a panic or a driver
+/// error instead of silently dropping it.
+async fn close_driver(
+    driver_handle: JoinHandle<Result<(), tendermint_rpc::Error>>,
+) -> anyhow::Result<()> {
+    driver_handle
+        .await
+        .context("WebSocket driver task panicked")?
+        .context("WebSocket driver exited with an error")
+}
+
+/// The `tx.hash` attribute CometBFT's indexer always attaches to a `Tx` event, parsed into the
+/// same [`Hash`] type [`TxProvider::eth_tx_receipt`] takes.
+fn event_tx_hash(event: &Event) -> anyhow::Result<Hash> {
+    let attrs = event
+        .events
+        .as_ref()
+        .ok_or_else(|| anyhow!("tx subscription event has no indexed attributes"))?;
+    let raw = attrs
+        .get("tx.hash")
+        .and_then(|values| values.first())
+        .ok_or_else(|| anyhow!("tx subscription event is missing a tx.hash attribute"))?;
+    raw.parse()
+        .with_context(|| format!("failed to parse tx hash {raw:?} from subscription event"))
+}
+
+/// A live stream of newly finalized block headers, opened by [`JsonRpcProvider::subscribe_blocks`].
+#[pin_project]
+pub struct BlockSubscription {
+    #[pin]
+    subscription: Subscription,
+    client: WebSocketClient,
+    driver_handle: Option<JoinHandle<Result<(), tendermint_rpc::Error>>>,
+}
+
+impl BlockSubscription {
+    /// Tears down the underlying WebSocket connection cleanly, waiting for the driver task to
+    /// exit.
+    pub async fn close(mut self) -> anyhow::Result<()> {
+        let handle = self
+            .driver_handle
+            .take()
+            .expect("BlockSubscription::close called more than once");
+        self.client
+            .close()
+            .context("failed to close WebSocket client")?;
+        close_driver(handle).await
+    }
+}
+
+impl Stream for BlockSubscription {
+    type Item = anyhow::Result<Header>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.subscription.poll_next(cx)) {
+            None => None,
+            Some(Err(e)) => Some(Err(anyhow!(e).context("WebSocket subscription error"))),
+            Some(Ok(event)) => Some(match event.data {
+                EventData::NewBlock {
+                    block: Some(block), ..
+                } => Ok(block.header),
+                other => Err(anyhow!(
+                    "unexpected event on block subscription: {other:?}"
+                )),
+            }),
+        })
+    }
+}
+
+/// A live stream of committed transactions, decoded into [`et::TransactionReceipt`], opened by
+/// [`JsonRpcProvider::subscribe_txs`].
+#[pin_project]
+pub struct TxSubscription<C> {
+    #[pin]
+    subscription: Subscription,
+    client: WebSocketClient,
+    driver_handle: Option<JoinHandle<Result<(), tendermint_rpc::Error>>>,
+    provider: JsonRpcProvider<C>,
+    /// Set while a matched event's receipt is being fetched over the provider's regular (HTTP
+    /// or Unix-socket) transport, since that's an async call this `Stream` has to drive itself.
+    pending: Option<Pin<Box<dyn Future<Output = anyhow::Result<et::TransactionReceipt>> + Send>>>,
+}
+
+impl<C> TxSubscription<C> {
+    /// Tears down the underlying WebSocket connection cleanly, waiting for the driver task to
+    /// exit.
+    pub async fn close(mut self) -> anyhow::Result<()> {
+        let handle = self
+            .driver_handle
+            .take()
+            .expect("TxSubscription::close called more than once");
+        self.client
+            .close()
+            .context("failed to close WebSocket client")?;
+        close_driver(handle).await
+    }
+}
+
+impl<C> Stream for TxSubscription<C>
+where
+    C: Client + Send + Sync + Clone + 'static,
+{
+    type Item = anyhow::Result<et::TransactionReceipt>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if let Some(fut) = this.pending.as_mut() {
+                let result = ready!(fut.as_mut().poll(cx));
+                *this.pending = None;
+                return Poll::Ready(Some(result));
+            }
+
+            return match ready!(this.subscription.as_mut().poll_next(cx)) {
+                None => Poll::Ready(None),
+                Some(Err(e)) => Poll::Ready(Some(Err(anyhow!(e).context("WebSocket subscription error")))),
+                Some(Ok(event)) => match event_tx_hash(&event) {
+                    Err(e) => Poll::Ready(Some(Err(e))),
+                    Ok(hash) => {
+                        let provider = this.provider.clone();
+                        *this.pending =
+                            Some(Box::pin(async move { provider.eth_tx_receipt(hash, false).await }));
+                        continue;
+                    }
+                },
+            };
+        }
+    }
+}
+
+/// One committed transaction's indexed CometBFT attributes, as delivered to a
+/// [`TxEventSubscription`].
+#[derive(Debug, Clone)]
+pub struct TxEvent {
+    /// The block height the transaction committed at, parsed from the standard `tx.height`
+    /// attribute every CometBFT app attaches to a `Tx` event.
+    pub height: Option<i64>,
+    /// The transaction's hash, parsed from the standard `tx.hash` attribute.
+    pub hash: Option<Hash>,
+    /// Every attribute the chain indexed for this transaction, keyed by `<event_type>.<key>`
+    /// (e.g. `tx.height`, `tx.hash`), with each key's raw string values in emission order.
+    pub attributes: HashMap<String, Vec<String>>,
+}
+
+/// A live stream of every committed transaction's raw indexed attributes, opened by
+/// [`JsonRpcProvider::subscribe_tx_events`].
+#[pin_project]
+pub struct TxEventSubscription {
+    #[pin]
+    subscription: Subscription,
+    client: WebSocketClient,
+    driver_handle: Option<JoinHandle<Result<(), tendermint_rpc::Error>>>,
+}
+
+impl TxEventSubscription {
+    /// Tears down the underlying WebSocket connection cleanly, waiting for the driver task to
+    /// exit.
+    pub async fn close(mut self) -> anyhow::Result<()> {
+        let handle = self
+            .driver_handle
+            .take()
+            .expect("TxEventSubscription::close called more than once");
+        self.client
+            .close()
+            .context("failed to close WebSocket client")?;
+        close_driver(handle).await
+    }
+}
+
+impl Stream for TxEventSubscription {
+    type Item = anyhow::Result<TxEvent>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        Poll::Ready(match ready!(this.subscription.poll_next(cx)) {
+            None => None,
+            Some(Err(e)) => Some(Err(anyhow!(e).context("WebSocket subscription error"))),
+            Some(Ok(event)) => {
+                let attributes = event.events.clone().unwrap_or_default();
+                let height = attributes
+                    .get("tx.height")
+                    .and_then(|values| values.first())
+                    .and_then(|v| v.parse::<i64>().ok());
+                let hash = event_tx_hash(&event).ok();
+                Some(Ok(TxEvent {
+                    height,
+                    hash,
+                    attributes,
+                }))
+            }
+        })
+    }
+}
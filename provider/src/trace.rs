@@ -0,0 +1,122 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Structured call tracing for [`crate::query::QueryProvider::trace_call`], built on top of the
+//! same `info` backtrace string that [`crate::response`] already base64-decodes out of
+//! `DeliverTx`. The node only reports a flat, outermost-to-innermost backtrace rather than a
+//! true per-frame execution trace, so each backtrace line becomes one [`CallFrame`], nested
+//! under the previous one in call order.
+
+use serde::Serialize;
+
+/// A single frame in a [`crate::query::QueryProvider::trace_call`] call trace.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct CallFrame {
+    /// The invoked actor, as reported by the node (e.g. `t0119`).
+    pub actor: String,
+    /// The invoked method number, if the backtrace line carried one.
+    pub method_num: Option<u64>,
+    /// The FVM exit code this frame aborted with, if any.
+    pub exit_code: Option<u32>,
+    /// The decoded revert reason for this frame, if any.
+    pub reason: Option<String>,
+    /// Nested calls made by this frame, ordered outer-to-inner as reported by the node.
+    pub subcalls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    /// A single successful frame with no further detail, for the happy path where the node
+    /// reports no backtrace at all.
+    fn success(actor: String, method_num: Option<u64>) -> Self {
+        CallFrame {
+            actor,
+            method_num,
+            exit_code: None,
+            reason: None,
+            subcalls: Vec::new(),
+        }
+    }
+}
+
+/// Parse a node backtrace string (e.g. `"00: t0119 (method 3844450837) -- contract reverted
+/// (33)\n01: ..."`) into a nested [`CallFrame`] tree, one frame per line, each nested under the
+/// previous so the outermost call is the root and the innermost (where the abort happened) is
+/// the deepest leaf. Returns `None` if `info` doesn't look like a backtrace (e.g. it's empty).
+pub fn parse_backtrace(info: &str) -> Option<CallFrame> {
+    let mut frames: Vec<CallFrame> = info
+        .lines()
+        .filter_map(|line| parse_backtrace_line(line))
+        .collect();
+
+    let mut root = frames.pop()?;
+    while let Some(parent) = frames.pop() {
+        root = CallFrame {
+            subcalls: vec![root],
+            ..parent
+        };
+    }
+    Some(root)
+}
+
+/// Parse a single line of the form `"NN: tACTOR (method METHOD) -- REASON (CODE)"`.
+fn parse_backtrace_line(line: &str) -> Option<CallFrame> {
+    let (_idx, rest) = line.split_once(": ")?;
+    let (actor, rest) = rest.split_once(" (method ")?;
+    let (method_str, rest) = rest.split_once(") -- ")?;
+    let method_num = method_str.trim().parse::<u64>().ok();
+
+    let (reason, exit_code) = match rest.rsplit_once('(') {
+        Some((reason, code_str)) => {
+            let code_str = code_str.trim_end_matches(')').trim();
+            (reason.trim().to_string(), code_str.parse::<u32>().ok())
+        }
+        None => (rest.trim().to_string(), None),
+    };
+
+    Some(CallFrame {
+        actor: actor.trim().to_string(),
+        method_num,
+        exit_code,
+        reason: Some(reason).filter(|r| !r.is_empty()),
+        subcalls: Vec::new(),
+    })
+}
+
+/// Build a single, detail-free root frame for a call that produced no backtrace at all
+/// (the success path, where there's nothing to trace).
+pub fn success_root(actor: String, method_num: Option<u64>) -> CallFrame {
+    CallFrame::success(actor, method_num)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_single_frame_backtrace() {
+        let info = "message failed with backtrace:\n00: t0119 (method 3844450837) -- contract reverted (33)\n";
+        let frame = parse_backtrace(info).expect("expected a frame");
+        assert_eq!(frame.actor, "t0119");
+        assert_eq!(frame.method_num, Some(3844450837));
+        assert_eq!(frame.exit_code, Some(33));
+        assert_eq!(frame.reason.as_deref(), Some("contract reverted"));
+        assert!(frame.subcalls.is_empty());
+    }
+
+    #[test]
+    fn parses_nested_backtrace_in_call_order() {
+        let info = "message failed with backtrace:\n\
+00: t0100 (method 1) -- outer call failed (16)\n\
+01: t0119 (method 2) -- contract reverted (33)\n";
+        let root = parse_backtrace(info).expect("expected a frame");
+        assert_eq!(root.actor, "t0100");
+        assert_eq!(root.subcalls.len(), 1);
+        assert_eq!(root.subcalls[0].actor, "t0119");
+        assert!(root.subcalls[0].subcalls.is_empty());
+    }
+
+    #[test]
+    fn returns_none_for_empty_backtrace() {
+        assert!(parse_backtrace("").is_none());
+    }
+}
@@ -3,13 +3,16 @@
 // SPDX-License-Identifier: Apache-2.0, MIT
 
 use std::str::FromStr;
+use std::time::Duration;
 
 use anyhow::anyhow;
 use async_trait::async_trait;
+use backoff::{future::retry, ExponentialBackoff};
 use ethers::core::types as et;
 use serde::Serialize;
 
 use crate::message::ChainMessage;
+use crate::query::{FvmQueryHeight, QueryProvider};
 
 pub use tendermint::{abci::response::DeliverTx, block::Height, Hash};
 
@@ -23,6 +26,12 @@ pub enum BroadcastMode {
     /// Wait for the delivery results before returning from broadcast.
     #[default]
     Commit,
+    /// Like `Commit`, but additionally waits until the including block is buried under `N`
+    /// additional blocks before returning, re-checking the transaction's receipt on every poll.
+    /// This protects callers on subnets with potential reorgs from acting on a commit that later
+    /// gets orphaned; a reorg that drops the transaction surfaces as an error instead of a false
+    /// success.
+    Finalized(u32),
 }
 
 impl FromStr for BroadcastMode {
@@ -106,6 +115,102 @@ impl<T> TxResult<T> {
             TxStatus::Committed(ref receipt) => receipt.transaction_hash,
         }
     }
+
+    /// Wraps this result in a [`PendingTransaction`] against `provider`, for the ergonomic
+    /// "broadcast with [`BroadcastMode::Async`]/[`BroadcastMode::Sync`], then await N
+    /// confirmations" flow, instead of choosing between a non-blocking and a fully-blocking
+    /// broadcast up front. Works regardless of `status`: a result that's already
+    /// [`TxStatus::Committed`] just resolves on its first poll.
+    pub fn confirm_with<'a, P>(&self, provider: &'a P) -> PendingTransaction<'a, P> {
+        PendingTransaction {
+            hash: to_tm_hash(self.hash()),
+            provider,
+        }
+    }
+}
+
+/// Converts the Ethereum-shaped hash [`TxResult::hash`] returns to the `tendermint_rpc` hash
+/// [`TxProvider::eth_tx_receipt`] takes; both are 32-byte transaction hashes, just wrapped in
+/// different crates' types.
+fn to_tm_hash(hash: et::TxHash) -> Hash {
+    Hash::Sha256(hash.0)
+}
+
+/// A transaction broadcast with [`BroadcastMode::Async`] or [`BroadcastMode::Sync`] but not yet
+/// waited on, built from a [`TxResult`] via [`TxResult::confirm_with`]. Resolves to the
+/// transaction's [`et::TransactionReceipt`] once it's retrievable and, for
+/// [`PendingTransaction::confirmations`], once at least that many additional blocks have been
+/// produced on top of the including block -- the same reorg-safety [`BroadcastMode::Finalized`]
+/// gives a `Commit` broadcast, but available after the fact instead of blocking the initial
+/// broadcast call.
+pub struct PendingTransaction<'a, P> {
+    hash: Hash,
+    provider: &'a P,
+}
+
+impl<'a, P> PendingTransaction<'a, P> {
+    /// The transaction hash being awaited.
+    pub fn hash(&self) -> Hash {
+        self.hash
+    }
+}
+
+impl<'a, P> PendingTransaction<'a, P>
+where
+    P: TxProvider + QueryProvider + Sync,
+{
+    /// Polls (with exponential backoff, for up to two minutes) until the receipt is retrievable
+    /// and at least `confirmations` additional blocks have landed on top of the including block.
+    /// `.await`ing a [`PendingTransaction`] directly is equivalent to `confirmations(0)`: just
+    /// wait for inclusion.
+    pub async fn confirmations(&self, confirmations: u32) -> anyhow::Result<et::TransactionReceipt> {
+        let mut backoff = ExponentialBackoff {
+            max_elapsed_time: Some(Duration::from_secs(120)),
+            ..Default::default()
+        };
+        backoff.reset();
+        retry(backoff, || async {
+            let receipt = self
+                .provider
+                .eth_tx_receipt(self.hash, false)
+                .await
+                .map_err(backoff::Error::transient)?;
+            let tx_height = receipt
+                .block_number
+                .ok_or_else(|| {
+                    backoff::Error::permanent(anyhow!("receipt is missing a block number"))
+                })?
+                .as_u64();
+            let latest_height = self
+                .provider
+                .state_params(FvmQueryHeight::Committed)
+                .await
+                .map_err(backoff::Error::transient)?
+                .height
+                .value();
+            if tx_height + confirmations as u64 <= latest_height {
+                Ok(receipt)
+            } else {
+                Err(backoff::Error::transient(anyhow!(
+                    "only {} of {confirmations} confirmations so far",
+                    latest_height.saturating_sub(tx_height)
+                )))
+            }
+        })
+        .await
+    }
+}
+
+impl<'a, P> std::future::IntoFuture for PendingTransaction<'a, P>
+where
+    P: TxProvider + QueryProvider + Sync + 'a,
+{
+    type Output = anyhow::Result<et::TransactionReceipt>;
+    type IntoFuture = std::pin::Pin<Box<dyn std::future::Future<Output = Self::Output> + Send + 'a>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Box::pin(async move { self.confirmations(0).await })
+    }
 }
 
 /// Provider for submitting transactions.
@@ -93,6 +93,60 @@ pub fn parse_metadata(s: &str) -> anyhow::Result<(String, String)> {
     Ok((key, val))
 }
 
+/// Serde helpers for round-tripping numeric newtypes (e.g. `Credit`, `TokenAmount`) through
+/// their decimal string `Display`/`FromStr` representations, rejecting non-numeric input at
+/// deserialization time instead of leaving it as an opaque, unvalidated `String`.
+pub mod decimal_string {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        serializer.serialize_str(&value.to_string())
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        T::from_str(&s).map_err(Error::custom)
+    }
+
+    /// Same as [`serialize`]/[`deserialize`] but for an `Option<T>`, keeping the `null` shape.
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: Display,
+            S: Serializer,
+        {
+            match value {
+                Some(v) => serializer.serialize_some(&v.to_string()),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            T: FromStr,
+            T::Err: Display,
+            D: Deserializer<'de>,
+        {
+            let s: Option<String> = Option::deserialize(deserializer)?;
+            s.map(|s| T::from_str(&s).map_err(Error::custom)).transpose()
+        }
+    }
+}
+
 /// Parse metadata from string accepting empty values.
 pub fn parse_metadata_optional(s: &str) -> anyhow::Result<(String, Option<String>)> {
     match s.find('=') {
@@ -19,7 +19,7 @@ async fn main() -> anyhow::Result<()> {
     let pk = parse_secret_key(pk_kex)?;
 
     // Use testnet network defaults
-    let cfg = Network::Testnet.get_config();
+    let cfg = Network::Testnet.get_config()?;
 
     // Setup local wallet using private key from arg
     let signer = Wallet::new_secp256k1(pk, AccountKind::Ethereum, cfg.subnet_id.parent()?)?;
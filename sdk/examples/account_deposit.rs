@@ -8,29 +8,56 @@ use ethers::utils::hex::ToHexExt;
 
 use hoku_provider::fvm_shared::econ::TokenAmount;
 use hoku_sdk::{account::Account, network::Network};
-use hoku_signer::{key::parse_secret_key, AccountKind, Signer, Wallet};
+use hoku_signer::{key::parse_secret_key, AccountKind, LedgerConfig, LedgerSigner, Signer, Wallet};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args: Vec<String> = env::args().collect();
     if args.len() < 2 {
-        return Err(anyhow!("missing hex-encoded private key"));
+        return Err(anyhow!(
+            "missing argument: a hex-encoded private key, or --ledger <account index>"
+        ));
     }
-    let pk_kex = &args[1];
-    let pk = parse_secret_key(pk_kex)?;
 
     // Use testnet network defaults
     // Note: The debit account _must_ hold at least 1 Calibration HOKU for the deposit
     // plus enough to cover the transaction fee.
     // Go to the faucet at https://faucet.calibnet.chainsafe-fil.io/ to get yourself some HOKU.
-    let cfg = Network::Testnet.get_config();
+    let cfg = Network::Testnet.get_config()?;
+
+    if args[1] == "--ledger" {
+        let account_index = args
+            .get(2)
+            .ok_or_else(|| anyhow!("missing Ledger account index"))?
+            .parse()?;
+        let config = LedgerConfig {
+            account_index,
+            ..Default::default()
+        };
+        // Connecting confirms the device is reachable and reports the address it holds, but a
+        // deposit can't be driven from it yet: `EvmManager` signs transactions by pulling a raw
+        // secret key off the signer (see `get_eth_signer` in `hoku_sdk::ipc::manager`), and
+        // `LedgerSigner::secret_key` returns `None` by design since the key never leaves the
+        // device. Until that signing path is taught to drive an `ethers::signers::Ledger`
+        // directly, a Ledger-backed deposit has to stop here.
+        let signer = LedgerSigner::connect(config, cfg.subnet_id.parent()?).await?;
+        return Err(anyhow!(
+            "connected to Ledger device at {}, but this example can't yet sign a deposit \
+             transaction with a hardware wallet; pass a hex-encoded private key instead",
+            signer.eth_address()?.encode_hex_with_prefix()
+        ));
+    }
+
+    let pk_kex = &args[1];
+    let pk = parse_secret_key(pk_kex)?;
 
     // Setup local wallet using private key from arg
     let signer = Wallet::new_secp256k1(pk, AccountKind::Ethereum, cfg.subnet_id.parent()?)?;
 
-    // Deposit some calibration funds into the subnet
+    // Deposit some calibration funds into the subnet, and wait for the parent's supply source
+    // balance to reflect the deposit.
     // Note: The debit account _must_ have Calibration
-    let tx = Account::deposit(
+    let updated_balance = Account::deposit(
         &signer,
         signer.address(),
         cfg.parent_subnet_config()
@@ -38,16 +65,14 @@ async fn main() -> anyhow::Result<()> {
         cfg.subnet_id,
         TokenAmount::from_whole(1),
     )
+    .await?
     .await?;
 
     println!(
         "Deposited 1 HOKU to {}",
         signer.eth_address()?.encode_hex_with_prefix()
     );
-    println!(
-        "Transaction hash: 0x{}",
-        hex::encode(tx.transaction_hash.to_fixed_bytes())
-    );
+    println!("Updated supply source balance: {updated_balance}");
 
     Ok(())
 }
@@ -29,10 +29,15 @@ async fn main() -> anyhow::Result<()> {
     let pk = parse_secret_key(pk_kex)?;
 
     // Use testnet network defaults
-    let cfg = Network::Testnet.get_config();
+    let cfg = Network::Testnet.get_config()?;
 
     // Setup network provider
-    let provider = JsonRpcProvider::new_http(cfg.rpc_url, None, Some(cfg.object_api_url))?;
+    let provider = JsonRpcProvider::new_auto(
+        cfg.rpc_url,
+        cfg.subnet_id.chain_id(),
+        None,
+        Some(cfg.object_api_url),
+    )?;
 
     // Setup local wallet using private key from arg
     let mut signer = Wallet::new_secp256k1(pk, AccountKind::Ethereum, cfg.subnet_id)?;
@@ -42,11 +42,16 @@ const REQUESTS_PER_USER: u32 = 500;
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // Use devnet network defaults
-    let cfg = Network::Devnet.get_config();
+    let cfg = Network::Devnet.get_config()?;
 
     // Setup network provider
     let provider =
-        JsonRpcProvider::new_http(cfg.rpc_url.clone(), None, Some(cfg.object_api_url.clone()))?;
+        JsonRpcProvider::new_auto(
+            cfg.rpc_url.clone(),
+            cfg.subnet_id.chain_id(),
+            None,
+            Some(cfg.object_api_url.clone()),
+        )?;
 
     // Setup admin wallet
     let pk = parse_secret_key(&ADMIN_PRIVATE_KEY)?;
@@ -30,10 +30,10 @@ async fn main() -> anyhow::Result<()> {
     let pk = parse_secret_key(pk_hex)?;
 
     // Use testnet network defaults
-    let cfg = Network::Testnet.get_config();
+    let cfg = Network::Testnet.get_config()?;
 
     // Setup network provider
-    let provider = JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+    let provider = JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
 
     // Setup local wallet using private key from arg
     let mut signer = Wallet::new_secp256k1(pk, AccountKind::Ethereum, cfg.subnet_id.clone())?;
@@ -13,22 +13,33 @@ use recall_sdk::{
     machine::{timehub::Timehub, Machine},
     network::Network,
 };
-use recall_signer::{key::parse_secret_key, AccountKind, Wallet};
+use recall_signer::{
+    key::{load_keystore, parse_secret_key},
+    AccountKind, Wallet,
+};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let args: Vec<String> = env::args().collect();
     if args.len() < 2 {
-        return Err(anyhow!("missing hex-encoded private key"));
+        return Err(anyhow!(
+            "missing private key: pass a hex-encoded key, or a keystore file path plus \
+             RECALL_KEYSTORE_PASSWORD"
+        ));
     }
-    let pk_kex = &args[1];
-    let pk = parse_secret_key(pk_kex)?;
+    let pk = if let Ok(sk) = parse_secret_key(&args[1]) {
+        sk
+    } else {
+        let password = env::var("RECALL_KEYSTORE_PASSWORD")
+            .map_err(|_| anyhow!("{} is not a hex private key; set RECALL_KEYSTORE_PASSWORD to load it as a keystore file", args[1]))?;
+        load_keystore(args[1].as_ref(), &password)?
+    };
 
     // Use testnet network defaults
-    let cfg = Network::Testnet.get_config();
+    let cfg = Network::Testnet.get_config()?;
 
     // Setup network provider
-    let provider = JsonRpcProvider::new_http(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
+    let provider = JsonRpcProvider::new_auto(cfg.rpc_url, cfg.subnet_id.chain_id(), None, None)?;
 
     // Setup local wallet using private key from arg
     let mut signer = Wallet::new_secp256k1(pk, AccountKind::Ethereum, cfg.subnet_id)?;
@@ -1,6 +1,13 @@
 // Copyright 2025 Recall Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
+use std::future::Future;
+use std::ops::Sub;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use anyhow::anyhow;
 use fendermint_actor_blobs_shared::params::{SetAccountStatusParams, SetSponsorParams};
 use fendermint_actor_blobs_shared::Method::{SetAccountSponsor, SetAccountStatus};
 use fendermint_vm_actor_interface::blobs::BLOBS_ACTOR_ADDR;
@@ -19,8 +26,15 @@ use recall_provider::{
 use recall_signer::{Signer, SubnetID};
 
 pub use crate::ipc::{manager::EvmManager, subnet::EVMSubnet};
+use crate::middleware::{GasFillingLayer, ProviderLayer, SigningMiddleware};
 pub use ethers::prelude::TransactionReceipt;
 
+/// How often [`PendingDeposit`] polls for a balance update by default.
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long [`PendingDeposit`] waits for a balance update before giving up, by default.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(120);
+
 /// Options for setting credit sponsor.
 #[derive(Clone, Default, Debug)]
 pub struct SetSponsorOptions {
@@ -41,6 +55,125 @@ pub struct SetTtlStatusOptions {
     pub gas_params: GasParams,
 }
 
+/// Which balance a [`PendingDeposit`] should poll to confirm a transfer landed.
+#[derive(Clone, Copy)]
+enum WatchedBalance {
+    /// The native token balance on an [`EVMSubnet`], via [`EvmManager::balance`].
+    Native,
+    /// The supply source (ERC20) balance on an [`EVMSubnet`], via
+    /// [`EvmManager::supply_source_balance`].
+    SupplySource,
+}
+
+impl WatchedBalance {
+    async fn fetch(self, address: Address, subnet: EVMSubnet) -> anyhow::Result<TokenAmount> {
+        match self {
+            WatchedBalance::Native => EvmManager::balance(address, subnet).await,
+            WatchedBalance::SupplySource => EvmManager::supply_source_balance(address, subnet).await,
+        }
+    }
+}
+
+/// A handle to a cross-subnet transfer whose effect hasn't been confirmed yet.
+///
+/// [`Account::deposit`] and [`Account::withdraw`] submit their transaction and return
+/// immediately; the side of the transfer this handle watches settles asynchronously once it's
+/// relayed across the subnet boundary. Awaiting a [`PendingDeposit`] (modeled on ethers'
+/// `PendingTransaction`) polls that balance until it reflects the transfer, instead of every
+/// caller hand-rolling the same loop, and errors out if `timeout` elapses first.
+pub struct PendingDeposit {
+    watched: WatchedBalance,
+    address: Address,
+    subnet: EVMSubnet,
+    baseline: TokenAmount,
+    amount: TokenAmount,
+    interval: Duration,
+    timeout: Duration,
+    confirmations: u32,
+    state: Option<Pin<Box<dyn Future<Output = anyhow::Result<TokenAmount>> + Send>>>,
+}
+
+impl PendingDeposit {
+    fn new(
+        watched: WatchedBalance,
+        address: Address,
+        subnet: EVMSubnet,
+        baseline: TokenAmount,
+        amount: TokenAmount,
+    ) -> Self {
+        PendingDeposit {
+            watched,
+            address,
+            subnet,
+            baseline,
+            amount,
+            interval: DEFAULT_POLL_INTERVAL,
+            timeout: DEFAULT_TIMEOUT,
+            confirmations: 1,
+            state: None,
+        }
+    }
+
+    /// Requires the expected balance to be observed this many consecutive polls before
+    /// resolving, guarding against a transient (or since-reorged) update. Default: 1.
+    pub fn confirmations(mut self, confirmations: u32) -> Self {
+        self.confirmations = confirmations.max(1);
+        self
+    }
+
+    /// Overrides how long to wait before giving up. Default: 120 seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Overrides how often the balance is polled. Default: 1 second.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+}
+
+impl Future for PendingDeposit {
+    type Output = anyhow::Result<TokenAmount>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let state = this.state.get_or_insert_with(|| {
+            let watched = this.watched;
+            let address = this.address;
+            let subnet = this.subnet.clone();
+            let baseline = this.baseline.clone();
+            let amount = this.amount.clone();
+            let interval = this.interval;
+            let confirmations = this.confirmations;
+            let timeout = this.timeout;
+            Box::pin(async move {
+                let poll_loop = async {
+                    let mut seen = 0u32;
+                    loop {
+                        let balance = watched.fetch(address, subnet.clone()).await?;
+                        let delta = baseline.clone().sub(&balance);
+                        if delta == amount {
+                            seen += 1;
+                            if seen >= confirmations {
+                                return Ok(balance);
+                            }
+                        } else {
+                            seen = 0;
+                        }
+                        tokio::time::sleep(interval).await;
+                    }
+                };
+                tokio::time::timeout(timeout, poll_loop)
+                    .await
+                    .unwrap_or_else(|_| Err(anyhow!("timed out waiting for transfer of {amount} to confirm")))
+            })
+        });
+        state.as_mut().poll(cx)
+    }
+}
+
 /// A static wrapper around Recall account methods.
 pub struct Account {}
 
@@ -72,28 +205,52 @@ impl Account {
     }
 
     /// Deposit funds from a [`Signer`] to an address in the given subnet.
+    ///
+    /// Returns a [`PendingDeposit`] rather than waiting for the destination subnet to see the
+    /// funds: await it (`Account::deposit(..).await?.await?`) to block until the supply source
+    /// balance on `from_subnet` reflects the deposit, or time out.
     pub async fn deposit(
         signer: &impl Signer,
         to: Address,
         from_subnet: EVMSubnet,
         to_subnet: SubnetID,
         amount: TokenAmount,
-    ) -> anyhow::Result<TransactionReceipt> {
+    ) -> anyhow::Result<PendingDeposit> {
+        let baseline = EvmManager::supply_source_balance(signer.address(), from_subnet.clone()).await?;
         // Approve the gateway to spend funds on behalf of the user.
         // This is required when the subnet uses a custom ERC20 token as
         // the gateway's supply source.
         EvmManager::approve_gateway(signer, from_subnet.clone(), amount.clone()).await?;
-        EvmManager::deposit(signer, to, from_subnet, to_subnet, amount).await
+        EvmManager::deposit(signer, to, from_subnet.clone(), to_subnet, amount.clone()).await?;
+        Ok(PendingDeposit::new(
+            WatchedBalance::SupplySource,
+            signer.address(),
+            from_subnet,
+            baseline,
+            amount,
+        ))
     }
 
     /// Withdraw funds from a [`Signer`] to an address in the given subnet.
+    ///
+    /// Returns a [`PendingDeposit`] rather than waiting for the withdrawal to clear: await it
+    /// (`Account::withdraw(..).await?.await?`) to block until the native balance on `subnet`
+    /// reflects the withdrawal, or time out.
     pub async fn withdraw(
         signer: &impl Signer,
         to: Address,
         subnet: EVMSubnet,
         amount: TokenAmount,
-    ) -> anyhow::Result<TransactionReceipt> {
-        EvmManager::withdraw(signer, to, subnet, amount).await
+    ) -> anyhow::Result<PendingDeposit> {
+        let baseline = EvmManager::balance(signer.address(), subnet.clone()).await?;
+        EvmManager::withdraw(signer, to, subnet.clone(), amount.clone()).await?;
+        Ok(PendingDeposit::new(
+            WatchedBalance::Native,
+            signer.address(),
+            subnet,
+            baseline,
+            amount,
+        ))
     }
 
     /// Transfer funds from [`Signer`] to an address in the given subnet.
@@ -129,6 +286,7 @@ impl Account {
                 SetAccountSponsor as u64,
                 params,
                 options.gas_params,
+                None,
                 options.broadcast_mode,
                 decode_empty,
             )
@@ -158,9 +316,71 @@ impl Account {
                 SetAccountStatus as u64,
                 params,
                 options.gas_params,
+                None,
                 options.broadcast_mode,
                 decode_empty,
             )
             .await
     }
+
+    /// Like [`Self::set_sponsor`], but sends through a [`SigningMiddleware`] stack (see
+    /// [`crate::middleware`]) instead of a raw provider/signer pair, so the stack's
+    /// `NonceManager` and `GasOracle` handle sequencing and gas instead of a manual
+    /// `set_sequence`/`init_sequence` call.
+    pub async fn set_sponsor_via<C, P, S>(
+        middleware: &mut SigningMiddleware<GasFillingLayer<ProviderLayer<P>>, S>,
+        sponsor: Option<Address>,
+        broadcast_mode: BroadcastMode,
+    ) -> anyhow::Result<TxResult<()>>
+    where
+        C: Client + Send + Sync,
+        P: Provider<C>,
+        S: Signer,
+    {
+        let params = SetSponsorParams {
+            from: middleware.address(),
+            sponsor,
+        };
+        let params = RawBytes::serialize(params)?;
+        middleware
+            .send_transaction(
+                BLOBS_ACTOR_ADDR,
+                Default::default(),
+                SetAccountSponsor as u64,
+                params,
+                broadcast_mode,
+                decode_empty,
+            )
+            .await
+    }
+
+    /// Like [`Self::set_ttl_status`], but sends through a [`SigningMiddleware`] stack instead of
+    /// a raw provider/signer pair -- see [`Self::set_sponsor_via`].
+    pub async fn set_ttl_status_via<C, P, S>(
+        middleware: &mut SigningMiddleware<GasFillingLayer<ProviderLayer<P>>, S>,
+        account: Address,
+        status: TtlStatus,
+        broadcast_mode: BroadcastMode,
+    ) -> anyhow::Result<TxResult<()>>
+    where
+        C: Client + Send + Sync,
+        P: Provider<C>,
+        S: Signer,
+    {
+        let params = SetAccountStatusParams {
+            subscriber: account,
+            status,
+        };
+        let params = RawBytes::serialize(params)?;
+        middleware
+            .send_transaction(
+                BLOBS_ACTOR_ADDR,
+                Default::default(),
+                SetAccountStatus as u64,
+                params,
+                broadcast_mode,
+                decode_empty,
+            )
+            .await
+    }
 }
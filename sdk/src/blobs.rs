@@ -71,6 +71,7 @@ impl Blobs {
                 FundAccount as u64,
                 params,
                 options.gas_params,
+                None,
             )
             .await?;
         provider
@@ -1,7 +1,7 @@
 // Copyright 2025 Recall Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use anyhow::anyhow;
 use ethers::utils::hex::ToHexExt;
@@ -17,11 +17,12 @@ use serde::{Deserialize, Serialize};
 use recall_provider::{
     fvm_ipld_encoding::{self, RawBytes},
     fvm_shared::{address::Address, clock::ChainEpoch, econ::TokenAmount},
+    gas_oracle::GasOracle,
     message::{local_message, GasParams},
     query::{FvmQueryHeight, QueryProvider},
     response::{decode_bytes, decode_empty},
     tx::{BroadcastMode, DeliverTx, TxResult},
-    util::get_eth_address,
+    util::{decimal_string, get_eth_address},
     {Client, Provider},
 };
 use recall_signer::Signer;
@@ -29,17 +30,19 @@ use recall_signer::Signer;
 pub use recall_fendermint_actor_blobs_shared::state::{Credit, TokenCreditRate};
 
 /// Options for buying credit.
-#[derive(Clone, Default, Debug)]
-pub struct BuyOptions {
+#[derive(Clone, Default)]
+pub struct BuyOptions<'a> {
     /// Broadcast mode for the transaction.
     pub broadcast_mode: BroadcastMode,
     /// Gas params for the transaction.
     pub gas_params: GasParams,
+    /// Gas oracle consulted to fill in `gas_params` when its `gas_limit` is left unset.
+    pub gas_oracle: Option<&'a (dyn GasOracle + Sync)>,
 }
 
 /// Options for approving credit.
-#[derive(Clone, Default, Debug)]
-pub struct ApproveOptions {
+#[derive(Clone, Default)]
+pub struct ApproveOptions<'a> {
     /// Credit approval limit.
     /// If specified, the approval becomes invalid once the used credits reach the
     /// specified limit.
@@ -47,7 +50,13 @@ pub struct ApproveOptions {
     /// Gas fee limit.
     /// If specified, the approval becomes invalid once the used gas fees reach the
     /// specified limit.
+    ///
+    /// Ignored when [`Self::silo`] is set -- [`SiloPolicy::gas_fee_limit`] is used instead, so
+    /// the two ways of bounding spend can't silently disagree.
     pub gas_fee_limit: Option<TokenAmount>,
+    /// A fixed per-transaction budget, in place of [`Self::gas_fee_limit`]'s open-ended measured
+    /// gas limit. See [`SiloPolicy`].
+    pub silo: Option<SiloPolicy>,
     /// Credit approval time-to-live epochs.
     /// If specified, the approval becomes invalid after this duration.
     pub ttl: Option<ChainEpoch>,
@@ -55,24 +64,58 @@ pub struct ApproveOptions {
     pub broadcast_mode: BroadcastMode,
     /// Gas params for the transaction.
     pub gas_params: GasParams,
+    /// Gas oracle consulted to fill in `gas_params` when its `gas_limit` is left unset.
+    pub gas_oracle: Option<&'a (dyn GasOracle + Sync)>,
+}
+
+/// A fixed-cost ("silo") gas budget for a credit approval: instead of bounding total spend only
+/// by measured gas against an open-ended ceiling, the sponsor plans for a flat cost per sponsored
+/// transaction and a maximum transaction count, so the total budget is the simple product
+/// `fixed_cost_per_tx * max_tx_count` rather than a function of how much gas each transaction
+/// happens to use.
+///
+/// The blobs actor only tracks spend against `gas_fee_limit`/`gas_fee_used` measured from actual
+/// gas (see [`Approval`]); it has no notion of a fixed per-transaction charge, so this can't make
+/// the actor debit a flat amount at submission time the way a true chain-side silo would -- each
+/// sponsored transaction is still metered by its real gas cost. What this gives the sponsor is
+/// deterministic budgeting: [`Self::gas_fee_limit`] is passed as the approval's `gas_fee_limit`,
+/// so the approval can never cost more than `fixed_cost_per_tx * max_tx_count` in aggregate, even
+/// though individual transactions may use more or less than `fixed_cost_per_tx` of that budget.
+#[derive(Clone, Debug)]
+pub struct SiloPolicy {
+    /// The budget set aside for each sponsored transaction.
+    pub fixed_cost_per_tx: TokenAmount,
+    /// The number of sponsored transactions this policy budgets for.
+    pub max_tx_count: u64,
+}
+
+impl SiloPolicy {
+    /// The `gas_fee_limit` this policy implies: `fixed_cost_per_tx * max_tx_count`.
+    pub fn gas_fee_limit(&self) -> TokenAmount {
+        TokenAmount::from_atto(self.fixed_cost_per_tx.atto() * self.max_tx_count)
+    }
 }
 
 /// Options for revoking credit.
-#[derive(Clone, Default, Debug)]
-pub struct RevokeOptions {
+#[derive(Clone, Default)]
+pub struct RevokeOptions<'a> {
     /// Broadcast mode for the transaction.
     pub broadcast_mode: BroadcastMode,
     /// Gas params for the transaction.
     pub gas_params: GasParams,
+    /// Gas oracle consulted to fill in `gas_params` when its `gas_limit` is left unset.
+    pub gas_oracle: Option<&'a (dyn GasOracle + Sync)>,
 }
 
 /// Credit balance for an account.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Balance {
     /// Current free credit in byte-blocks that can be used for new commitments.
-    pub credit_free: String,
+    #[serde(with = "decimal_string")]
+    pub credit_free: Credit,
     /// Current committed credit in byte-blocks that will be used for debits.
-    pub credit_committed: String,
+    #[serde(with = "decimal_string")]
+    pub credit_committed: Credit,
     /// Optional default sponsor account address.
     pub credit_sponsor: Option<String>,
     /// The chain epoch of the last debit.
@@ -84,20 +127,21 @@ pub struct Balance {
     /// The maximum allowed TTL for actor's blobs.
     pub max_ttl: ChainEpoch,
     /// The total token value an account has used to buy credits.
-    pub gas_allowance: String,
+    #[serde(with = "decimal_string")]
+    pub gas_allowance: TokenAmount,
 }
 
 impl Default for Balance {
     fn default() -> Self {
         Self {
-            credit_free: "0".into(),
-            credit_committed: "0".into(),
+            credit_free: Credit::from_whole(0),
+            credit_committed: Credit::from_whole(0),
             last_debit_epoch: Some(0),
             credit_sponsor: None,
             approvals_to: HashMap::new(),
             approvals_from: HashMap::new(),
             max_ttl: 0,
-            gas_allowance: "0".into(),
+            gas_allowance: TokenAmount::from_atto(0),
         }
     }
 }
@@ -110,8 +154,8 @@ impl From<recall_fendermint_actor_blobs_shared::state::AccountInfo> for Balance
             None
         };
         Self {
-            credit_free: v.credit_free.to_string(),
-            credit_committed: v.credit_committed.to_string(),
+            credit_free: v.credit_free,
+            credit_committed: v.credit_committed,
             last_debit_epoch,
             credit_sponsor: v.credit_sponsor.map(|a| {
                 get_eth_address(a)
@@ -135,7 +179,7 @@ impl From<recall_fendermint_actor_blobs_shared::state::AccountInfo> for Balance
                 })
                 .collect(),
             max_ttl: v.max_ttl,
-            gas_allowance: v.gas_allowance.to_string(),
+            gas_allowance: v.gas_allowance,
         }
     }
 }
@@ -143,28 +187,35 @@ impl From<recall_fendermint_actor_blobs_shared::state::AccountInfo> for Balance
 /// A credit approval.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Approval {
-    /// Optional credit approval limit.
+    /// Callers allowed to use this approval, e.g. a bucket or contract address. `None` means any
+    /// caller is allowed.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub credit_limit: Option<String>,
+    pub caller_allowlist: Option<HashSet<String>>,
+    /// Optional credit approval limit.
+    #[serde(skip_serializing_if = "Option::is_none", with = "decimal_string::option")]
+    pub credit_limit: Option<Credit>,
     /// Optional gas fee approval limit.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub gas_fee_limit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "decimal_string::option")]
+    pub gas_fee_limit: Option<TokenAmount>,
     /// Optional credit approval expiry epoch.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expiry: Option<ChainEpoch>,
     /// Counter for how much credit has been used via this approval.
-    pub credit_used: String,
+    #[serde(with = "decimal_string")]
+    pub credit_used: Credit,
     /// Amount of gas that has been used via this approval.
-    pub gas_fee_used: String,
+    #[serde(with = "decimal_string")]
+    pub gas_fee_used: TokenAmount,
 }
 
 impl Default for Approval {
     fn default() -> Self {
         Self {
+            caller_allowlist: None,
             credit_limit: None,
-            credit_used: "0".into(),
+            credit_used: Credit::from_whole(0),
             gas_fee_limit: None,
-            gas_fee_used: "0".into(),
+            gas_fee_used: TokenAmount::from_atto(0),
             expiry: None,
         }
     }
@@ -173,26 +224,92 @@ impl Default for Approval {
 impl From<recall_fendermint_actor_blobs_shared::state::CreditApproval> for Approval {
     fn from(v: recall_fendermint_actor_blobs_shared::state::CreditApproval) -> Self {
         Self {
-            credit_limit: v.credit_limit.map(|l| l.to_string()),
-            credit_used: v.credit_used.to_string(),
-            gas_fee_limit: v.gas_fee_limit.map(|l| l.to_string()),
-            gas_fee_used: v.gas_fee_used.to_string(),
+            caller_allowlist: v.caller_allowlist.map(|set| {
+                set.into_iter()
+                    .map(|a| {
+                        get_eth_address(a)
+                            .expect("invalid address")
+                            .encode_hex_with_prefix()
+                    })
+                    .collect()
+            }),
+            credit_limit: v.credit_limit,
+            credit_used: v.credit_used,
+            gas_fee_limit: v.gas_fee_limit,
+            gas_fee_used: v.gas_fee_used,
             expiry: v.expiry,
         }
     }
 }
 
+/// A summary of an [`Approval`], with the remaining credit/gas-fee allowance and epochs until
+/// expiry computed against the current chain height -- used by `recall credit list` to let a
+/// delegator audit which accounts can still spend their credits before they silently hit a limit
+/// or TTL boundary.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ApprovalSummary {
+    /// The counterparty address: the receiver, for an approval this account granted, or the
+    /// granter, for one this account received.
+    pub address: String,
+    /// Callers allowed to use this approval. `None` means any caller is allowed.
+    pub caller_allowlist: Option<HashSet<String>>,
+    /// Remaining credit before the approval's `credit_limit` is reached, or `None` if the
+    /// approval has no limit.
+    #[serde(skip_serializing_if = "Option::is_none", with = "decimal_string::option")]
+    pub credit_remaining: Option<Credit>,
+    /// Remaining gas-fee allowance before the approval's `gas_fee_limit` is reached, or `None` if
+    /// the approval has no limit.
+    #[serde(skip_serializing_if = "Option::is_none", with = "decimal_string::option")]
+    pub gas_fee_remaining: Option<TokenAmount>,
+    /// Epochs remaining until the approval expires, or `None` if it has no TTL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub epochs_until_expiry: Option<ChainEpoch>,
+}
+
+impl ApprovalSummary {
+    /// Summarizes `approval` (granted to or received from `address`) against `height`.
+    pub fn new(address: String, approval: &Approval, height: ChainEpoch) -> Self {
+        Self {
+            address,
+            caller_allowlist: approval.caller_allowlist.clone(),
+            credit_remaining: approval
+                .credit_limit
+                .clone()
+                .map(|limit| limit - approval.credit_used.clone()),
+            gas_fee_remaining: approval
+                .gas_fee_limit
+                .clone()
+                .map(|limit| limit - approval.gas_fee_used.clone()),
+            epochs_until_expiry: approval.expiry.map(|expiry| expiry - height),
+        }
+    }
+}
+
+/// The credit approvals granted by, and received by, an account, as returned by
+/// [`Credits::list`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Approvals {
+    /// Approvals this account has granted to others.
+    pub granted: Vec<ApprovalSummary>,
+    /// Approvals this account has received from others.
+    pub received: Vec<ApprovalSummary>,
+}
+
 /// Subnet-wide credit statistics.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct CreditStats {
     /// The current token balance earned by the subnet.
-    pub balance: String,
+    #[serde(with = "decimal_string")]
+    pub balance: TokenAmount,
     /// The total number of credits sold in the subnet.
-    pub credit_sold: String,
+    #[serde(with = "decimal_string")]
+    pub credit_sold: Credit,
     /// The total number of credits committed to active storage in the subnet.
-    pub credit_committed: String,
+    #[serde(with = "decimal_string")]
+    pub credit_committed: Credit,
     /// The total number of credits debited in the subnet.
-    pub credit_debited: String,
+    #[serde(with = "decimal_string")]
+    pub credit_debited: Credit,
     /// The token to credit rate.
     pub token_credit_rate: TokenCreditRate,
     // Total number of debit accounts.
@@ -202,10 +319,10 @@ pub struct CreditStats {
 impl From<recall_fendermint_actor_blobs_shared::params::GetStatsReturn> for CreditStats {
     fn from(v: recall_fendermint_actor_blobs_shared::params::GetStatsReturn) -> Self {
         Self {
-            balance: v.balance.to_string(),
-            credit_sold: v.credit_sold.to_string(),
-            credit_committed: v.credit_committed.to_string(),
-            credit_debited: v.credit_debited.to_string(),
+            balance: v.balance,
+            credit_sold: v.credit_sold,
+            credit_committed: v.credit_committed,
+            credit_debited: v.credit_debited,
             token_credit_rate: v.token_credit_rate,
             num_accounts: v.num_accounts,
         }
@@ -241,13 +358,62 @@ impl Credits {
         }
     }
 
+    /// Lists the credit approvals granted by, and received by, `from`, with each approval's
+    /// remaining allowance and epochs-until-expiry computed against the current chain height.
+    pub async fn list(
+        provider: &impl QueryProvider,
+        from: Address,
+        height: FvmQueryHeight,
+    ) -> anyhow::Result<Approvals> {
+        let balance = Self::balance(provider, from, height).await?;
+        let tip = provider.state_params(height).await?;
+        let current_height = tip.height.value() as ChainEpoch;
+
+        let granted = balance
+            .approvals_to
+            .iter()
+            .map(|(address, approval)| {
+                ApprovalSummary::new(address.clone(), approval, current_height)
+            })
+            .collect();
+        let received = balance
+            .approvals_from
+            .iter()
+            .map(|(address, approval)| {
+                ApprovalSummary::new(address.clone(), approval, current_height)
+            })
+            .collect();
+
+        Ok(Approvals { granted, received })
+    }
+
+    /// Ranks the top `limit` accounts by credit balance (`credit_free + credit_committed`) and
+    /// by committed blob capacity (`credit_committed`).
+    ///
+    /// Unimplemented: the blobs actor (`recall_fendermint_actor_blobs_shared::Method`, as
+    /// vendored here) only exposes [`GetAccount`] for a single, caller-supplied address -- there
+    /// is no query method that lists or pages over all accounts, so the candidates to rank can't
+    /// be discovered without already knowing their addresses. A real implementation needs the
+    /// actor to expose that (e.g. a `ListAccounts { cursor, limit }` method), at which point this
+    /// should page through the results, call [`Self::balance`] for each, and sort.
+    pub async fn largest(
+        _provider: &impl QueryProvider,
+        _limit: usize,
+        _height: FvmQueryHeight,
+    ) -> anyhow::Result<Vec<(Address, Balance)>> {
+        Err(anyhow!(
+            "largest is not supported: the blobs actor doesn't expose a query method for \
+             listing accounts"
+        ))
+    }
+
     /// Buy credits for an account.
     pub async fn buy<C>(
         provider: &impl Provider<C>,
         signer: &mut impl Signer,
         to: Address,
         amount: TokenAmount,
-        options: BuyOptions,
+        options: BuyOptions<'_>,
     ) -> anyhow::Result<TxResult<Balance>>
     where
         C: Client + Send + Sync,
@@ -262,6 +428,7 @@ impl Credits {
                 BuyCredit as u64,
                 params,
                 options.gas_params,
+                options.gas_oracle,
                 options.broadcast_mode,
                 decode_buy,
             )
@@ -274,17 +441,21 @@ impl Credits {
         signer: &mut impl Signer,
         from: Address,
         to: Address,
-        options: ApproveOptions,
+        options: ApproveOptions<'_>,
     ) -> anyhow::Result<TxResult<Approval>>
     where
         C: Client + Send + Sync,
     {
+        let gas_fee_limit = match &options.silo {
+            Some(silo) => Some(silo.gas_fee_limit()),
+            None => options.gas_fee_limit,
+        };
         let params = ApproveCreditParams {
             from,
             to,
             caller_allowlist: None, // TODO: remove this when it's been removed in ipc
             credit_limit: options.credit_limit,
-            gas_fee_limit: options.gas_fee_limit,
+            gas_fee_limit,
             ttl: options.ttl,
         };
         let params = RawBytes::serialize(params)?;
@@ -296,6 +467,7 @@ impl Credits {
                 ApproveCredit as u64,
                 params,
                 options.gas_params,
+                options.gas_oracle,
                 options.broadcast_mode,
                 decode_approve,
             )
@@ -308,7 +480,7 @@ impl Credits {
         signer: &mut impl Signer,
         from: Address,
         to: Address,
-        options: RevokeOptions,
+        options: RevokeOptions<'_>,
     ) -> anyhow::Result<TxResult<()>>
     where
         C: Client + Send + Sync,
@@ -327,6 +499,7 @@ impl Credits {
                 RevokeCredit as u64,
                 params,
                 options.gas_params,
+                options.gas_oracle,
                 options.broadcast_mode,
                 decode_empty,
             )
@@ -0,0 +1,43 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! Object encryption: server-side (SSE-C and SSE-KMS) and account-bound (ECIES).
+//!
+//! Encrypted content is stored using the [`dare`] streaming AEAD format. [`sse_c`], [`sse_kms`],
+//! and [`ecies`] each derive a per-object content key and the metadata needed to recover it
+//! later, while [`object::EncryptedObjectExt`] reads that metadata back off an uploaded [`Object`]
+//! to unseal the key on download.
+//!
+//! [`Object`]: fendermint_actor_bucket::Object
+
+pub mod decryptor;
+pub mod ecies;
+pub mod encryptor;
+pub mod kdf;
+pub mod key;
+pub mod kms;
+pub mod metadata;
+pub mod object;
+pub mod sse_c;
+pub mod sse_kms;
+
+/// Re-exported so callers can select a cipher suite for [`sse_c::encrypt_reader`]/
+/// [`sse_kms::encrypt_reader`] without taking a direct dependency on `dare`.
+pub use dare::CipherSuite;
+
+/// The [`metadata::META_CIPHER_SUITE`] label for `suite`.
+pub fn cipher_suite_label(suite: CipherSuite) -> &'static str {
+    match suite {
+        CipherSuite::AES256GCM => "AES256GCM",
+        CipherSuite::ChaCha20Poly1305 => "ChaCha20Poly1305",
+    }
+}
+
+/// Parses a [`metadata::META_CIPHER_SUITE`] label back into a [`CipherSuite`].
+pub fn parse_cipher_suite_label(label: &str) -> anyhow::Result<CipherSuite> {
+    match label {
+        "AES256GCM" => Ok(CipherSuite::AES256GCM),
+        "ChaCha20Poly1305" => Ok(CipherSuite::ChaCha20Poly1305),
+        other => Err(anyhow::anyhow!("unrecognized cipher suite metadata: {other}")),
+    }
+}
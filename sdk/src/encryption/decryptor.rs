@@ -1,129 +1,332 @@
 use bytes::{Buf, BufMut, BytesMut};
-use dare::{DAREDecryptor, DAREHeader, HEADER_SIZE, TAG_SIZE};
-use futures_core::ready;
+use dare::{DAREDecryptor, DAREHeader, HEADER_SIZE, MAX_PAYLOAD_SIZE, TAG_SIZE};
+use futures::stream::FuturesOrdered;
+use futures_core::{ready, Stream};
 use pin_project::pin_project;
-use std::io::{Error, ErrorKind};
+use std::fmt;
+use std::io::{Error, ErrorKind, SeekFrom};
+use std::ops::Range;
 use std::pin::Pin;
 use std::task::{Context, Poll};
-use tokio::io::AsyncWrite;
+use tokio::io::{AsyncRead, AsyncSeek, AsyncWrite, ReadBuf};
+use tokio::task::JoinHandle;
 
+/// A structured error from decrypting a DARE stream.
+///
+/// `DecryptWriter` used to collapse every failure into a generic `io::Error` of kind
+/// `InvalidData`, which gave callers no way to tell a wrong key apart from a corrupted or
+/// truncated object. Mirrors the shape of [`crate::machine::timehub::TimehubError`]: a plain enum
+/// with a stable numeric `code()`, convertible into `io::Error` (and from there into
+/// `anyhow::Error`) without losing which of the three actually happened.
+#[derive(Debug, Clone)]
+pub enum DecryptError {
+    /// A package's AEAD tag failed to verify -- either `key` is wrong, or the ciphertext (or its
+    /// header) was altered. `package_index` is 0-based.
+    AuthenticationFailed { package_index: u64 },
+    /// The stream ended partway through a package's header or payload.
+    TruncatedPackage { package_index: u64 },
+    /// A package's header could not be parsed.
+    MalformedHeader { package_index: u64, source: String },
+}
+
+impl DecryptError {
+    /// A stable numeric code identifying the error kind, for programmatic callers.
+    pub fn code(&self) -> i32 {
+        match self {
+            DecryptError::AuthenticationFailed { .. } => 1,
+            DecryptError::TruncatedPackage { .. } => 2,
+            DecryptError::MalformedHeader { .. } => 3,
+        }
+    }
+}
+
+impl fmt::Display for DecryptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecryptError::AuthenticationFailed { package_index } => write!(
+                f,
+                "authentication failed (code={}): package {package_index} failed to verify -- \
+                 wrong key or corrupted ciphertext",
+                self.code()
+            ),
+            DecryptError::TruncatedPackage { package_index } => write!(
+                f,
+                "truncated stream (code={}): package {package_index} ended before a full \
+                 header+payload+tag was read",
+                self.code()
+            ),
+            DecryptError::MalformedHeader {
+                package_index,
+                source,
+            } => write!(
+                f,
+                "malformed header (code={}): package {package_index}: {source}",
+                self.code()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DecryptError {}
+
+impl From<DecryptError> for Error {
+    fn from(err: DecryptError) -> Self {
+        let kind = match err {
+            DecryptError::AuthenticationFailed { .. } | DecryptError::MalformedHeader { .. } => {
+                ErrorKind::InvalidData
+            }
+            DecryptError::TruncatedPackage { .. } => ErrorKind::UnexpectedEof,
+        };
+        Error::new(kind, err)
+    }
+}
+
+/// Trims a decrypted DARE stream down to one or more disjoint plaintext windows, in the order
+/// bytes flow through [`DecryptWriter::poll_write`].
+///
+/// Windows are plaintext-absolute `(offset, length)` pairs, sorted by offset and non-overlapping.
+/// As each package is decrypted, [`Filter::apply`] walks the windows that fall (even partially)
+/// within that package's plaintext range and copies just those bytes to the output, so a caller
+/// asking for e.g. a header plus a footer gets both in one decrypt pass instead of two `get`
+/// calls.
 pub struct Filter {
-    pub offset: u64,
-    pub length: u64,
-    pub consumed: u64,
+    windows: Vec<(u64, u64)>,
+    /// How many plaintext bytes have flowed through `apply` so far.
+    consumed: u64,
+    /// Index of the first window that might still have bytes left to emit.
+    next_window: usize,
+}
+
+impl Filter {
+    /// Builds a filter over several disjoint windows. `windows` need not be pre-sorted; overlapping
+    /// windows are a caller error and will duplicate the overlapping bytes in the output.
+    pub fn new(mut windows: Vec<(u64, u64)>) -> Self {
+        windows.sort_by_key(|&(offset, _)| offset);
+        Self {
+            windows,
+            consumed: 0,
+            next_window: 0,
+        }
+    }
+
+    /// Builds a filter over a single contiguous `offset..offset+length` window.
+    pub fn single(offset: u64, length: u64) -> Self {
+        Self::new(vec![(offset, length)])
+    }
+
+    /// Copies the bytes of `plaintext` (the next `plaintext.len()` bytes of the stream, starting
+    /// at `self.consumed`) that fall within an active window into `out`, advancing past any
+    /// windows this package fully satisfies.
+    fn apply(&mut self, plaintext: &[u8], out: &mut BytesMut) {
+        let package_start = self.consumed;
+        let package_end = self.consumed + plaintext.len() as u64;
+
+        while let Some(&(offset, length)) = self.windows.get(self.next_window) {
+            let window_end = offset + length;
+
+            if window_end <= package_start {
+                // Fully consumed by an earlier package; never had bytes in this one.
+                self.next_window += 1;
+                continue;
+            }
+            if offset >= package_end {
+                // Starts after this package; nothing more to emit until the next one.
+                break;
+            }
+
+            let start = offset.max(package_start) - package_start;
+            let end = window_end.min(package_end) - package_start;
+            out.put_slice(&plaintext[start as usize..end as usize]);
+
+            if window_end > package_end {
+                // Window continues into the next package; stay on it.
+                break;
+            }
+            self.next_window += 1;
+        }
+
+        self.consumed = package_end;
+    }
+}
+
+/// Computes the ciphertext byte range that must be fetched to recover the plaintext bytes in
+/// `start..end`, along with the [`Filter`] that trims the decrypted output of that range back
+/// down to exactly those bytes.
+///
+/// The returned range is aligned to whole DARE packages (package boundaries are the only points
+/// a ciphertext stream can be split at and still decrypt independently), so callers that want to
+/// fetch several plaintext ranges concurrently can feed each ciphertext range straight into its
+/// own [`DecryptWriter`] without sharing any decryption state across them. `ciphertext_size` is
+/// the object's total (encrypted) size, used to clamp the range to the stream's actual end, since
+/// the final package is usually shorter than a full `MAX_PAYLOAD_SIZE`.
+pub fn ciphertext_range_for(start: u64, end: u64, ciphertext_size: u64) -> (Range<u64>, Filter) {
+    let package_plaintext = MAX_PAYLOAD_SIZE as u64;
+    let package_ciphertext = (HEADER_SIZE + MAX_PAYLOAD_SIZE + TAG_SIZE) as u64;
+
+    let first_package = start / package_plaintext;
+    let last_package = end.saturating_sub(1) / package_plaintext;
+
+    let cipher_start = first_package * package_ciphertext;
+    let cipher_end = ((last_package + 1) * package_ciphertext).min(ciphertext_size);
+
+    let filter = Filter::single(start - first_package * package_plaintext, end - start);
+
+    (cipher_start..cipher_end, filter)
+}
+
+/// Builds a [`DecryptWriter`] that decrypts a ciphertext range produced by
+/// [`ciphertext_range_for`], writing only the plaintext bytes the caller asked for to `inner`.
+pub fn decrypt_range_writer<W: AsyncWrite + Unpin>(
+    inner: W,
+    key: [u8; 32],
+    filter: Filter,
+) -> DecryptWriter<W> {
+    DecryptWriter::with_filter(inner, key, filter)
+}
+
+/// Streams `reader` through decryption under `key` without producing any plaintext output,
+/// confirming every package authenticates. Useful for periodic integrity audits of stored
+/// encrypted blobs, where the point is to confirm the object is intact and the key is right, not
+/// to recover the plaintext.
+pub async fn verify_stream<R: AsyncRead + Unpin>(
+    mut reader: R,
+    key: [u8; 32],
+) -> Result<(), DecryptError> {
+    let mut writer = DecryptWriter::new(tokio::io::sink(), key);
+    tokio::io::copy(&mut reader, &mut writer)
+        .await
+        .map_err(io_error_to_decrypt_error)?;
+    Ok(())
+}
+
+/// Recovers the [`DecryptError`] that produced an `io::Error` built via `DecryptError`'s `From`
+/// impl. Falls back to a generic truncation error if `err` didn't originate from this module (e.g.
+/// an I/O failure from `reader` itself).
+fn io_error_to_decrypt_error(err: Error) -> DecryptError {
+    err.into_inner()
+        .and_then(|err| err.downcast::<DecryptError>().ok())
+        .map(|err| *err)
+        .unwrap_or(DecryptError::TruncatedPackage { package_index: 0 })
 }
 
+/// A package decrypted on the `tokio` blocking pool.
+type ParallelDecryptResult = Result<Vec<u8>, DecryptError>;
+
 #[pin_project(project = DecryptWriterStateProj)]
 enum DecryptWriterState {
     ReadingHeader,
-    Decrypting(DAREHeader),
+    Decrypting { header: DAREHeader, package_index: u64 },
+    /// Several whole packages dispatched to the blocking pool at once. `FuturesOrdered` yields
+    /// them back in submission (i.e. sequence) order regardless of which finishes first, so no
+    /// separate reorder buffer is needed.
+    DecryptingParallel {
+        pending: FuturesOrdered<JoinHandle<ParallelDecryptResult>>,
+    },
     Writing,
 }
 
+/// Decrypts a DARE stream, package by package, as bytes are written in. Each package carries its
+/// own cipher suite in its header, so a single `DecryptWriter` transparently handles a stream
+/// whose packages were written under different [`dare::CipherSuite`]s -- there's no suite to pick
+/// here, unlike on the encrypt side.
+///
+/// By default packages are decrypted one at a time on the calling task. Call
+/// [`Self::with_max_parallel_packages`] to instead dispatch whole packages already sitting in the
+/// internal buffer to the `tokio` blocking pool, up to that many at once -- useful when `inner` is
+/// fast enough (e.g. a local file) that single-core AEAD decryption is the bottleneck.
 #[pin_project]
 pub struct DecryptWriter<W: AsyncWrite + Unpin> {
     #[pin]
     inner: W,
-    decryptor: DAREDecryptor,
+    key: [u8; 32],
     #[pin]
     state: DecryptWriterState,
     buffer: BytesMut,    // Internal buffer for incoming data
     decrypted: BytesMut, // Buffer for decrypted data
     is_writing: bool,    // Indicates if a write to the inner write is happening
-    should_filter: bool, // Indicates if the decrypted content should be filtered
-
-    // proprieties used in case of filtering
-    offset: u64,    // bytes less than offset should be ignored
-    consumed: u64,  // how many bytes we have consumed from original content
-    remaining: u64, // how many bytes left to return
+    filter: Option<Filter>, // If set, only bytes in the filter's windows are written to `inner`
+    max_parallel: usize, // How many whole buffered packages may be decrypted concurrently
+    /// 0-based index of the next package to be read out of `buffer`, for [`DecryptError`].
+    next_package_index: u64,
 }
 
 impl<W: AsyncWrite + Unpin> DecryptWriter<W> {
-    pub fn new(inner: W, decryptor: DAREDecryptor) -> Self {
+    pub fn new(inner: W, key: [u8; 32]) -> Self {
         Self {
             inner,
-            decryptor,
+            key,
             state: DecryptWriterState::ReadingHeader,
             buffer: BytesMut::new(),
             decrypted: BytesMut::new(),
             is_writing: false,
-            should_filter: false,
-            offset: 0,
-            consumed: 0,
-            remaining: 0,
+            filter: None,
+            max_parallel: 1,
+            next_package_index: 0,
         }
     }
 
-    pub fn with_filter(inner: W, decryptor: DAREDecryptor, filter: Filter) -> Self {
+    pub fn with_filter(inner: W, key: [u8; 32], filter: Filter) -> Self {
         Self {
             inner,
-            decryptor,
+            key,
             state: DecryptWriterState::ReadingHeader,
             buffer: BytesMut::new(),
             decrypted: BytesMut::new(),
             is_writing: false,
-            should_filter: true,
-            offset: filter.offset,
-            consumed: filter.consumed,
-            remaining: filter.length,
+            filter: Some(filter),
+            max_parallel: 1,
+            next_package_index: 0,
         }
     }
 
-    fn filter_bytes<'a>(&mut self, plaintext: &'a [u8]) -> &'a [u8] {
-        if !self.should_filter {
-            return plaintext;
-        }
-
-        let plaintext_size = plaintext.len() as u64;
+    /// Allows up to `max_parallel` whole buffered packages to be decrypted concurrently on the
+    /// blocking pool instead of one at a time. `max_parallel <= 1` (the default) keeps the
+    /// sequential path.
+    pub fn with_max_parallel_packages(mut self, max_parallel: usize) -> Self {
+        self.max_parallel = max_parallel;
+        self
+    }
 
-        // We haven't reached offset yet, we must ignore the decrypted content
-        if self.consumed + plaintext_size <= self.offset {
-            self.consumed += plaintext_size;
-            return &plaintext[plaintext.len()..];
+    fn filter_bytes(&mut self, plaintext: &[u8]) -> BytesMut {
+        match &mut self.filter {
+            None => BytesMut::from(plaintext),
+            Some(filter) => {
+                let mut out = BytesMut::new();
+                filter.apply(plaintext, &mut out);
+                out
+            }
         }
+    }
+}
 
-        // We reached offset, so we take the bytes from offset up to the end of package
-        //
-        // +---------------------------------+
-        // |  DISCARD  |      GRAB THIS      |
-        // +---------------------------------+
-        // |           |
-        // consumed    offset
-        //
-        let plaintext_within_range = &plaintext[(self.offset - self.consumed) as usize..];
-        let plaintext_within_range_size = plaintext_within_range.len() as u64;
+/// Counts how many complete DARE packages (header + payload + tag) are fully present at the
+/// front of `buffer`, without consuming anything, stopping once `max_packages` is reached.
+/// `first_package_index` is the 0-based index of the first package in `buffer`, used only to
+/// label a [`DecryptError::MalformedHeader`] should one of the headers fail to parse.
+fn count_whole_packages(
+    buffer: &[u8],
+    max_packages: usize,
+    first_package_index: u64,
+) -> Result<usize, DecryptError> {
+    let mut offset = 0usize;
+    let mut count = 0usize;
 
-        // if grabbed fewer bytes than the remaining bytes to take, we return it all
-        //
-        // +---------------------------------+-----------------------
-        // |  DISCARD  |      GRAB THIS      |       NEXT PACKAGE
-        // +---------------------------------+-----------------------
-        // |           |                                   |
-        // consumed    offset                              remaining
-        //
-        if plaintext_within_range_size <= self.remaining {
-            self.consumed += plaintext_size;
-            self.offset = self.consumed;
-            self.remaining -= plaintext_within_range_size;
-            return plaintext_within_range;
+    while count < max_packages && buffer.len() - offset >= HEADER_SIZE {
+        let header = DAREHeader::from_bytes(&buffer[offset..offset + HEADER_SIZE]).map_err(
+            |err| DecryptError::MalformedHeader {
+                package_index: first_package_index + count as u64,
+                source: err.to_string(),
+            },
+        )?;
+        let needed = HEADER_SIZE + header.payload_size() as usize + TAG_SIZE;
+        if buffer.len() - offset < needed {
+            break;
         }
-
-        // if not, we must take up to the remaining
-        //
-        // +---------------------------------+
-        // |  DISCARD  | GRAB THIS | DISCARD |
-        // +---------------------------------+
-        // |           |           |
-        // consumed    offset      remaining
-        //
-        let plaintext_within_range = &plaintext_within_range[..self.remaining as usize];
-        let plaintext_within_range_size = plaintext_within_range.len() as u64;
-        self.consumed += plaintext_size;
-        self.offset = self.consumed;
-        self.remaining -= plaintext_within_range_size;
-
-        plaintext_within_range
+        offset += needed;
+        count += 1;
     }
+
+    Ok(count)
 }
 
 impl<R: AsyncWrite + Unpin> AsyncWrite for DecryptWriter<R> {
@@ -148,19 +351,74 @@ impl<R: AsyncWrite + Unpin> AsyncWrite for DecryptWriter<R> {
 
             match this.state.as_mut().project() {
                 DecryptWriterStateProj::ReadingHeader => {
+                    // Only worth dispatching to the blocking pool if several whole packages are
+                    // already buffered and there's no narrow-range filter to make that wasted
+                    // work; otherwise fall through to the sequential path below.
+                    if *this.max_parallel > 1 && this.filter.is_none() {
+                        let first_package_index = *this.next_package_index;
+                        let available = count_whole_packages(
+                            this.buffer,
+                            *this.max_parallel,
+                            first_package_index,
+                        )?;
+                        if available >= 2 {
+                            let key = *this.key;
+                            let pending = (0..available)
+                                .map(|i| {
+                                    let package_index = first_package_index + i as u64;
+                                    let header_bytes = this.buffer.split_to(HEADER_SIZE);
+                                    let dare_header = DAREHeader::from_bytes(header_bytes.as_ref())
+                                        .map_err(|err| DecryptError::MalformedHeader {
+                                            package_index,
+                                            source: err.to_string(),
+                                        })?;
+                                    let message = this
+                                        .buffer
+                                        .split_to(dare_header.payload_size() as usize + TAG_SIZE);
+
+                                    Ok(tokio::task::spawn_blocking(move || {
+                                        DAREDecryptor::new(key)
+                                            .decrypt(&dare_header.to_bytes()[..], message.as_ref())
+                                            .map_err(|_| DecryptError::AuthenticationFailed {
+                                                package_index,
+                                            })
+                                    }))
+                                })
+                                .collect::<Result<FuturesOrdered<_>, DecryptError>>()?;
+                            *this.next_package_index += available as u64;
+
+                            this.state
+                                .set(DecryptWriterState::DecryptingParallel { pending });
+                            continue;
+                        }
+                    }
+
                     // if our internal buffer is not big enough to read the header of a package,
                     // we request more data
                     if this.buffer.len() < HEADER_SIZE {
                         return Poll::Ready(Ok(buf.len()));
                     }
 
+                    let package_index = *this.next_package_index;
                     let header = this.buffer.split_to(HEADER_SIZE);
 
-                    let dare_header = DAREHeader::from_bytes(header.as_ref())
-                        .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
-                    this.state.set(DecryptWriterState::Decrypting(dare_header));
+                    let dare_header = DAREHeader::from_bytes(header.as_ref()).map_err(|err| {
+                        DecryptError::MalformedHeader {
+                            package_index,
+                            source: err.to_string(),
+                        }
+                    })?;
+                    this.state.set(DecryptWriterState::Decrypting {
+                        header: dare_header,
+                        package_index,
+                    });
                 }
-                DecryptWriterStateProj::Decrypting(dare_header) => {
+                DecryptWriterStateProj::Decrypting {
+                    header: dare_header,
+                    package_index,
+                } => {
+                    let package_index = *package_index;
+
                     // if our internal buffer is not big enough to read the rest of the package,
                     // we request more data
                     if this.buffer.len() < dare_header.payload_size() as usize + TAG_SIZE {
@@ -171,10 +429,10 @@ impl<R: AsyncWrite + Unpin> AsyncWrite for DecryptWriter<R> {
                         .buffer
                         .split_to(dare_header.payload_size() as usize + TAG_SIZE);
 
-                    let decrypted = this
-                        .decryptor
+                    let decrypted = DAREDecryptor::new(*this.key)
                         .decrypt(&dare_header.to_bytes()[..], message.as_ref())
-                        .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+                        .map_err(|_| DecryptError::AuthenticationFailed { package_index })?;
+                    *this.next_package_index += 1;
 
                     #[allow(clippy::drop_non_drop)]
                     drop(this);
@@ -185,6 +443,27 @@ impl<R: AsyncWrite + Unpin> AsyncWrite for DecryptWriter<R> {
                     this.state.set(DecryptWriterState::Writing);
                     *this.is_writing = true;
                 }
+                DecryptWriterStateProj::DecryptingParallel { pending } => {
+                    let next = ready!(Pin::new(pending).poll_next(cx));
+                    match next {
+                        Some(join_result) => {
+                            let decrypted = join_result
+                                .map_err(|err| Error::new(ErrorKind::Other, err.to_string()))?
+                                .map_err(Error::from)?;
+
+                            #[allow(clippy::drop_non_drop)]
+                            drop(this);
+                            let decrypted = self.filter_bytes(&decrypted);
+                            let mut this = self.as_mut().project();
+
+                            this.decrypted.put_slice(decrypted);
+                        }
+                        None => {
+                            this.state.set(DecryptWriterState::Writing);
+                            *this.is_writing = true;
+                        }
+                    }
+                }
                 DecryptWriterStateProj::Writing => {
                     match ready!(this.inner.poll_write(cx, this.decrypted)) {
                         Ok(n) => {
@@ -209,18 +488,39 @@ impl<R: AsyncWrite + Unpin> AsyncWrite for DecryptWriter<R> {
             return Poll::Ready(Ok(()));
         }
 
+        let package_index = *this.next_package_index;
+
+        // `poll_write` already consumed this package's header and decrypts as soon as the whole
+        // payload is buffered, so what is left here is a short payload, not another header.
+        if matches!(*this.state, DecryptWriterState::Decrypting { .. }) {
+            return Poll::Ready(Err(DecryptError::TruncatedPackage { package_index }.into()));
+        }
+
+        // A trailing package too short to even hold a header is truncated, not malformed --
+        // splitting past `buffer`'s end here would panic.
+        if this.buffer.len() < HEADER_SIZE {
+            return Poll::Ready(Err(DecryptError::TruncatedPackage { package_index }.into()));
+        }
+
         let header = this.buffer.split_to(HEADER_SIZE);
 
-        let dare_header = DAREHeader::from_bytes(header.as_ref())
-            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
-        let message = this
-            .buffer
-            .split_to(dare_header.payload_size() as usize + TAG_SIZE);
+        let dare_header = DAREHeader::from_bytes(header.as_ref()).map_err(|err| {
+            DecryptError::MalformedHeader {
+                package_index,
+                source: err.to_string(),
+            }
+        })?;
 
-        let decrypted = this
-            .decryptor
+        let payload_len = dare_header.payload_size() as usize + TAG_SIZE;
+        if this.buffer.len() < payload_len {
+            return Poll::Ready(Err(DecryptError::TruncatedPackage { package_index }.into()));
+        }
+        let message = this.buffer.split_to(payload_len);
+
+        let decrypted = DAREDecryptor::new(*this.key)
             .decrypt(&dare_header.to_bytes()[..], message.as_ref())
-            .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+            .map_err(|_| DecryptError::AuthenticationFailed { package_index })?;
+        *this.next_package_index += 1;
 
         match ready!(this.inner.poll_write(cx, &decrypted)) {
             Ok(_) => Poll::Ready(Ok(())),
@@ -233,10 +533,328 @@ impl<R: AsyncWrite + Unpin> AsyncWrite for DecryptWriter<R> {
     }
 }
 
+/// Size, in ciphertext bytes, of one DARE package (header + payload + tag).
+const PACKAGE_CIPHERTEXT_SIZE: u64 = (HEADER_SIZE + MAX_PAYLOAD_SIZE + TAG_SIZE) as u64;
+
+enum DecryptReaderState {
+    /// No load in flight; `poll_read` should start one for the package covering `position`.
+    Idle,
+    /// Waiting for the underlying reader to finish seeking to a package's ciphertext offset.
+    Seeking,
+    /// Reading a package's header into `buf` (`filled` bytes read so far).
+    ReadingHeader { buf: Vec<u8>, filled: usize },
+    /// Reading a package's payload + tag into `buf`, now that its header is known.
+    ReadingPayload {
+        header: DAREHeader,
+        buf: Vec<u8>,
+        filled: usize,
+    },
+}
+
+/// Decrypts an object by seeking directly to the DARE package covering a requested plaintext
+/// offset, rather than decrypting every package from byte zero like [`DecryptWriter`] +
+/// [`Filter`] do. Since the package containing plaintext offset `O` is `O / MAX_PAYLOAD_SIZE`,
+/// with ciphertext position `package_index * (HEADER_SIZE + MAX_PAYLOAD_SIZE + TAG_SIZE)`, a seek
+/// costs O(1) packages to satisfy instead of O(packages before it) -- useful when `inner` is a
+/// cheaply-seekable local source (e.g. a file) and only a small tail of a large object is needed.
+///
+/// Each package's header is self-describing (it carries its own nonce), so a package can be
+/// decrypted on its own with a freshly constructed [`DAREDecryptor`] -- there's no running
+/// sequence state to carry from one package to the next, matching how [`decrypt_range_writer`]
+/// already decrypts an arbitrary package range without replaying the packages before it.
+#[pin_project]
+pub struct DecryptReader<R> {
+    #[pin]
+    inner: R,
+    key: [u8; 32],
+    /// Total plaintext size of the object, needed to size the final (possibly short) package.
+    plaintext_size: u64,
+    /// Plaintext offset the next byte served by `poll_read` comes from.
+    position: u64,
+    /// Decrypted plaintext of the package covering `position`, already trimmed to start there.
+    decrypted: BytesMut,
+    state: DecryptReaderState,
+}
+
+impl<R: AsyncRead + AsyncSeek> DecryptReader<R> {
+    pub fn new(inner: R, key: [u8; 32], plaintext_size: u64) -> Self {
+        Self {
+            inner,
+            key,
+            plaintext_size,
+            position: 0,
+            decrypted: BytesMut::new(),
+            state: DecryptReaderState::Idle,
+        }
+    }
+}
+
+/// Applies a signed offset to a `u64` base, as used by `SeekFrom::Current`/`SeekFrom::End`.
+fn apply_signed_offset(base: u64, offset: i64) -> std::io::Result<u64> {
+    if offset >= 0 {
+        base.checked_add(offset as u64)
+    } else {
+        base.checked_sub(offset.unsigned_abs())
+    }
+    .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "seek out of bounds"))
+}
+
+impl<R: AsyncRead + AsyncSeek> AsyncRead for DecryptReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let mut this = self.project();
+
+        loop {
+            if !this.decrypted.is_empty() {
+                let to_copy = this.decrypted.len().min(buf.remaining());
+                buf.put_slice(&this.decrypted[..to_copy]);
+                this.decrypted.advance(to_copy);
+                *this.position += to_copy as u64;
+                return Poll::Ready(Ok(()));
+            }
+
+            if *this.position >= *this.plaintext_size {
+                return Poll::Ready(Ok(()));
+            }
+
+            match this.state {
+                DecryptReaderState::Idle => {
+                    let package_index = *this.position / MAX_PAYLOAD_SIZE as u64;
+                    let cipher_offset = package_index * PACKAGE_CIPHERTEXT_SIZE;
+                    this.inner
+                        .as_mut()
+                        .start_seek(SeekFrom::Start(cipher_offset))?;
+                    *this.state = DecryptReaderState::Seeking;
+                }
+                DecryptReaderState::Seeking => {
+                    ready!(this.inner.as_mut().poll_complete(cx))?;
+                    *this.state = DecryptReaderState::ReadingHeader {
+                        buf: vec![0u8; HEADER_SIZE],
+                        filled: 0,
+                    };
+                }
+                DecryptReaderState::ReadingHeader { buf: header_buf, filled } => {
+                    if *filled < HEADER_SIZE {
+                        let mut read_buf = ReadBuf::new(&mut header_buf[*filled..]);
+                        ready!(this.inner.as_mut().poll_read(cx, &mut read_buf))?;
+                        let n = read_buf.filled().len();
+                        if n == 0 {
+                            return Poll::Ready(Err(Error::new(
+                                ErrorKind::UnexpectedEof,
+                                "unexpected EOF reading DARE package header",
+                            )));
+                        }
+                        *filled += n;
+                        continue;
+                    }
+
+                    let header = DAREHeader::from_bytes(header_buf.as_slice())
+                        .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+                    let payload_len = header.payload_size() as usize + TAG_SIZE;
+                    *this.state = DecryptReaderState::ReadingPayload {
+                        header,
+                        buf: vec![0u8; payload_len],
+                        filled: 0,
+                    };
+                }
+                DecryptReaderState::ReadingPayload { header, buf: payload_buf, filled } => {
+                    if *filled < payload_buf.len() {
+                        let mut read_buf = ReadBuf::new(&mut payload_buf[*filled..]);
+                        ready!(this.inner.as_mut().poll_read(cx, &mut read_buf))?;
+                        let n = read_buf.filled().len();
+                        if n == 0 {
+                            return Poll::Ready(Err(Error::new(
+                                ErrorKind::UnexpectedEof,
+                                "unexpected EOF reading DARE package payload",
+                            )));
+                        }
+                        *filled += n;
+                        continue;
+                    }
+
+                    let mut decryptor = DAREDecryptor::new(*this.key);
+                    let decrypted = decryptor
+                        .decrypt(&header.to_bytes()[..], payload_buf.as_slice())
+                        .map_err(|err| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+
+                    let package_index = *this.position / MAX_PAYLOAD_SIZE as u64;
+                    let package_start = package_index * MAX_PAYLOAD_SIZE as u64;
+                    let skip = (*this.position - package_start) as usize;
+
+                    this.decrypted.clear();
+                    this.decrypted.put_slice(&decrypted);
+                    this.decrypted.advance(skip.min(this.decrypted.len()));
+                    *this.state = DecryptReaderState::Idle;
+                }
+            }
+        }
+    }
+}
+
+impl<R: AsyncRead + AsyncSeek> AsyncSeek for DecryptReader<R> {
+    fn start_seek(self: Pin<&mut Self>, position: SeekFrom) -> std::io::Result<()> {
+        let this = self.project();
+        let target = match position {
+            SeekFrom::Start(n) => n,
+            SeekFrom::Current(delta) => apply_signed_offset(*this.position, delta)?,
+            SeekFrom::End(delta) => apply_signed_offset(*this.plaintext_size, delta)?,
+        };
+
+        *this.position = target;
+        this.decrypted.clear();
+        *this.state = DecryptReaderState::Idle;
+        Ok(())
+    }
+
+    fn poll_complete(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<u64>> {
+        Poll::Ready(Ok(*self.project().position))
+    }
+}
+
+enum StreamDecryptReaderState {
+    /// Reading a package's header (`filled` bytes read so far).
+    ReadingHeader { buf: Vec<u8>, filled: usize },
+    /// Reading a package's payload + tag, now that its header is known.
+    ReadingPayload {
+        header: DAREHeader,
+        buf: Vec<u8>,
+        filled: usize,
+    },
+    /// A clean EOF was observed between packages; every subsequent read returns EOF too.
+    Done,
+}
+
+/// Decrypts a DARE stream lazily, package by package, as it is read -- the pull-based mirror of
+/// [`crate::encryption::encryptor::EncryptReader`]. Unlike [`DecryptReader`], which seeks directly
+/// to the package covering a requested offset, this only ever reads `inner` forward, so it works
+/// over sources that can't be seeked, such as an HTTP response body streamed straight into a pull
+/// pipeline (e.g. another outbound HTTP response, or a file written incrementally), without
+/// buffering the whole object in memory.
+#[pin_project]
+pub struct StreamDecryptReader<R> {
+    #[pin]
+    inner: R,
+    key: [u8; 32],
+    state: StreamDecryptReaderState,
+    /// Decrypted plaintext of the most recently finished package, not yet handed to the caller.
+    decrypted: BytesMut,
+    /// 0-based index of the package currently being read, for [`DecryptError`].
+    package_index: u64,
+}
+
+impl<R: AsyncRead> StreamDecryptReader<R> {
+    pub fn new(inner: R, key: [u8; 32]) -> Self {
+        Self {
+            inner,
+            key,
+            state: StreamDecryptReaderState::ReadingHeader {
+                buf: vec![0u8; HEADER_SIZE],
+                filled: 0,
+            },
+            decrypted: BytesMut::new(),
+            package_index: 0,
+        }
+    }
+}
+
+impl<R: AsyncRead> AsyncRead for StreamDecryptReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let mut this = self.project();
+
+        loop {
+            if !this.decrypted.is_empty() {
+                let to_copy = this.decrypted.len().min(buf.remaining());
+                buf.put_slice(&this.decrypted[..to_copy]);
+                this.decrypted.advance(to_copy);
+                return Poll::Ready(Ok(()));
+            }
+
+            match this.state {
+                StreamDecryptReaderState::Done => return Poll::Ready(Ok(())),
+                StreamDecryptReaderState::ReadingHeader {
+                    buf: header_buf,
+                    filled,
+                } => {
+                    if *filled < HEADER_SIZE {
+                        let mut read_buf = ReadBuf::new(&mut header_buf[*filled..]);
+                        ready!(this.inner.as_mut().poll_read(cx, &mut read_buf))?;
+                        let n = read_buf.filled().len();
+                        if n == 0 {
+                            if *filled == 0 {
+                                // Clean EOF between packages: the stream simply ends here.
+                                *this.state = StreamDecryptReaderState::Done;
+                                return Poll::Ready(Ok(()));
+                            }
+                            return Poll::Ready(Err(DecryptError::TruncatedPackage {
+                                package_index: *this.package_index,
+                            }
+                            .into()));
+                        }
+                        *filled += n;
+                        continue;
+                    }
+
+                    let header = DAREHeader::from_bytes(header_buf.as_slice()).map_err(|err| {
+                        DecryptError::MalformedHeader {
+                            package_index: *this.package_index,
+                            source: err.to_string(),
+                        }
+                    })?;
+                    let payload_len = header.payload_size() as usize + TAG_SIZE;
+                    *this.state = StreamDecryptReaderState::ReadingPayload {
+                        header,
+                        buf: vec![0u8; payload_len],
+                        filled: 0,
+                    };
+                }
+                StreamDecryptReaderState::ReadingPayload {
+                    header,
+                    buf: payload_buf,
+                    filled,
+                } => {
+                    if *filled < payload_buf.len() {
+                        let mut read_buf = ReadBuf::new(&mut payload_buf[*filled..]);
+                        ready!(this.inner.as_mut().poll_read(cx, &mut read_buf))?;
+                        let n = read_buf.filled().len();
+                        if n == 0 {
+                            return Poll::Ready(Err(DecryptError::TruncatedPackage {
+                                package_index: *this.package_index,
+                            }
+                            .into()));
+                        }
+                        *filled += n;
+                        continue;
+                    }
+
+                    let decrypted = DAREDecryptor::new(*this.key)
+                        .decrypt(&header.to_bytes()[..], payload_buf.as_slice())
+                        .map_err(|_| DecryptError::AuthenticationFailed {
+                            package_index: *this.package_index,
+                        })?;
+
+                    this.decrypted.put_slice(&decrypted);
+                    *this.package_index += 1;
+                    *this.state = StreamDecryptReaderState::ReadingHeader {
+                        buf: vec![0u8; HEADER_SIZE],
+                        filled: 0,
+                    };
+                }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::encryption::decryptor::{DecryptWriter, Filter};
-    use dare::{CipherSuite, DAREDecryptor, DAREEncryptor};
+    use dare::{CipherSuite, DAREEncryptor};
     use std::io::Cursor;
     use tokio::io::AsyncWriteExt;
 
@@ -256,9 +874,8 @@ mod tests {
             .unwrap();
 
         // Decryption
-        let decryptor = DAREDecryptor::new(key);
         let mut destination = Cursor::new(Vec::new());
-        let mut writer = DecryptWriter::new(&mut destination, decryptor);
+        let mut writer = DecryptWriter::new(&mut destination, key);
         writer.write_all(&encrypted.into_inner()).await.unwrap();
 
         // assertion
@@ -341,17 +958,12 @@ mod tests {
         ];
 
         for test in tests {
-            let decryptor = DAREDecryptor::new(key);
             let mut decrypted = Vec::new();
             let decrypted_cursor = Cursor::new(&mut decrypted);
             let mut writer = DecryptWriter::with_filter(
                 decrypted_cursor,
-                decryptor,
-                Filter {
-                    offset: test.offset,
-                    length: test.length,
-                    consumed: 0,
-                },
+                key,
+                Filter::single(test.offset, test.length),
             );
             tokio::io::copy(&mut Cursor::new(&mut encrypted), &mut writer)
                 .await
@@ -367,4 +979,265 @@ mod tests {
             assert_eq!(test.expected.as_bytes(), decrypted);
         }
     }
+
+    #[tokio::test]
+    async fn test_async_decryption_with_multi_window_filter() {
+        let key = [0u8; 32]; // In practice, use a secure random key
+
+        // Same 200,000-byte / 4-package layout as `test_async_decryption_with_filter`.
+        let plaintext = b"abcde".repeat(40000).to_vec();
+
+        let mut encryptor =
+            DAREEncryptor::new(key, CipherSuite::AES256GCM).expect("should not fail");
+        let mut encrypted = Vec::new();
+        encryptor
+            .encrypt_stream(&mut Cursor::new(&plaintext), &mut encrypted)
+            .await
+            .unwrap();
+
+        // Grab a window within the first package and one within the last, in a single pass.
+        let mut decrypted = Vec::new();
+        let decrypted_cursor = Cursor::new(&mut decrypted);
+        let mut writer = DecryptWriter::with_filter(
+            decrypted_cursor,
+            key,
+            Filter::new(vec![(196605, 6), (0, 5)]),
+        );
+        tokio::io::copy(&mut Cursor::new(&mut encrypted), &mut writer)
+            .await
+            .unwrap();
+
+        assert_eq!(b"abcdeabcdea".as_slice(), decrypted);
+    }
+
+    #[tokio::test]
+    async fn test_async_decryption_parallel() {
+        let key = [0u8; 32]; // In practice, use a secure random key
+
+        // 200,000 bytes across 4 packages -- enough for the parallel path to engage.
+        let plaintext = b"a".repeat(200000).to_vec();
+
+        let mut encryptor =
+            DAREEncryptor::new(key, CipherSuite::AES256GCM).expect("should not fail");
+        let mut encrypted = Cursor::new(Vec::new());
+        encryptor
+            .encrypt_stream(&mut Cursor::new(&plaintext), &mut encrypted)
+            .await
+            .unwrap();
+
+        let mut destination = Cursor::new(Vec::new());
+        let mut writer =
+            DecryptWriter::new(&mut destination, key).with_max_parallel_packages(4);
+        writer.write_all(&encrypted.into_inner()).await.unwrap();
+
+        let bytes = destination.into_inner();
+        assert_eq!(plaintext.len(), bytes.len());
+        assert_eq!(plaintext, bytes);
+    }
+
+    #[tokio::test]
+    async fn test_verify_stream_success() {
+        use crate::encryption::decryptor::verify_stream;
+
+        let key = [0u8; 32];
+        let plaintext = b"a".repeat(200000).to_vec();
+
+        let mut encryptor =
+            DAREEncryptor::new(key, CipherSuite::AES256GCM).expect("should not fail");
+        let mut encrypted = Vec::new();
+        encryptor
+            .encrypt_stream(&mut Cursor::new(&plaintext), &mut encrypted)
+            .await
+            .unwrap();
+
+        verify_stream(Cursor::new(&encrypted), key).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_stream_detects_wrong_key() {
+        use crate::encryption::decryptor::{verify_stream, DecryptError};
+
+        let key = [0u8; 32];
+        let wrong_key = [1u8; 32];
+        let plaintext = b"a".repeat(200000).to_vec();
+
+        let mut encryptor =
+            DAREEncryptor::new(key, CipherSuite::AES256GCM).expect("should not fail");
+        let mut encrypted = Vec::new();
+        encryptor
+            .encrypt_stream(&mut Cursor::new(&plaintext), &mut encrypted)
+            .await
+            .unwrap();
+
+        let err = verify_stream(Cursor::new(&encrypted), wrong_key)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DecryptError::AuthenticationFailed { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_verify_stream_detects_truncation() {
+        use crate::encryption::decryptor::{verify_stream, DecryptError};
+
+        let key = [0u8; 32];
+        let plaintext = b"a".repeat(200000).to_vec();
+
+        let mut encryptor =
+            DAREEncryptor::new(key, CipherSuite::AES256GCM).expect("should not fail");
+        let mut encrypted = Vec::new();
+        encryptor
+            .encrypt_stream(&mut Cursor::new(&plaintext), &mut encrypted)
+            .await
+            .unwrap();
+
+        // Chop off the last few bytes so the final package is short.
+        encrypted.truncate(encrypted.len() - 10);
+
+        let err = verify_stream(Cursor::new(&encrypted), key).await.unwrap_err();
+        assert!(matches!(err, DecryptError::TruncatedPackage { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_async_decrypt_reader_seek() {
+        use crate::encryption::decryptor::DecryptReader;
+        use std::io::SeekFrom;
+        use tokio::io::{AsyncReadExt, AsyncSeekExt};
+
+        let key = [0u8; 32]; // In practice, use a secure random key
+
+        // Same layout as `test_async_decryption_with_filter`: 200,000 bytes across 4 packages.
+        let plaintext = b"abcde".repeat(40000).to_vec();
+
+        let mut encryptor =
+            DAREEncryptor::new(key, CipherSuite::AES256GCM).expect("should not fail");
+        let mut encrypted = Vec::new();
+        encryptor
+            .encrypt_stream(&mut Cursor::new(&plaintext), &mut encrypted)
+            .await
+            .unwrap();
+
+        struct TestSpec {
+            offset: u64,
+            length: usize,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestSpec {
+                offset: 0,
+                length: 6,
+                expected: "abcdea",
+            },
+            TestSpec {
+                offset: 65533,
+                length: 8,
+                expected: "deabcdea",
+            },
+            TestSpec {
+                offset: 131069,
+                length: 7,
+                expected: "eabcdea",
+            },
+            TestSpec {
+                offset: 196605,
+                length: 6,
+                expected: "abcdea",
+            },
+            TestSpec {
+                offset: 199999,
+                length: 1,
+                expected: "e",
+            },
+        ];
+
+        for test in tests {
+            let mut reader =
+                DecryptReader::new(Cursor::new(&encrypted), key, plaintext.len() as u64);
+            reader.seek(SeekFrom::Start(test.offset)).await.unwrap();
+
+            let mut decrypted = vec![0u8; test.length];
+            reader.read_exact(&mut decrypted).await.unwrap();
+
+            assert_eq!(
+                test.expected.as_bytes(),
+                decrypted,
+                "test case (len, offset) = ({}, {})",
+                test.length,
+                test.offset
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_decrypt_reader() {
+        use crate::encryption::decryptor::StreamDecryptReader;
+        use tokio::io::AsyncReadExt;
+
+        let key = [0u8; 32]; // In practice, use a secure random key
+
+        // 200,000 bytes across 4 packages, same layout as the other multi-package tests.
+        let plaintext = b"a".repeat(200000).to_vec();
+
+        let mut encryptor =
+            DAREEncryptor::new(key, CipherSuite::AES256GCM).expect("should not fail");
+        let mut encrypted = Cursor::new(Vec::new());
+        encryptor
+            .encrypt_stream(&mut Cursor::new(&plaintext), &mut encrypted)
+            .await
+            .unwrap();
+
+        let mut reader = StreamDecryptReader::new(Cursor::new(encrypted.into_inner()), key);
+        let mut decrypted = Vec::new();
+        reader.read_to_end(&mut decrypted).await.unwrap();
+
+        assert_eq!(plaintext, decrypted);
+    }
+
+    #[tokio::test]
+    async fn test_stream_decrypt_reader_detects_wrong_key() {
+        use crate::encryption::decryptor::StreamDecryptReader;
+        use tokio::io::AsyncReadExt;
+
+        let key = [0u8; 32];
+        let wrong_key = [1u8; 32];
+        let plaintext = b"a".repeat(200000).to_vec();
+
+        let mut encryptor =
+            DAREEncryptor::new(key, CipherSuite::AES256GCM).expect("should not fail");
+        let mut encrypted = Vec::new();
+        encryptor
+            .encrypt_stream(&mut Cursor::new(&plaintext), &mut encrypted)
+            .await
+            .unwrap();
+
+        let mut reader = StreamDecryptReader::new(Cursor::new(encrypted), wrong_key);
+        let mut decrypted = Vec::new();
+        let err = reader.read_to_end(&mut decrypted).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[tokio::test]
+    async fn test_stream_decrypt_reader_detects_truncation() {
+        use crate::encryption::decryptor::StreamDecryptReader;
+        use tokio::io::AsyncReadExt;
+
+        let key = [0u8; 32];
+        let plaintext = b"a".repeat(200000).to_vec();
+
+        let mut encryptor =
+            DAREEncryptor::new(key, CipherSuite::AES256GCM).expect("should not fail");
+        let mut encrypted = Vec::new();
+        encryptor
+            .encrypt_stream(&mut Cursor::new(&plaintext), &mut encrypted)
+            .await
+            .unwrap();
+
+        // Chop off the last few bytes so the final package is short.
+        encrypted.truncate(encrypted.len() - 10);
+
+        let mut reader = StreamDecryptReader::new(Cursor::new(encrypted), key);
+        let mut decrypted = Vec::new();
+        let err = reader.read_to_end(&mut decrypted).await.unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::UnexpectedEof);
+    }
 }
@@ -1,6 +1,7 @@
 use dare::{DAREEncryptor, MAX_PAYLOAD_SIZE};
 use futures_core::ready;
 use pin_project::pin_project;
+use std::io::{Error, ErrorKind};
 use std::pin::Pin;
 use std::task::{Context, Poll};
 use tokio::io::{AsyncRead, ReadBuf};
@@ -66,11 +67,10 @@ impl<R: AsyncRead> AsyncRead for EncryptReader<R> {
         }
 
         // Encrypt the chunk and store it in the buffer
-        // TODO: handle error
         let encrypted_data = this
             .encryptor
             .encrypt(&this.chunk_buffer[..*this.chunk_filled])
-            .unwrap();
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "failed to encrypt chunk"))?;
         this.buffer.extend_from_slice(&encrypted_data);
 
         // Reset chunk filled for the next read cycle
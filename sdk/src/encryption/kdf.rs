@@ -0,0 +1,52 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+use rand::rngs::OsRng;
+use rand::RngCore;
+
+/// Argon2id algorithm label stored in an encrypted object's metadata, so `get` knows how to
+/// re-derive the same key-encryption-key from a passphrase.
+pub const ARGON2ID: &str = "argon2id";
+
+/// Argon2id parameters used to derive an SSE-C key-encryption-key from a passphrase. Stored
+/// alongside an object's other encryption metadata at write time, so the same derivation can be
+/// repeated on read without the caller needing to remember the parameters -- only the passphrase.
+#[derive(Debug, Clone)]
+pub struct KdfParams {
+    pub salt: [u8; 16],
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl Default for KdfParams {
+    /// OWASP-recommended Argon2id baseline (19 MiB, 2 iterations, 1 lane) with a fresh random
+    /// salt.
+    fn default() -> Self {
+        let mut salt = [0u8; 16];
+        OsRng.fill_bytes(&mut salt);
+        KdfParams {
+            salt,
+            memory_kib: 19 * 1024,
+            iterations: 2,
+            parallelism: 1,
+        }
+    }
+}
+
+/// Derives a 32-byte key-encryption-key from `passphrase` using Argon2id under `params`.
+pub fn derive_kek(passphrase: &str, params: &KdfParams) -> anyhow::Result<[u8; 32]> {
+    let argon2_params = Params::new(
+        params.memory_kib,
+        params.iterations,
+        params.parallelism,
+        Some(32),
+    )
+    .map_err(|err| anyhow::anyhow!("invalid Argon2 parameters: {err}"))?;
+    let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, argon2_params);
+
+    let mut kek = [0u8; 32];
+    argon2
+        .hash_password_into(passphrase.as_bytes(), &params.salt, &mut kek)
+        .map_err(|err| anyhow::anyhow!("Argon2 key derivation failed: {err}"))?;
+
+    Ok(kek)
+}
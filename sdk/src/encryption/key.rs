@@ -1,9 +1,20 @@
+use crate::encryption::kdf::{self, KdfParams};
+use aes::cipher::{KeyIvInit, StreamCipher};
+use anyhow::anyhow;
 use base64::{engine::general_purpose::STANDARD, Engine as _};
+use ctr::Ctr128BE;
 use dare::{CipherSuite, DAREDecryptor, DAREEncryptor, HEADER_SIZE};
+use fendermint_crypto::{PublicKey, SecretKey};
 use hmac::{Hmac, Mac};
 use rand::rngs::OsRng;
 use rand::RngCore;
-use sha2::Sha256;
+use sha2::{Digest, Sha256};
+
+type Aes256Ctr = Ctr128BE<aes::Aes256>;
+
+/// Algorithm label stored alongside an [`EciesSealedObjectKey`], identifying how the object key
+/// was wrapped.
+pub const ECIES_ALGORITHM: &str = "ECIES-secp256k1-AES256GCM";
 
 pub fn generate_object_key(
     kek: &[u8],
@@ -54,6 +65,16 @@ pub struct ObjectKey {
     pub key: [u8; 32],
 }
 
+/// The metadata needed to recover an SSE-KMS object's content key: the key as wrapped by the
+/// external KMS, and the algorithm label it was wrapped under. Unlike [`SealedObjectKey`], this
+/// can't be unsealed locally — recovering the plaintext key requires calling back out to the same
+/// `KeyManagementService` that issued it (see `crate::encryption::sse_kms::decrypt_key`).
+#[derive(Debug)]
+pub struct SseKmsSealedKey {
+    pub wrapped: Vec<u8>,
+    pub algorithm: String,
+}
+
 #[derive(Debug)]
 pub struct SealedObjectKey {
     key: Vec<u8>,
@@ -100,8 +121,22 @@ impl SealedObjectKey {
     }
 
     pub fn unseal(&self, kek: String, object_path: &str) -> anyhow::Result<ObjectKey> {
-        let key = STANDARD.decode(&kek)?;
-        let mut mac = Hmac::<Sha256>::new_from_slice(&key).expect("HMAC can take key of any size");
+        self.unseal_with_kek(&STANDARD.decode(&kek)?, object_path)
+    }
+
+    /// Like [`Self::unseal`], but for an object whose key-encryption-key was derived from a
+    /// passphrase (see [`crate::encryption::kdf`]) rather than supplied as a raw key.
+    pub fn unseal_with_passphrase(
+        &self,
+        passphrase: &str,
+        kdf_params: &KdfParams,
+        object_path: &str,
+    ) -> anyhow::Result<ObjectKey> {
+        self.unseal_with_kek(&kdf::derive_kek(passphrase, kdf_params)?, object_path)
+    }
+
+    fn unseal_with_kek(&self, kek: &[u8], object_path: &str) -> anyhow::Result<ObjectKey> {
+        let mut mac = Hmac::<Sha256>::new_from_slice(kek).expect("HMAC can take key of any size");
 
         // Write data to the MAC
         mac.update(self.iv.as_slice()); // iv
@@ -154,4 +189,152 @@ impl ObjectKey {
             domain: domain.to_string(),
         })
     }
+
+    /// Seals this object key to `recipient`'s secp256k1 public key via ECIES, so an object can be
+    /// encrypted "to an account" and only the holder of the matching [`SecretKey`] can recover it
+    /// -- unlike [`Self::seal`], which requires a symmetric key-encryption-key to be distributed to
+    /// the recipient out of band.
+    pub fn seal_to_recipient(&self, recipient: &PublicKey) -> anyhow::Result<EciesSealedObjectKey> {
+        let mut rng = OsRng;
+        let ephemeral_sk = SecretKey::random(&mut rng);
+        let ephemeral_pk = ephemeral_sk.public_key();
+
+        let seed = ecdh_seed(recipient, &ephemeral_sk)?;
+        let (aes_key, mac_key) = concat_kdf(&seed);
+
+        let mut iv = [0u8; 16];
+        rng.fill_bytes(&mut iv);
+
+        let mut ciphertext = self.key.to_vec();
+        let mut cipher = Aes256Ctr::new(&aes_key.into(), &iv.into());
+        cipher.apply_keystream(&mut ciphertext);
+
+        let tag = ecies_tag(&mac_key, &iv, &ciphertext);
+
+        Ok(EciesSealedObjectKey {
+            ephemeral_public_key: ephemeral_pk.serialize_compressed().to_vec(),
+            iv,
+            ciphertext,
+            tag,
+            algorithm: ECIES_ALGORITHM.to_string(),
+        })
+    }
+}
+
+/// The metadata needed to recover an object key that was [`ObjectKey::seal_to_recipient`]ed to a
+/// secp256k1 account: the ephemeral public key generated for the ECIES exchange, the AES-encrypted
+/// object key, and the HMAC tag authenticating them. Unlike [`SealedObjectKey`], unsealing needs
+/// the recipient's [`SecretKey`] rather than a shared key-encryption-key.
+#[derive(Debug)]
+pub struct EciesSealedObjectKey {
+    ephemeral_public_key: Vec<u8>,
+    iv: [u8; 16],
+    ciphertext: Vec<u8>,
+    tag: Vec<u8>,
+    algorithm: String,
+}
+
+impl EciesSealedObjectKey {
+    pub fn new(
+        ephemeral_public_key: String,
+        iv: String,
+        ciphertext: String,
+        tag: String,
+        algorithm: String,
+    ) -> anyhow::Result<EciesSealedObjectKey> {
+        Ok(EciesSealedObjectKey {
+            ephemeral_public_key: STANDARD.decode(ephemeral_public_key)?,
+            iv: STANDARD.decode(iv)?.as_slice().try_into()?,
+            ciphertext: STANDARD.decode(ciphertext)?,
+            tag: STANDARD.decode(tag)?,
+            algorithm,
+        })
+    }
+
+    pub fn algorithm(&self) -> String {
+        self.algorithm.clone()
+    }
+
+    pub fn ephemeral_public_key_as_string(&self) -> String {
+        STANDARD.encode(&self.ephemeral_public_key)
+    }
+
+    pub fn iv_as_string(&self) -> String {
+        STANDARD.encode(self.iv)
+    }
+
+    pub fn ciphertext_as_string(&self) -> String {
+        STANDARD.encode(&self.ciphertext)
+    }
+
+    pub fn tag_as_string(&self) -> String {
+        STANDARD.encode(&self.tag)
+    }
+
+    /// Recovers the object key, given the `secret` half of the keypair it was sealed to. Fails if
+    /// `secret` doesn't match the public key used to seal it, or if the sealed key was corrupted.
+    pub fn unseal(&self, secret: &SecretKey) -> anyhow::Result<ObjectKey> {
+        let ephemeral_pk = PublicKey::parse_slice(&self.ephemeral_public_key, None)
+            .map_err(|err| anyhow!("invalid ECIES ephemeral public key: {err:?}"))?;
+
+        let seed = ecdh_seed(&ephemeral_pk, secret)?;
+        let (aes_key, mac_key) = concat_kdf(&seed);
+
+        let expected_tag = ecies_tag(&mac_key, &self.iv, &self.ciphertext);
+        if expected_tag != self.tag {
+            return Err(anyhow!(
+                "ECIES authentication failed: wrong secret key or corrupted sealed key"
+            ));
+        }
+
+        let mut key = self.ciphertext.clone();
+        let mut cipher = Aes256Ctr::new(&aes_key.into(), &self.iv.into());
+        cipher.apply_keystream(&mut key);
+
+        Ok(ObjectKey {
+            key: key.as_slice().try_into()?,
+        })
+    }
+}
+
+/// Computes the ECDH shared secret between `pubkey` and `secret` (i.e. `secret * pubkey`),
+/// returning the X coordinate of the resulting point, the seed [`concat_kdf`] expands into the
+/// AES and HMAC keys.
+fn ecdh_seed(pubkey: &PublicKey, secret: &SecretKey) -> anyhow::Result<[u8; 32]> {
+    let mut shared_point = *pubkey;
+    shared_point
+        .tweak_mul_assign(secret)
+        .map_err(|err| anyhow!("ECDH failed: {err:?}"))?;
+
+    // Uncompressed serialization is `0x04 || X || Y`; the leading byte is skipped and only the
+    // 32-byte X coordinate is used as the KDF seed.
+    shared_point.serialize()[1..33].try_into().map_err(|_| anyhow!("unexpected point encoding"))
+}
+
+/// NIST SP 800-56A concat KDF: repeatedly hashes a big-endian counter (starting at 1) together
+/// with `seed`, concatenating the digests until there's enough output for a 32-byte AES-256 key
+/// and a 32-byte HMAC-SHA256 key.
+fn concat_kdf(seed: &[u8]) -> ([u8; 32], [u8; 32]) {
+    let mut output = Vec::with_capacity(64);
+    let mut counter: u32 = 1;
+    while output.len() < 64 {
+        let mut hasher = Sha256::new();
+        hasher.update(counter.to_be_bytes());
+        hasher.update(seed);
+        output.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+
+    let aes_key = output[0..32].try_into().expect("32 bytes sliced from a >=64-byte buffer");
+    let mac_key = output[32..64].try_into().expect("32 bytes sliced from a >=64-byte buffer");
+    (aes_key, mac_key)
+}
+
+/// `HMAC-SHA256(mac_key, iv || ciphertext)`, the tag [`ObjectKey::seal_to_recipient`] appends and
+/// [`EciesSealedObjectKey::unseal`] verifies.
+fn ecies_tag(mac_key: &[u8; 32], iv: &[u8; 16], ciphertext: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(mac_key).expect("HMAC can take key of any size");
+    mac.update(iv);
+    mac.update(ciphertext);
+    mac.finalize().into_bytes().to_vec()
 }
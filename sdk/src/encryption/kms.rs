@@ -0,0 +1,208 @@
+use crate::encryption::key::{generate_iv, generate_object_key, SealedObjectKey};
+use anyhow::anyhow;
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use recall_provider::json_rpc::Url;
+use serde::{Deserialize, Serialize};
+
+/// A data key freshly issued by a [`KeyManagementService`]: the plaintext key to use as an
+/// object's content encryption key, and its KMS-wrapped form to store in the object's metadata so
+/// the key can be recovered on read without ever writing the plaintext to disk.
+pub struct DataKey {
+    pub plaintext: [u8; 32],
+    pub wrapped: Vec<u8>,
+}
+
+/// Pluggable external key-management backend for SSE-KMS, mirroring the [`Signer`] trait's
+/// pluggable design: object encryption keys are generated and unwrapped by whatever KMS a
+/// deployment configures, rather than derived from a key the caller supplies directly as in
+/// SSE-C.
+///
+/// [`Signer`]: recall_signer::Signer
+#[async_trait]
+pub trait KeyManagementService: Send + Sync {
+    /// Requests a fresh data key for `object_path` from the KMS.
+    async fn generate_data_key(&self, object_path: &str) -> anyhow::Result<DataKey>;
+
+    /// Unwraps a data key previously issued by [`generate_data_key`](Self::generate_data_key) for
+    /// `object_path`.
+    async fn unwrap_data_key(&self, wrapped: &[u8], object_path: &str)
+        -> anyhow::Result<[u8; 32]>;
+}
+
+#[derive(Serialize)]
+struct GenerateDataKeyRequest<'a> {
+    key_id: &'a str,
+    object_path: &'a str,
+}
+
+#[derive(Deserialize)]
+struct GenerateDataKeyResponse {
+    plaintext: String,
+    wrapped: String,
+}
+
+#[derive(Serialize)]
+struct UnwrapDataKeyRequest<'a> {
+    wrapped: String,
+    object_path: &'a str,
+}
+
+#[derive(Deserialize)]
+struct UnwrapDataKeyResponse {
+    plaintext: String,
+}
+
+/// A [`KeyManagementService`] backed by a remote KMS reachable over HTTP, the way a deployment's
+/// secret-store/private-transactions key server would be. `key_id` selects which key the KMS
+/// should use to wrap a new data key; it's only needed for [`generate_data_key`], since an
+/// unwrap request identifies the key from the wrapped data key itself.
+///
+/// [`generate_data_key`]: KeyManagementService::generate_data_key
+pub struct HttpKeyManagementService {
+    client: reqwest::Client,
+    url: Url,
+    key_id: Option<String>,
+}
+
+impl HttpKeyManagementService {
+    /// Creates a client for the KMS at `url`. `key_id` is required to [`generate_data_key`], but
+    /// may be omitted if the client will only ever [`unwrap_data_key`].
+    ///
+    /// [`generate_data_key`]: KeyManagementService::generate_data_key
+    /// [`unwrap_data_key`]: KeyManagementService::unwrap_data_key
+    pub fn new(url: Url, key_id: Option<String>) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            url,
+            key_id,
+        }
+    }
+}
+
+#[async_trait]
+impl KeyManagementService for HttpKeyManagementService {
+    async fn generate_data_key(&self, object_path: &str) -> anyhow::Result<DataKey> {
+        let key_id = self
+            .key_id
+            .as_deref()
+            .ok_or_else(|| anyhow!("a key ID is required to generate a data key"))?;
+
+        let url = format!("{}v1/data-keys", self.url);
+        let response = self
+            .client
+            .post(url)
+            .json(&GenerateDataKeyRequest {
+                key_id,
+                object_path,
+            })
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "failed to generate data key: {}",
+                response.text().await?
+            ));
+        }
+
+        let body: GenerateDataKeyResponse = response.json().await?;
+        let plaintext = STANDARD.decode(body.plaintext)?;
+        let plaintext: [u8; 32] = plaintext
+            .try_into()
+            .map_err(|_| anyhow!("KMS returned a data key that is not 32 bytes"))?;
+
+        Ok(DataKey {
+            plaintext,
+            wrapped: STANDARD.decode(body.wrapped)?,
+        })
+    }
+
+    async fn unwrap_data_key(
+        &self,
+        wrapped: &[u8],
+        object_path: &str,
+    ) -> anyhow::Result<[u8; 32]> {
+        let url = format!("{}v1/data-keys/unwrap", self.url);
+        let response = self
+            .client
+            .post(url)
+            .json(&UnwrapDataKeyRequest {
+                wrapped: STANDARD.encode(wrapped),
+                object_path,
+            })
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(anyhow!(
+                "failed to unwrap data key: {}",
+                response.text().await?
+            ));
+        }
+
+        let body: UnwrapDataKeyResponse = response.json().await?;
+        let plaintext = STANDARD.decode(body.plaintext)?;
+        plaintext
+            .try_into()
+            .map_err(|_| anyhow!("KMS returned a data key that is not 32 bytes"))
+    }
+}
+
+/// Algorithm/domain label a [`LocalKeyManagementService`] seals its wrapped data keys under.
+const LOCAL_ALGORITHM: &str = "SSE-KMS-Local";
+
+/// A [`KeyManagementService`] that wraps/unwraps data keys itself under a local master key,
+/// rather than calling out to an external KMS -- useful for local development and tests, where
+/// standing up an HTTP KMS endpoint is unnecessary. Wrapping reuses the same sealing scheme as
+/// SSE-C (see [`crate::encryption::key::ObjectKey::seal`]): the data key is sealed under the
+/// master key with `object_path` bound into the HMAC context, so a wrapped key can't be replayed
+/// to unwrap a different object's key.
+pub struct LocalKeyManagementService {
+    master_key: [u8; 32],
+}
+
+impl LocalKeyManagementService {
+    /// Uses `master_key` to wrap and unwrap data keys. Losing it makes every data key it ever
+    /// wrapped permanently unrecoverable, same as losing an external KMS's key material would.
+    pub fn new(master_key: [u8; 32]) -> Self {
+        Self { master_key }
+    }
+}
+
+#[async_trait]
+impl KeyManagementService for LocalKeyManagementService {
+    async fn generate_data_key(&self, object_path: &str) -> anyhow::Result<DataKey> {
+        let object_key = generate_object_key(&self.master_key, None)?;
+        let iv = generate_iv(None);
+        let sealed = object_key.seal(&self.master_key, &iv, LOCAL_ALGORITHM, object_path)?;
+
+        let mut wrapped = iv.to_vec();
+        wrapped.extend_from_slice(&sealed.key());
+
+        Ok(DataKey {
+            plaintext: object_key.key,
+            wrapped,
+        })
+    }
+
+    async fn unwrap_data_key(
+        &self,
+        wrapped: &[u8],
+        object_path: &str,
+    ) -> anyhow::Result<[u8; 32]> {
+        if wrapped.len() <= 32 {
+            return Err(anyhow!("wrapped data key is too short"));
+        }
+        let (iv, sealed_key) = wrapped.split_at(32);
+
+        let sealed_object_key = SealedObjectKey::new(
+            STANDARD.encode(sealed_key),
+            STANDARD.encode(iv),
+            "DAREv1-HMAC-SHA256".to_string(),
+            LOCAL_ALGORITHM.to_string(),
+        )?;
+
+        let object_key =
+            sealed_object_key.unseal(STANDARD.encode(self.master_key), object_path)?;
+        Ok(object_key.key)
+    }
+}
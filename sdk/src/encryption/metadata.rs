@@ -6,3 +6,28 @@ pub const META_IV: &str = "sse-iv";
 pub const META_SEALED_KEY_SSE_C: &str = "sse-sealed-key-ssec";
 /// META_SEALED_KEY_SSE_C is the sealed object encryption key in case of SSE-KMS.
 pub const META_SEALED_KEY_SSE_KMS: &str = "sse-sealed-key-kms";
+/// META_KDF_ALGORITHM is the key-derivation algorithm used to derive an SSE-C
+/// key-encryption-key from a passphrase (e.g. "argon2id"). Only present when the object was
+/// encrypted with a passphrase rather than a raw base64 key.
+pub const META_KDF_ALGORITHM: &str = "sse-kdf-algorithm";
+/// META_KDF_SALT is the base64-encoded salt used in passphrase key derivation.
+pub const META_KDF_SALT: &str = "sse-kdf-salt";
+/// META_KDF_MEMORY is the Argon2 memory cost, in KiB, used in passphrase key derivation.
+pub const META_KDF_MEMORY: &str = "sse-kdf-memory";
+/// META_KDF_ITERATIONS is the Argon2 iteration count used in passphrase key derivation.
+pub const META_KDF_ITERATIONS: &str = "sse-kdf-iterations";
+/// META_KDF_PARALLELISM is the Argon2 parallelism factor used in passphrase key derivation.
+pub const META_KDF_PARALLELISM: &str = "sse-kdf-parallelism";
+/// META_SEALED_KEY_ECIES is the AES-encrypted object key in case of account-bound ECIES
+/// encryption.
+pub const META_SEALED_KEY_ECIES: &str = "sse-sealed-key-ecies";
+/// META_ECIES_EPHEMERAL_KEY is the base64-encoded compressed ephemeral public key generated for
+/// an ECIES exchange.
+pub const META_ECIES_EPHEMERAL_KEY: &str = "sse-ecies-ephemeral-key";
+/// META_ECIES_TAG is the base64-encoded HMAC-SHA256 tag authenticating an ECIES-sealed key.
+pub const META_ECIES_TAG: &str = "sse-ecies-tag";
+/// META_CIPHER_SUITE is the DARE cipher suite the object's content was encrypted with (e.g.
+/// "AES256GCM" or "ChaCha20Poly1305"). Informational only: each DARE package header already
+/// identifies its own suite, so decryption doesn't depend on this field, but it lets callers
+/// introspect an object's cipher suite without reading any of its content.
+pub const META_CIPHER_SUITE: &str = "sse-cipher-suite";
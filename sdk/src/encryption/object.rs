@@ -1,8 +1,13 @@
-use crate::encryption::key::SealedObjectKey;
+use crate::encryption::kdf::KdfParams;
+use crate::encryption::key::{EciesSealedObjectKey, SealedObjectKey, SseKmsSealedKey};
 use crate::encryption::metadata::{
-    META_ALGORITHM, META_IV, META_SEALED_KEY_SSE_C, META_SEALED_KEY_SSE_KMS,
+    META_ALGORITHM, META_CIPHER_SUITE, META_ECIES_EPHEMERAL_KEY, META_ECIES_TAG, META_IV,
+    META_KDF_ITERATIONS, META_KDF_MEMORY, META_KDF_PARALLELISM, META_KDF_SALT,
+    META_SEALED_KEY_ECIES, META_SEALED_KEY_SSE_C, META_SEALED_KEY_SSE_KMS,
 };
+use crate::encryption::parse_cipher_suite_label;
 use anyhow::anyhow;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use dare::{HEADER_SIZE, MAX_PAYLOAD_SIZE, TAG_SIZE};
 use fendermint_actor_bucket::Object;
 
@@ -10,14 +15,27 @@ pub trait EncryptedObjectExt {
     fn is_encrypted(&self) -> bool;
     fn is_sse_c(&self) -> bool;
     fn is_sse_kms(&self) -> bool;
+    fn is_ecies(&self) -> bool;
     fn sealed_object_key(&self) -> anyhow::Result<SealedObjectKey>;
+    fn sse_kms_sealed_key(&self) -> anyhow::Result<SseKmsSealedKey>;
+    fn ecies_sealed_key(&self) -> anyhow::Result<EciesSealedObjectKey>;
+    /// Returns the Argon2 parameters this SSE-C object's key-encryption-key was derived from, if
+    /// it was encrypted with a passphrase rather than a raw key. `None` for objects encrypted
+    /// with a raw key, or objects that aren't SSE-C at all.
+    fn kdf_params(&self) -> anyhow::Result<Option<KdfParams>>;
+
+    /// The DARE cipher suite this object's content was encrypted with, if recorded (objects
+    /// written before [`META_CIPHER_SUITE`] was introduced won't have it). Informational only --
+    /// every DARE package header already identifies its own suite, so decryption doesn't depend
+    /// on this.
+    fn content_cipher_suite(&self) -> anyhow::Result<Option<dare::CipherSuite>>;
 
     fn size_decrypted(&self) -> u64;
 }
 
 impl EncryptedObjectExt for Object {
     fn is_encrypted(&self) -> bool {
-        self.is_sse_c() || self.is_sse_kms()
+        self.is_sse_c() || self.is_sse_kms() || self.is_ecies()
     }
 
     fn is_sse_c(&self) -> bool {
@@ -30,6 +48,11 @@ impl EncryptedObjectExt for Object {
             .contains_key::<String>(&META_SEALED_KEY_SSE_KMS.into())
     }
 
+    fn is_ecies(&self) -> bool {
+        self.metadata
+            .contains_key::<String>(&META_SEALED_KEY_ECIES.into())
+    }
+
     fn sealed_object_key(&self) -> anyhow::Result<SealedObjectKey> {
         if !self.is_encrypted() {
             return Err(anyhow!(
@@ -37,14 +60,15 @@ impl EncryptedObjectExt for Object {
             ));
         }
 
-        let (key, domain) = if self.is_sse_c() {
-            (
-                self.metadata.get(META_SEALED_KEY_SSE_C).unwrap().to_owned(),
-                "SSE-C".to_string(),
-            )
-        } else {
-            return Err(anyhow!("no other method is implemented"));
-        };
+        if !self.is_sse_c() {
+            return Err(anyhow!(
+                "sealed_object_key only supports SSE-C objects; use sse_kms_sealed_key for \
+                 SSE-KMS or ecies_sealed_key for ECIES"
+            ));
+        }
+
+        let key = self.metadata.get(META_SEALED_KEY_SSE_C).unwrap().to_owned();
+        let domain = "SSE-C".to_string();
 
         let Some(iv) = self.metadata.get(META_IV) else {
             return Err(anyhow!("encrypted objects should have META_IV metadata"));
@@ -58,6 +82,110 @@ impl EncryptedObjectExt for Object {
         SealedObjectKey::new(key, iv.to_owned(), algorithm.to_owned(), domain)
     }
 
+    fn sse_kms_sealed_key(&self) -> anyhow::Result<SseKmsSealedKey> {
+        if !self.is_sse_kms() {
+            return Err(anyhow!(
+                "you called sse_kms_sealed_key on an object that is not SSE-KMS encrypted"
+            ));
+        }
+
+        let wrapped = self
+            .metadata
+            .get(META_SEALED_KEY_SSE_KMS)
+            .unwrap()
+            .to_owned();
+        let wrapped = STANDARD.decode(wrapped)?;
+
+        let Some(algorithm) = self.metadata.get(META_ALGORITHM) else {
+            return Err(anyhow!(
+                "encrypted objects should have META_ALGORITHM metadata"
+            ));
+        };
+
+        Ok(SseKmsSealedKey {
+            wrapped,
+            algorithm: algorithm.to_owned(),
+        })
+    }
+
+    fn ecies_sealed_key(&self) -> anyhow::Result<EciesSealedObjectKey> {
+        if !self.is_ecies() {
+            return Err(anyhow!(
+                "you called ecies_sealed_key on an object that is not ECIES encrypted"
+            ));
+        }
+
+        let ciphertext = self
+            .metadata
+            .get(META_SEALED_KEY_ECIES)
+            .unwrap()
+            .to_owned();
+
+        let Some(ephemeral_public_key) = self.metadata.get(META_ECIES_EPHEMERAL_KEY) else {
+            return Err(anyhow!(
+                "ECIES-encrypted objects should have META_ECIES_EPHEMERAL_KEY metadata"
+            ));
+        };
+
+        let Some(tag) = self.metadata.get(META_ECIES_TAG) else {
+            return Err(anyhow!(
+                "ECIES-encrypted objects should have META_ECIES_TAG metadata"
+            ));
+        };
+
+        let Some(iv) = self.metadata.get(META_IV) else {
+            return Err(anyhow!("encrypted objects should have META_IV metadata"));
+        };
+
+        let Some(algorithm) = self.metadata.get(META_ALGORITHM) else {
+            return Err(anyhow!(
+                "encrypted objects should have META_ALGORITHM metadata"
+            ));
+        };
+
+        EciesSealedObjectKey::new(
+            ephemeral_public_key.to_owned(),
+            iv.to_owned(),
+            ciphertext,
+            tag.to_owned(),
+            algorithm.to_owned(),
+        )
+    }
+
+    fn kdf_params(&self) -> anyhow::Result<Option<KdfParams>> {
+        if !self.is_sse_c() {
+            return Ok(None);
+        }
+
+        let Some(salt) = self.metadata.get(META_KDF_SALT) else {
+            return Ok(None);
+        };
+
+        let salt = STANDARD.decode(salt)?.as_slice()[0..16].try_into()?;
+
+        let parse_u32 = |meta_key: &str| -> anyhow::Result<u32> {
+            self.metadata
+                .get(meta_key)
+                .ok_or_else(|| anyhow!("encrypted objects should have {meta_key} metadata"))?
+                .parse()
+                .map_err(|err| anyhow!("invalid {meta_key} metadata: {err}"))
+        };
+
+        Ok(Some(KdfParams {
+            salt,
+            memory_kib: parse_u32(META_KDF_MEMORY)?,
+            iterations: parse_u32(META_KDF_ITERATIONS)?,
+            parallelism: parse_u32(META_KDF_PARALLELISM)?,
+        }))
+    }
+
+    fn content_cipher_suite(&self) -> anyhow::Result<Option<dare::CipherSuite>> {
+        match self.metadata.get(META_CIPHER_SUITE) {
+            Some(label) => Ok(Some(parse_cipher_suite_label(label)?)),
+            None => Ok(None),
+        }
+    }
+
     fn size_decrypted(&self) -> u64 {
         if !self.is_encrypted() {
             return self.size;
@@ -71,3 +199,15 @@ impl EncryptedObjectExt for Object {
         (content_length - (n_package * (HEADER_SIZE + TAG_SIZE))) as u64
     }
 }
+
+/// Computes the ciphertext size of a DARE-encrypted stream given the plaintext size, i.e. the
+/// inverse of [`EncryptedObjectExt::size_decrypted`]. Callers need this upfront to upload an
+/// encrypted object, since the content length sent to the store is the ciphertext length.
+pub fn size_encrypted(plaintext_size: u64) -> u64 {
+    let package_size = MAX_PAYLOAD_SIZE;
+    let content_length = plaintext_size as usize;
+
+    let n_package = (content_length + package_size - 1) / package_size;
+
+    (content_length + n_package * (HEADER_SIZE + TAG_SIZE)) as u64
+}
@@ -1,22 +1,45 @@
+use crate::encryption::decryptor::StreamDecryptReader;
 use crate::encryption::encryptor::EncryptReader;
-use crate::encryption::key::{generate_iv, generate_object_key};
-use crate::encryption::metadata::{META_ALGORITHM, META_IV, META_SEALED_KEY_SSE_C};
+use crate::encryption::kdf::{self, KdfParams, ARGON2ID};
+use crate::encryption::key::{generate_iv, generate_object_key, SealedObjectKey};
+use crate::encryption::metadata::{
+    META_ALGORITHM, META_CIPHER_SUITE, META_IV, META_KDF_ALGORITHM, META_KDF_ITERATIONS,
+    META_KDF_MEMORY, META_KDF_PARALLELISM, META_KDF_SALT, META_SEALED_KEY_SSE_C,
+};
+use crate::encryption::cipher_suite_label;
+use anyhow::anyhow;
 use base64::{engine::general_purpose::STANDARD, Engine as _};
 use std::collections::HashMap;
 use tokio::io::AsyncRead;
 
+/// Where an SSE-C key-encryption-key comes from.
+pub enum SseCKey<'a> {
+    /// A raw 32-byte key-encryption-key, base64-encoded.
+    Key(&'a str),
+    /// A human passphrase. The key-encryption-key is derived via Argon2id with a fresh random
+    /// salt, and the salt plus KDF parameters are stored in the object's metadata so `get` can
+    /// repeat the derivation given the same passphrase.
+    Passphrase(&'a str),
+}
+
 pub fn encrypt_reader<R: AsyncRead>(
     reader: R,
-    key: &str,
+    key: SseCKey,
     object_path: &str,
+    cipher_suite: dare::CipherSuite,
 ) -> anyhow::Result<(EncryptReader<R>, HashMap<String, String>)> {
-    let kek = STANDARD.decode(key)?;
+    let (kek, kdf_params) = match key {
+        SseCKey::Key(key) => (STANDARD.decode(key)?, None),
+        SseCKey::Passphrase(passphrase) => {
+            let params = KdfParams::default();
+            let kek = kdf::derive_kek(passphrase, &params)?.to_vec();
+            (kek, Some(params))
+        }
+    };
+
     let object_encryption_key = generate_object_key(&kek, None)?;
 
-    let encryptor = dare::encryptor::DAREEncryptor::new(
-        object_encryption_key.key,
-        dare::CipherSuite::AES256GCM,
-    )?;
+    let encryptor = dare::encryptor::DAREEncryptor::new(object_encryption_key.key, cipher_suite)?;
 
     let reader = EncryptReader::new(reader, encryptor);
 
@@ -31,6 +54,79 @@ pub fn encrypt_reader<R: AsyncRead>(
     metadata.insert(META_SEALED_KEY_SSE_C.into(), sealed_key_str);
     metadata.insert(META_IV.into(), iv);
     metadata.insert(META_ALGORITHM.into(), algorithm);
+    metadata.insert(
+        META_CIPHER_SUITE.into(),
+        cipher_suite_label(cipher_suite).to_string(),
+    );
+
+    if let Some(params) = kdf_params {
+        metadata.insert(META_KDF_ALGORITHM.into(), ARGON2ID.to_string());
+        metadata.insert(META_KDF_SALT.into(), STANDARD.encode(params.salt));
+        metadata.insert(META_KDF_MEMORY.into(), params.memory_kib.to_string());
+        metadata.insert(META_KDF_ITERATIONS.into(), params.iterations.to_string());
+        metadata.insert(META_KDF_PARALLELISM.into(), params.parallelism.to_string());
+    }
 
     Ok((reader, metadata))
 }
+
+/// The symmetric counterpart to [`encrypt_reader`]: reads the `META_SEALED_KEY_SSE_C`/`META_IV`/
+/// `META_ALGORITHM` (and, for a passphrase-derived key, `META_KDF_*`) fields out of `metadata`,
+/// unseals the object's content encryption key with `key` -- verifying it was sealed for this
+/// exact `object_path`, so a key recovered for one object can't decrypt another -- and returns a
+/// streaming DARE-decrypting reader over `reader`.
+pub fn decrypt_reader<R: AsyncRead>(
+    reader: R,
+    key: SseCKey,
+    object_path: &str,
+    metadata: &HashMap<String, String>,
+) -> anyhow::Result<StreamDecryptReader<R>> {
+    let sealed_key = metadata
+        .get(META_SEALED_KEY_SSE_C)
+        .ok_or_else(|| anyhow!("object metadata is missing {META_SEALED_KEY_SSE_C}"))?
+        .to_owned();
+    let iv = metadata
+        .get(META_IV)
+        .ok_or_else(|| anyhow!("object metadata is missing {META_IV}"))?
+        .to_owned();
+    let algorithm = metadata
+        .get(META_ALGORITHM)
+        .ok_or_else(|| anyhow!("object metadata is missing {META_ALGORITHM}"))?
+        .to_owned();
+    let sealed_object_key = SealedObjectKey::new(sealed_key, iv, algorithm, "SSE-C".to_string())?;
+
+    let object_key = match key {
+        SseCKey::Key(key) => sealed_object_key.unseal(key.to_string(), object_path)?,
+        SseCKey::Passphrase(passphrase) => {
+            let kdf_params = parse_kdf_params(metadata)?
+                .ok_or_else(|| anyhow!("object metadata is missing its KDF parameters"))?;
+            sealed_object_key.unseal_with_passphrase(passphrase, &kdf_params, object_path)?
+        }
+    };
+
+    Ok(StreamDecryptReader::new(reader, object_key.key))
+}
+
+/// Parses the `META_KDF_*` fields [`encrypt_reader`] stores for a passphrase-derived key back
+/// into a [`KdfParams`], or `None` if the object wasn't encrypted with a passphrase.
+fn parse_kdf_params(metadata: &HashMap<String, String>) -> anyhow::Result<Option<KdfParams>> {
+    let Some(salt) = metadata.get(META_KDF_SALT) else {
+        return Ok(None);
+    };
+    let salt = STANDARD.decode(salt)?.as_slice()[0..16].try_into()?;
+
+    let parse_u32 = |meta_key: &str| -> anyhow::Result<u32> {
+        metadata
+            .get(meta_key)
+            .ok_or_else(|| anyhow!("object metadata is missing {meta_key}"))?
+            .parse()
+            .map_err(|err| anyhow!("invalid {meta_key} metadata: {err}"))
+    };
+
+    Ok(Some(KdfParams {
+        salt,
+        memory_kib: parse_u32(META_KDF_MEMORY)?,
+        iterations: parse_u32(META_KDF_ITERATIONS)?,
+        parallelism: parse_u32(META_KDF_PARALLELISM)?,
+    }))
+}
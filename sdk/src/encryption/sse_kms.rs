@@ -0,0 +1,54 @@
+use crate::encryption::cipher_suite_label;
+use crate::encryption::encryptor::EncryptReader;
+use crate::encryption::key::generate_iv;
+use crate::encryption::kms::KeyManagementService;
+use crate::encryption::metadata::{
+    META_ALGORITHM, META_CIPHER_SUITE, META_IV, META_SEALED_KEY_SSE_KMS,
+};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use std::collections::HashMap;
+use tokio::io::AsyncRead;
+
+/// Algorithm label stored in [`META_ALGORITHM`] for SSE-KMS objects.
+const ALGORITHM: &str = "SSE-KMS";
+
+/// Encrypts `reader` for SSE-KMS: requests a fresh data key from `kms` and uses it directly as
+/// the object's content encryption key (envelope encryption — the plaintext key never leaves this
+/// process), then records the KMS-wrapped key so it can be recovered on read.
+pub async fn encrypt_reader<R: AsyncRead>(
+    reader: R,
+    kms: &impl KeyManagementService,
+    object_path: &str,
+    cipher_suite: dare::CipherSuite,
+) -> anyhow::Result<(EncryptReader<R>, HashMap<String, String>)> {
+    let data_key = kms.generate_data_key(object_path).await?;
+
+    let encryptor = dare::encryptor::DAREEncryptor::new(data_key.plaintext, cipher_suite)?;
+    let reader = EncryptReader::new(reader, encryptor);
+
+    let iv = generate_iv(None);
+
+    let mut metadata = HashMap::new();
+    metadata.insert(
+        META_SEALED_KEY_SSE_KMS.into(),
+        STANDARD.encode(&data_key.wrapped),
+    );
+    metadata.insert(META_IV.into(), STANDARD.encode(iv));
+    metadata.insert(META_ALGORITHM.into(), ALGORITHM.to_string());
+    metadata.insert(
+        META_CIPHER_SUITE.into(),
+        cipher_suite_label(cipher_suite).to_string(),
+    );
+
+    Ok((reader, metadata))
+}
+
+/// Recovers the plaintext content key for an SSE-KMS object by asking `kms` to unwrap the key
+/// stored in its metadata.
+pub async fn decrypt_key(
+    kms: &impl KeyManagementService,
+    sealed_key: &crate::encryption::key::SseKmsSealedKey,
+    object_path: &str,
+) -> anyhow::Result<[u8; 32]> {
+    kms.unwrap_data_key(&sealed_key.wrapped, object_path).await
+}
@@ -0,0 +1,111 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! A [`JsonRpcClient`] over several EVM RPC endpoints, so [`EVMSubnet`](crate::ipc::subnet::EVMSubnet)
+//! can carry a redundant `provider_http` list instead of a single URL that breaks everything built
+//! on it when it goes dark. Modeled on [`recall_provider::quorum::QuorumClient`]'s `Failover`
+//! mode: requests are tried against endpoints in order, starting from whichever one last
+//! succeeded, advancing to the next on error (which, since each member's `reqwest::Client` carries
+//! the configured timeout, also covers a hung endpoint), and an aggregated error is only returned
+//! once every endpoint has failed.
+
+use std::fmt::{self, Debug};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use ethers::providers::{Http, JsonRpcClient, JsonRpcError, RpcError};
+use serde::{de::DeserializeOwned, Serialize};
+
+/// A [`JsonRpcClient`] backed by an ordered list of [`Http`] endpoints. Remembers the index of
+/// the last endpoint that answered successfully and starts there on the next call, so a healthy
+/// subnet settles onto a single endpoint instead of re-probing dead ones every request.
+#[derive(Debug)]
+pub struct FailoverHttp {
+    members: Vec<Http>,
+    last_good: AtomicUsize,
+}
+
+impl FailoverHttp {
+    /// Builds a client over `members`, tried in list order starting from `members[0]`.
+    pub fn new(members: Vec<Http>) -> anyhow::Result<Self> {
+        if members.is_empty() {
+            return Err(anyhow::anyhow!("FailoverHttp needs at least one endpoint"));
+        }
+        Ok(FailoverHttp {
+            members,
+            last_good: AtomicUsize::new(0),
+        })
+    }
+}
+
+#[async_trait]
+impl JsonRpcClient for FailoverHttp {
+    type Error = FailoverError;
+
+    async fn request<T, R>(&self, method: &str, params: T) -> Result<R, Self::Error>
+    where
+        T: Debug + Serialize + Send + Sync,
+        R: DeserializeOwned,
+    {
+        // `Http::request`'s `params` isn't required to be `Clone`, so round-trip it through
+        // `serde_json::Value` once up front and reuse that for every endpoint tried, the same
+        // snapshot-and-reconstruct approach `QuorumClient` uses for non-`Clone` requests.
+        let params = serde_json::to_value(params).map_err(FailoverError::Serialize)?;
+
+        let start = self.last_good.load(Ordering::Relaxed);
+        let mut errors = Vec::with_capacity(self.members.len());
+        for offset in 0..self.members.len() {
+            let idx = (start + offset) % self.members.len();
+            match self.members[idx].request(method, &params).await {
+                Ok(res) => {
+                    self.last_good.store(idx, Ordering::Relaxed);
+                    return Ok(res);
+                }
+                Err(err) => errors.push(format!("endpoint {idx}: {err}")),
+            }
+        }
+
+        Err(FailoverError::AllFailed(errors))
+    }
+}
+
+/// Error returned by [`FailoverHttp`] once every endpoint it holds has failed.
+#[derive(Debug)]
+pub enum FailoverError {
+    /// `params` couldn't be converted to JSON before it was sent to any endpoint.
+    Serialize(serde_json::Error),
+    /// Every endpoint was tried and none returned a usable response; one message per endpoint,
+    /// in the order they were tried.
+    AllFailed(Vec<String>),
+}
+
+impl fmt::Display for FailoverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FailoverError::Serialize(err) => write!(f, "failed to serialize RPC params: {err}"),
+            FailoverError::AllFailed(errors) => {
+                write!(
+                    f,
+                    "all {} endpoint(s) failed: {}",
+                    errors.len(),
+                    errors.join("; ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FailoverError {}
+
+impl RpcError for FailoverError {
+    fn as_error_response(&self) -> Option<&JsonRpcError> {
+        None
+    }
+
+    fn as_serde_error(&self) -> Option<&serde_json::Error> {
+        match self {
+            FailoverError::Serialize(err) => Some(err),
+            FailoverError::AllFailed(_) => None,
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! An external gas-oracle backend, consulted before falling back to local
+//! `eth_feeHistory`-based estimation in [`crate::ipc::manager::EvmManager`]'s send paths.
+//! Many subnet RPC endpoints answer `eth_feeHistory` with flat or empty reward percentiles,
+//! so operators on those subnets need a way to price gas from an external service instead.
+
+use async_trait::async_trait;
+use ethers::types::U256;
+use serde::Deserialize;
+
+/// Desired speed tier when querying a multi-tier gas API.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GasSpeed {
+    Safe,
+    #[default]
+    Propose,
+    Fast,
+}
+
+/// Configuration for an external gas-oracle backend.
+#[derive(Debug, Clone)]
+pub struct GasOracleConfig {
+    /// Base URL of the gas API, e.g. `https://gas-api.example.com/gas-oracle`.
+    pub url: reqwest::Url,
+    /// Optional API key, sent as a `?apikey=` query parameter.
+    pub api_key: Option<String>,
+    /// Desired speed tier to select from the oracle's response.
+    pub speed: GasSpeed,
+}
+
+/// A priority fee and fee cap, in wei, suitable for an EIP-1559 transaction.
+pub type GasEstimate = (U256, U256);
+
+/// A pluggable source of gas prices, external to the subnet's own `eth_feeHistory`.
+#[async_trait]
+pub trait GasOracle: Send + Sync {
+    /// Fetch a `(max_priority_fee, max_fee_cap)` pair, in wei.
+    async fn fetch(&self) -> anyhow::Result<GasEstimate>;
+}
+
+/// A [`GasOracle`] backed by an HTTP API returning SafeGasPrice/ProposeGasPrice/FastGasPrice
+/// tiers (the shape used by, e.g., Etherscan-style gas trackers), with prices expressed in gwei.
+pub struct HttpGasOracle {
+    config: GasOracleConfig,
+    client: reqwest::Client,
+}
+
+#[derive(Debug, Deserialize)]
+struct GasApiResponse {
+    result: GasApiResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct GasApiResult {
+    #[serde(rename = "SafeGasPrice")]
+    safe_gas_price: String,
+    #[serde(rename = "ProposeGasPrice")]
+    propose_gas_price: String,
+    #[serde(rename = "FastGasPrice")]
+    fast_gas_price: String,
+    #[serde(rename = "suggestBaseFee")]
+    suggest_base_fee: String,
+}
+
+impl HttpGasOracle {
+    pub fn new(config: GasOracleConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl GasOracle for HttpGasOracle {
+    async fn fetch(&self) -> anyhow::Result<GasEstimate> {
+        let mut url = self.config.url.clone();
+        if let Some(api_key) = &self.config.api_key {
+            url.query_pairs_mut().append_pair("apikey", api_key);
+        }
+        let response = self.client.get(url).send().await?;
+        let body: GasApiResponse = response.json().await?;
+
+        let gwei_str = match self.config.speed {
+            GasSpeed::Safe => &body.result.safe_gas_price,
+            GasSpeed::Propose => &body.result.propose_gas_price,
+            GasSpeed::Fast => &body.result.fast_gas_price,
+        };
+        let priority_fee_gwei: f64 = gwei_str.parse()?;
+        let base_fee_gwei: f64 = body.result.suggest_base_fee.parse()?;
+
+        let priority_fee = gwei_to_wei(priority_fee_gwei);
+        let max_fee = gwei_to_wei(base_fee_gwei) + priority_fee;
+        Ok((priority_fee, max_fee))
+    }
+}
+
+fn gwei_to_wei(gwei: f64) -> U256 {
+    U256::from((gwei * 1_000_000_000.0).round() as u64)
+}
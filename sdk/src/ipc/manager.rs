@@ -2,7 +2,8 @@
 // Copyright 2022-2024 Protocol Labs
 // SPDX-License-Identifier: Apache-2.0, MIT
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex as StdMutex, OnceLock};
 use std::time::Duration;
 
 use anyhow::anyhow;
@@ -11,24 +12,33 @@ use ethers::{
     core::k256::ecdsa::SigningKey,
     middleware::{Middleware, SignerMiddleware},
     prelude::{
-        Authorization, Eip1559TransactionRequest, Http, LocalWallet, Provider, Signer as EthSigner,
-        TransactionReceipt, Wallet, I256, U256,
+        Authorization, Eip1559TransactionRequest, Http, LocalWallet, PendingTransaction, Provider,
+        Signer as EthSigner, TransactionReceipt, Wallet, I256, U256,
     },
-    types::transaction::eip2718::TypedTransaction,
+    types::{
+        transaction::{eip2718::TypedTransaction, eip2930::AccessList},
+        Bytes, H256,
+    },
+    utils::keccak256,
 };
 use ethers_contract::ContractCall;
-use gateway_manager_facet::{FvmAddress, GatewayManagerFacet, SubnetID as GatewaySubnetID};
+use gateway_manager_facet::{
+    FvmAddress, GatewayManagerFacet, GatewayManagerFacetEvents, SubnetID as GatewaySubnetID,
+};
 use hoku_provider::fvm_shared::{address::Address, econ::TokenAmount};
 use ipc_actors_abis::gateway_manager_facet;
 use ipc_api::evm::{fil_to_eth_amount, payload_to_evm_address};
 use num_traits::ToPrimitive;
 use reqwest::{header::HeaderValue, Client};
+use tokio::sync::Mutex as TokioMutex;
 
 use hoku_signer::{Signer, SubnetID};
 
-use crate::ipc::subnet::EVMSubnet;
+use crate::ipc::failover::FailoverHttp;
+use crate::ipc::gas_oracle::{GasOracle, GasOracleConfig};
+use crate::ipc::subnet::{EVMSubnet, EscalatorConfig, FeeEstimatorConfig};
 
-type DefaultSignerMiddleware = SignerMiddleware<Provider<Http>, Wallet<SigningKey>>;
+type DefaultSignerMiddleware = SignerMiddleware<Provider<FailoverHttp>, Wallet<SigningKey>>;
 
 /// Default polling time used by the Ethers provider to check for pending
 /// transactions and events. Default is 7, and for our child subnets we
@@ -43,15 +53,116 @@ const ETH_PROVIDER_POLLING_TIME: Duration = Duration::from_secs(1);
 /// roots (like Calibration and mainnet).
 const TRANSACTION_RECEIPT_RETRIES: usize = 200;
 
-// Generate ABI for `approval` method on ERC20
+/// The canonical deterministic CREATE2 deployment proxy, predeployed at this address on most
+/// EVM chains: https://github.com/Arachnid/deterministic-deployment-proxy. Forwards its calldata
+/// (a 32-byte salt followed by init code) to `CREATE2`, so the same bytecode and salt reproduce
+/// the same contract address on any subnet where the proxy is present.
+const CREATE2_DEPLOYER: &str = "0x4e59b44847b379578588920cA78FbF26c0B4956C";
+
+/// Per-account nonce cache, shared across calls so that `deposit`, `withdraw`,
+/// `approve_gateway`, and `transfer` can be issued back-to-back from the same signer
+/// without each reading the same `pending` nonce from the chain and colliding.
+///
+/// Mirrors the sequence-caching pattern `Wallet` uses for FVM messages (an
+/// `Arc<Mutex<_>>` cache, synced from the chain on first use), applied to EVM nonces.
+#[derive(Clone)]
+struct NonceManager {
+    nonce: Arc<TokioMutex<Option<U256>>>,
+}
+
+/// Registry of [`NonceManager`]s, one per account, so that separate [`get_eth_signer`] calls
+/// for the same address share the same cached nonce.
+static NONCE_MANAGERS: OnceLock<StdMutex<HashMap<ethers::types::Address, NonceManager>>> =
+    OnceLock::new();
+
+/// Caches an `eth_createAccessList` result by `(to, function selector)`, since repeat calls to
+/// the same contract method -- e.g. the `approve`/`deposit` pair `Account::deposit` sends in
+/// sequence -- touch the same storage slots and so get the same access list every time.
+static ACCESS_LIST_CACHE: OnceLock<StdMutex<HashMap<(ethers::types::Address, [u8; 4]), AccessList>>> =
+    OnceLock::new();
+
+/// Returns the cached access list for `(to, selector)`, if one was recorded by a previous call.
+fn cached_access_list(to: ethers::types::Address, selector: [u8; 4]) -> Option<AccessList> {
+    ACCESS_LIST_CACHE
+        .get_or_init(|| StdMutex::new(HashMap::new()))
+        .lock()
+        .unwrap()
+        .get(&(to, selector))
+        .cloned()
+}
+
+/// Records `access_list` as the cached result for `(to, selector)`.
+fn cache_access_list(to: ethers::types::Address, selector: [u8; 4], access_list: AccessList) {
+    ACCESS_LIST_CACHE
+        .get_or_init(|| StdMutex::new(HashMap::new()))
+        .lock()
+        .unwrap()
+        .insert((to, selector), access_list);
+}
+
+impl NonceManager {
+    /// Returns the shared nonce manager for `address`, creating one on first use.
+    fn for_address(address: ethers::types::Address) -> NonceManager {
+        let mut managers = NONCE_MANAGERS
+            .get_or_init(|| StdMutex::new(HashMap::new()))
+            .lock()
+            .unwrap();
+        managers
+            .entry(address)
+            .or_insert_with(|| NonceManager {
+                nonce: Arc::new(TokioMutex::new(None)),
+            })
+            .clone()
+    }
+
+    /// Returns the next nonce to use, initializing the cache from the chain's pending
+    /// transaction count on first use.
+    async fn next(&self, client: &DefaultSignerMiddleware) -> anyhow::Result<U256> {
+        let mut guard = self.nonce.lock().await;
+        let nonce = match *guard {
+            Some(nonce) => nonce,
+            None => {
+                client
+                    .get_transaction_count(
+                        client.address(),
+                        Some(ethers::types::BlockNumber::Pending.into()),
+                    )
+                    .await?
+            }
+        };
+        *guard = Some(nonce + 1);
+        Ok(nonce)
+    }
+
+    /// Forces a resync with the chain's pending nonce, discarding the cached value. Should be
+    /// called after a broadcast fails, since the cached nonce may have drifted from the chain.
+    async fn reset(&self, client: &DefaultSignerMiddleware) -> anyhow::Result<()> {
+        let nonce = client
+            .get_transaction_count(
+                client.address(),
+                Some(ethers::types::BlockNumber::Pending.into()),
+            )
+            .await?;
+        *self.nonce.lock().await = Some(nonce);
+        Ok(())
+    }
+}
+
+// Generate ABI for `approval` method on ERC20, plus the `Approval` event it emits, so a
+// `deposit`'s `approve_gateway` call can be confirmed from the receipt's logs (see
+// `BridgeEvents`) instead of just trusting the call succeeded.
 abigen!(
     IERC20,
-    r#"[{"inputs":[{"internalType":"address","name":"spender","type":"address"},{"internalType":"uint256","name":"amount","type":"uint256"}],"name":"approve","outputs":[{"internalType":"bool","name":"","type":"bool"}],"stateMutability":"nonpayable","type":"function"},{"inputs":[{"internalType":"address","name":"account","type":"address"}],"name":"balanceOf","outputs":[{"internalType":"uint256","name":"","type":"uint256"}],"stateMutability":"view","type":"function"}]"#
+    r#"[{"inputs":[{"internalType":"address","name":"spender","type":"address"},{"internalType":"uint256","name":"amount","type":"uint256"}],"name":"approve","outputs":[{"internalType":"bool","name":"","type":"bool"}],"stateMutability":"nonpayable","type":"function"},{"inputs":[{"internalType":"address","name":"account","type":"address"}],"name":"balanceOf","outputs":[{"internalType":"uint256","name":"","type":"uint256"}],"stateMutability":"view","type":"function"},{"anonymous":false,"inputs":[{"indexed":true,"internalType":"address","name":"owner","type":"address"},{"indexed":true,"internalType":"address","name":"spender","type":"address"},{"indexed":false,"internalType":"uint256","name":"value","type":"uint256"}],"name":"Approval","type":"event"}]"#
 );
 
-/// Returns an Ethereum provider for the given subnet configuration.
-fn get_eth_provider(subnet: &EVMSubnet) -> anyhow::Result<Provider<Http>> {
-    let url = subnet.provider_http.clone();
+/// Returns an Ethereum provider for the given subnet configuration. If `subnet.provider_http`
+/// lists more than one endpoint, requests fail over across them in list order (see
+/// [`FailoverHttp`]) instead of depending on a single RPC node.
+fn get_eth_provider(subnet: &EVMSubnet) -> anyhow::Result<Provider<FailoverHttp>> {
+    if subnet.provider_http.is_empty() {
+        return Err(anyhow!("subnet has no RPC provider endpoints configured"));
+    }
     let auth_token = subnet.auth_token.clone();
 
     let mut client = Client::builder();
@@ -68,7 +179,12 @@ fn get_eth_provider(subnet: &EVMSubnet) -> anyhow::Result<Provider<Http>> {
     }
     let client = client.build()?;
 
-    let provider = Http::new_with_client(url, client);
+    let members = subnet
+        .provider_http
+        .iter()
+        .map(|url| Http::new_with_client(url.clone(), client.clone()))
+        .collect();
+    let provider = FailoverHttp::new(members)?;
     let mut provider = Provider::new(provider);
     provider.set_interval(ETH_PROVIDER_POLLING_TIME);
 
@@ -131,7 +247,7 @@ fn get_supply_source(
 
 /// Get the balance of the supply source (ERC20) for the given subnet (e.g., the parent subnet).
 async fn get_supply_source_balance(
-    provider: Provider<Http>,
+    provider: Provider<FailoverHttp>,
     subnet: EVMSubnet,
     address: Address,
 ) -> anyhow::Result<TokenAmount> {
@@ -148,6 +264,160 @@ async fn get_supply_source_balance(
     Ok(TokenAmount::from_atto(balance.as_u128()))
 }
 
+/// A transaction that has confirmed, and which escalation attempt (0 = the original
+/// broadcast, N = the Nth fee-bumped rebroadcast) actually landed.
+pub struct Confirmation {
+    pub receipt: TransactionReceipt,
+    pub attempt: u32,
+}
+
+/// Gateway and supply-source events decoded directly out of a transaction's
+/// [`TransactionReceipt`], instead of a caller re-parsing `receipt.logs` by hand. A receipt
+/// already carries every log the transaction itself emitted, in order, so this needs no separate
+/// `eth_getLogs` round trip -- only address-based filtering, to set aside logs from unrelated
+/// contracts touched in the same block.
+#[derive(Debug, Clone, Default)]
+pub struct BridgeEvents {
+    /// Gateway-facet events found in the receipt, e.g. the cross-subnet message a deposit
+    /// queues or the release a withdrawal commits.
+    pub gateway: Vec<GatewayManagerFacetEvents>,
+    /// `Approval` events emitted by the supply-source ERC20, if the transaction needed one.
+    pub approvals: Vec<ApprovalFilter>,
+}
+
+impl BridgeEvents {
+    /// Filters `receipt.logs` down to those emitted by `gateway_addr` or `supply_source`, and
+    /// decodes each against the contract it came from.
+    fn from_receipt(
+        receipt: &TransactionReceipt,
+        gateway_addr: ethers::types::Address,
+        supply_source: Option<ethers::types::Address>,
+    ) -> Self {
+        let mut events = BridgeEvents::default();
+        for log in &receipt.logs {
+            if log.address == gateway_addr {
+                if let Ok(event) =
+                    ethers::contract::parse_log::<GatewayManagerFacetEvents>(log.clone())
+                {
+                    events.gateway.push(event);
+                }
+            } else if Some(log.address) == supply_source {
+                if let Ok(event) = ethers::contract::parse_log::<ApprovalFilter>(log.clone()) {
+                    events.approvals.push(event);
+                }
+            }
+        }
+        events
+    }
+}
+
+/// A deposit's confirmed receipt together with the gateway/ERC20 events it emitted, e.g. the
+/// cross-subnet message id needed to track the deposit's arrival in the destination subnet.
+pub struct DepositResult {
+    pub receipt: TransactionReceipt,
+    pub events: BridgeEvents,
+}
+
+/// A withdrawal's confirmed receipt together with the gateway events it emitted.
+pub struct WithdrawResult {
+    pub receipt: TransactionReceipt,
+    pub events: BridgeEvents,
+}
+
+/// A transaction that has been broadcast but not yet confirmed. Returned by the `_pending`
+/// variants of [`EvmManager`]'s send methods so a caller can fire several transactions and
+/// await their receipts concurrently, instead of blocking on confirmation one at a time.
+pub struct PendingTx {
+    /// Hash of the broadcast transaction.
+    pub hash: ethers::types::H256,
+    client: Arc<DefaultSignerMiddleware>,
+    /// The transaction as broadcast (nonce and fees already set), kept around so an
+    /// escalation round can rebuild it with bumped fees and the same nonce.
+    tx: TypedTransaction,
+    escalator_config: Option<EscalatorConfig>,
+}
+
+impl PendingTx {
+    /// Waits for the transaction receipt, escalating fees and rebroadcasting on the same
+    /// nonce per the configured [`EscalatorConfig`] if no receipt appears in time.
+    pub async fn wait(self) -> anyhow::Result<TransactionReceipt> {
+        self.wait_verbose().await.map(|c| c.receipt)
+    }
+
+    /// Like [`PendingTx::wait`], but also reports which escalation attempt confirmed.
+    pub async fn wait_verbose(mut self) -> anyhow::Result<Confirmation> {
+        let Some(escalator) = self.escalator_config else {
+            let receipt = PendingTransaction::new(self.hash, self.client.provider())
+                .retries(TRANSACTION_RECEIPT_RETRIES)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "txn sent to network, but receipt cannot be obtained, please check scanner"
+                    )
+                })?;
+            return Ok(Confirmation { receipt, attempt: 0 });
+        };
+
+        for attempt in 0..=escalator.max_rounds {
+            let wait = tokio::time::timeout(
+                escalator.initial_wait,
+                PendingTransaction::new(self.hash, self.client.provider()),
+            )
+            .await;
+            match wait {
+                Ok(Ok(Some(receipt))) => return Ok(Confirmation { receipt, attempt }),
+                Ok(Err(err)) => return Err(err.into()),
+                // Dropped from the mempool, or the wait just timed out: escalate below.
+                Ok(Ok(None)) | Err(_) => {}
+            }
+
+            if attempt == escalator.max_rounds {
+                break;
+            }
+
+            bump_fees(&mut self.tx, escalator.escalation_factor);
+            self.hash = *self
+                .client
+                .send_transaction(self.tx.clone(), None)
+                .await?;
+        }
+
+        Err(anyhow!(
+            "txn stuck in mempool after {} escalation rounds, please check scanner",
+            escalator.max_rounds
+        ))
+    }
+}
+
+/// Multiplies a transaction's `max_fee_per_gas`/`max_priority_fee_per_gas` (or `gas_price` for
+/// non-EIP-1559 transactions) by `factor`, in place, for an escalation round.
+fn bump_fees(tx: &mut TypedTransaction, factor: f64) {
+    let bumped = |fee: U256| -> U256 {
+        let fee = fee.as_u128() as f64 * factor;
+        U256::from(fee as u128)
+    };
+    match tx {
+        TypedTransaction::Eip1559(tx) => {
+            if let Some(fee) = tx.max_fee_per_gas {
+                tx.max_fee_per_gas = Some(bumped(fee));
+            }
+            if let Some(fee) = tx.max_priority_fee_per_gas {
+                tx.max_priority_fee_per_gas = Some(bumped(fee));
+            }
+        }
+        TypedTransaction::Legacy(tx) => {
+            if let Some(price) = tx.gas_price {
+                tx.gas_price = Some(bumped(price));
+            }
+        }
+        TypedTransaction::Eip2930(wrapped) => {
+            if let Some(price) = wrapped.tx.gas_price {
+                wrapped.tx.gas_price = Some(bumped(price));
+            }
+        }
+    }
+}
+
 /// A static wrapper around common EVM subnet methods.
 pub struct EvmManager {}
 
@@ -187,17 +457,26 @@ impl EvmManager {
 
         let call = supply_source.approve(gateway.address(), value.into());
 
-        client_send(supply_source.client(), call).await
+        client_send(
+            supply_source.client(),
+            call,
+            subnet.fee_estimator_config(),
+            subnet.gas_oracle_config.clone(),
+            subnet.access_list_enabled,
+            subnet.access_list_override.clone(),
+            subnet.escalator_config,
+        )
+        .await
     }
 
-    /// Deposit funds into a subnet.
-    pub async fn deposit(
+    /// Deposit funds into a subnet, without waiting for the transaction to confirm.
+    pub async fn deposit_pending(
         signer: &impl Signer,
         to: Address,
         from_subnet: EVMSubnet,
         to_subnet: SubnetID,
         amount: TokenAmount,
-    ) -> anyhow::Result<TransactionReceipt> {
+    ) -> anyhow::Result<PendingTx> {
         let gateway = get_gateway(signer, &from_subnet)?;
         let subnet_id = GatewaySubnetID::try_from(&to_subnet.inner())?;
 
@@ -208,16 +487,46 @@ impl EvmManager {
 
         let call = gateway.fund_with_token(subnet_id, FvmAddress::try_from(to)?, value.into());
 
-        client_send(gateway.client(), call).await
+        client_send_pending(
+            gateway.client(),
+            call,
+            from_subnet.fee_estimator_config(),
+            from_subnet.gas_oracle_config.clone(),
+            from_subnet.access_list_enabled,
+            from_subnet.access_list_override.clone(),
+            from_subnet.escalator_config,
+        )
+        .await
     }
 
-    /// Withdraw funds from a subnet.
-    pub async fn withdraw(
+    /// Deposit funds into a subnet.
+    pub async fn deposit(
+        signer: &impl Signer,
+        to: Address,
+        from_subnet: EVMSubnet,
+        to_subnet: SubnetID,
+        amount: TokenAmount,
+    ) -> anyhow::Result<DepositResult> {
+        let gateway_addr = payload_to_evm_address(from_subnet.gateway_addr.payload())?;
+        let supply_source = from_subnet
+            .supply_source
+            .map(|addr| payload_to_evm_address(addr.payload()))
+            .transpose()?;
+        let receipt = Self::deposit_pending(signer, to, from_subnet, to_subnet, amount)
+            .await?
+            .wait()
+            .await?;
+        let events = BridgeEvents::from_receipt(&receipt, gateway_addr, supply_source);
+        Ok(DepositResult { receipt, events })
+    }
+
+    /// Withdraw funds from a subnet, without waiting for the transaction to confirm.
+    pub async fn withdraw_pending(
         signer: &impl Signer,
         to: Address,
         subnet: EVMSubnet,
         amount: TokenAmount,
-    ) -> anyhow::Result<TransactionReceipt> {
+    ) -> anyhow::Result<PendingTx> {
         let gateway = get_gateway(signer, &subnet)?;
 
         let value = amount
@@ -228,103 +537,365 @@ impl EvmManager {
         let mut call = gateway.release(FvmAddress::try_from(to)?);
         call.tx.set_value(value);
 
-        client_send(gateway.client(), call).await
+        client_send_pending(
+            gateway.client(),
+            call,
+            subnet.fee_estimator_config(),
+            subnet.gas_oracle_config.clone(),
+            subnet.access_list_enabled,
+            subnet.access_list_override.clone(),
+            subnet.escalator_config,
+        )
+        .await
     }
 
-    /// Transfer funds between two accounts in a subnet.
-    pub async fn transfer(
+    /// Withdraw funds from a subnet.
+    pub async fn withdraw(
         signer: &impl Signer,
         to: Address,
         subnet: EVMSubnet,
         amount: TokenAmount,
-    ) -> anyhow::Result<TransactionReceipt> {
+    ) -> anyhow::Result<WithdrawResult> {
+        let gateway_addr = payload_to_evm_address(subnet.gateway_addr.payload())?;
+        let receipt = Self::withdraw_pending(signer, to, subnet, amount)
+            .await?
+            .wait()
+            .await?;
+        let events = BridgeEvents::from_receipt(&receipt, gateway_addr, None);
+        Ok(WithdrawResult { receipt, events })
+    }
+
+    /// Transfer funds between two accounts in a subnet, without waiting for the transaction
+    /// to confirm.
+    pub async fn transfer_pending(
+        signer: &impl Signer,
+        to: Address,
+        subnet: EVMSubnet,
+        amount: TokenAmount,
+    ) -> anyhow::Result<PendingTx> {
         let signer = Arc::new(get_eth_signer(signer, &subnet)?);
 
-        let (fee, fee_cap) = premium_estimation(signer.clone()).await?;
+        let nonce_manager = NonceManager::for_address(signer.address());
+        let nonce = nonce_manager.next(&signer).await?;
+        let (fee, fee_cap) = estimate_fees(
+            signer.clone(),
+            subnet.fee_estimator_config(),
+            subnet.gas_oracle_config.clone(),
+        )
+        .await?;
         let tx = Eip1559TransactionRequest::new()
             .to(payload_to_evm_address(to.payload())?)
             .value(fil_to_eth_amount(&amount)?)
+            .nonce(nonce)
             .max_priority_fee_per_gas(fee)
             .max_fee_per_gas(fee_cap);
 
-        let tx_pending = signer.send_transaction(tx, None).await?;
-        tx_pending
+        let typed_tx: TypedTransaction = tx.clone().into();
+        let hash = match signer.send_transaction(tx, None).await {
+            Ok(pending) => *pending,
+            Err(err) => {
+                let _ = nonce_manager.reset(&signer).await;
+                return Err(anyhow!(err));
+            }
+        };
+        Ok(PendingTx {
+            hash,
+            client: signer,
+            tx: typed_tx,
+            escalator_config: subnet.escalator_config,
+        })
+    }
+
+    /// Transfer funds between two accounts in a subnet.
+    pub async fn transfer(
+        signer: &impl Signer,
+        to: Address,
+        subnet: EVMSubnet,
+        amount: TokenAmount,
+    ) -> anyhow::Result<TransactionReceipt> {
+        Self::transfer_pending(signer, to, subnet, amount)
             .await?
-            .ok_or(anyhow!("transfer did not return receipt"))
+            .wait()
+            .await
+    }
+
+    /// Deploys `init_code` (compiled bytecode with constructor args already ABI-encoded and
+    /// appended) to a subnet. With `salt` unset, deploys via plain `CREATE`, so the address
+    /// depends on the sender's account nonce. With `salt` set, deploys via the canonical
+    /// [`CREATE2_DEPLOYER`] proxy, so the same `init_code` and `salt` reproduce the same
+    /// address on any subnet where the proxy is present (e.g. for subnet bootstrap, where a
+    /// supply-source ERC20 or router should live at the same address everywhere).
+    pub async fn deploy(
+        signer: &impl Signer,
+        subnet: EVMSubnet,
+        init_code: Bytes,
+        salt: Option<H256>,
+    ) -> anyhow::Result<DeployedContract> {
+        let eth_signer = Arc::new(get_eth_signer(signer, &subnet)?);
+        let nonce_manager = NonceManager::for_address(eth_signer.address());
+        let nonce = nonce_manager.next(&eth_signer).await?;
+        let (fee, fee_cap) = estimate_fees(
+            eth_signer.clone(),
+            subnet.fee_estimator_config(),
+            subnet.gas_oracle_config.clone(),
+        )
+        .await?;
+
+        let (to, data, address) = match salt {
+            None => {
+                let address = ethers::utils::get_contract_address(eth_signer.address(), nonce);
+                (None, init_code, address)
+            }
+            Some(salt) => {
+                let deployer: ethers::types::Address = CREATE2_DEPLOYER.parse()?;
+                let address = predict_create2_address(deployer, salt, &init_code);
+                let mut data = salt.as_bytes().to_vec();
+                data.extend_from_slice(&init_code);
+                (Some(deployer), Bytes::from(data), address)
+            }
+        };
+
+        let mut tx = Eip1559TransactionRequest::new()
+            .data(data)
+            .nonce(nonce)
+            .max_priority_fee_per_gas(fee)
+            .max_fee_per_gas(fee_cap);
+        if let Some(to) = to {
+            tx = tx.to(to);
+        }
+
+        let receipt = match eth_signer.send_transaction(tx, None).await {
+            Ok(pending) => pending
+                .retries(TRANSACTION_RECEIPT_RETRIES)
+                .await?
+                .ok_or_else(|| {
+                    anyhow!(
+                        "txn sent to network, but receipt cannot be obtained, please check scanner"
+                    )
+                })?,
+            Err(err) => {
+                let _ = nonce_manager.reset(&eth_signer).await;
+                return Err(anyhow!(err));
+            }
+        };
+
+        Ok(DeployedContract { address, receipt })
     }
 }
 
+/// The result of [`EvmManager::deploy`].
+pub struct DeployedContract {
+    pub address: ethers::types::Address,
+    pub receipt: TransactionReceipt,
+}
+
+/// Predicts the address `CREATE2` would assign to `init_code` deployed via `deployer` with
+/// `salt`, per EIP-1014: `keccak256(0xff ++ deployer ++ salt ++ keccak256(init_code))[12..]`.
+fn predict_create2_address(
+    deployer: ethers::types::Address,
+    salt: H256,
+    init_code: &[u8],
+) -> ethers::types::Address {
+    let init_code_hash = keccak256(init_code);
+    let mut bytes = Vec::with_capacity(1 + 20 + 32 + 32);
+    bytes.push(0xff);
+    bytes.extend_from_slice(deployer.as_bytes());
+    bytes.extend_from_slice(salt.as_bytes());
+    bytes.extend_from_slice(&init_code_hash);
+    ethers::types::Address::from_slice(&keccak256(bytes)[12..])
+}
+
+/// Sends a contract call and returns a handle to the broadcast transaction without
+/// waiting for it to confirm.
+async fn client_send_pending<T: ethers::abi::Detokenize>(
+    client: Arc<DefaultSignerMiddleware>,
+    call: ContractCall<DefaultSignerMiddleware, T>,
+    fee_config: FeeEstimatorConfig,
+    gas_oracle_config: Option<GasOracleConfig>,
+    access_list_enabled: bool,
+    access_list_override: Option<AccessList>,
+    escalator_config: Option<EscalatorConfig>,
+) -> anyhow::Result<PendingTx> {
+    let address = client.address();
+    let call = call_with_premium_and_pending_block(
+        client.clone(),
+        call,
+        fee_config,
+        gas_oracle_config,
+        access_list_enabled,
+        access_list_override,
+    )
+    .await?;
+    let tx = call.tx.clone();
+    let hash = match call.send().await {
+        Ok(tx) => *tx,
+        Err(err) => {
+            let _ = NonceManager::for_address(address).reset(&client).await;
+            return Err(anyhow!(err));
+        }
+    };
+    Ok(PendingTx {
+        hash,
+        client,
+        tx,
+        escalator_config,
+    })
+}
+
 /// Sends a contract call with configured retries using the provided client.
 async fn client_send<T: ethers::abi::Detokenize>(
     client: Arc<DefaultSignerMiddleware>,
     call: ContractCall<DefaultSignerMiddleware, T>,
+    fee_config: FeeEstimatorConfig,
+    gas_oracle_config: Option<GasOracleConfig>,
+    access_list_enabled: bool,
+    access_list_override: Option<AccessList>,
+    escalator_config: Option<EscalatorConfig>,
 ) -> anyhow::Result<TransactionReceipt> {
-    let call = call_with_premium_and_pending_block(client, call).await?;
-    let tx = call.send().await?;
-    match tx.retries(TRANSACTION_RECEIPT_RETRIES).await? {
-        Some(receipt) => Ok(receipt),
-        None => Err(anyhow!(
-            "txn sent to network, but receipt cannot be obtained, please check scanner"
-        )),
-    }
+    client_send_pending(
+        client,
+        call,
+        fee_config,
+        gas_oracle_config,
+        access_list_enabled,
+        access_list_override,
+        escalator_config,
+    )
+    .await?
+    .wait()
+    .await
 }
 
-/// Takes a `FunctionCall` input and returns a new instance with an estimated optimal `gas_premium`.
-/// The function also uses the pending block number to help retrieve the latest nonce
-/// via `get_transaction_count` with the `pending` parameter.
+/// Takes a `FunctionCall` input and returns a new instance with an estimated optimal `gas_premium`
+/// and the next nonce from the signer's [`NonceManager`], so that several calls issued
+/// back-to-back from the same account don't race on the same `pending` nonce lookup.
 async fn call_with_premium_and_pending_block<B, D, M>(
     signer: Arc<DefaultSignerMiddleware>,
     mut call: ethers_contract::FunctionCall<B, D, M>,
+    fee_config: FeeEstimatorConfig,
+    gas_oracle_config: Option<GasOracleConfig>,
+    access_list_enabled: bool,
+    access_list_override: Option<AccessList>,
 ) -> anyhow::Result<ethers_contract::FunctionCall<B, D, M>>
 where
     B: std::borrow::Borrow<D>,
     M: ethers::abi::Detokenize,
 {
-    let (max_priority_fee_per_gas, max_fee_per_gas) = premium_estimation(signer).await?;
-    let call_with_gas = match call.tx.clone() {
+    let nonce = NonceManager::for_address(signer.address())
+        .next(&signer)
+        .await?;
+    let (max_priority_fee_per_gas, max_fee_per_gas) =
+        estimate_fees(signer.clone(), fee_config, gas_oracle_config).await?;
+    let mut call_with_gas = match call.tx.clone() {
         TypedTransaction::Eip1559(mut tx) => {
             tx.max_fee_per_gas = Some(max_fee_per_gas);
             tx.max_priority_fee_per_gas = Some(max_priority_fee_per_gas);
+            tx.nonce = Some(nonce);
             call.tx = TypedTransaction::Eip1559(tx);
             call
         }
         TypedTransaction::Legacy(mut tx) => {
             tx.gas_price = Some(max_fee_per_gas);
+            tx.nonce = Some(nonce);
             call.tx = TypedTransaction::Legacy(tx);
             call
         }
         TypedTransaction::Eip2930(mut wrapped_tx) => {
             wrapped_tx.tx.gas_price = Some(max_fee_per_gas);
+            wrapped_tx.tx.nonce = Some(nonce);
             call.tx = TypedTransaction::Eip2930(wrapped_tx);
             call
         }
     };
 
+    if let Some(access_list) = access_list_override {
+        // A caller-supplied list always wins over `eth_createAccessList` -- it's there
+        // specifically to skip that extra RPC round trip.
+        call_with_gas.tx.set_access_list(access_list);
+    } else if access_list_enabled {
+        if let (Some(to), Some(data)) = (call_with_gas.tx.to_addr(), call_with_gas.tx.data()) {
+            if let Some(selector) = data.get(0..4) {
+                let selector: [u8; 4] = selector.try_into().unwrap();
+                let to = *to;
+                if let Some(access_list) = cached_access_list(to, selector) {
+                    call_with_gas.tx.set_access_list(access_list);
+                } else {
+                    let pending = Some(ethers::types::BlockNumber::Pending.into());
+                    if let Ok(access_list_with_gas_used) =
+                        signer.create_access_list(&call_with_gas.tx, pending).await
+                    {
+                        let access_list = access_list_with_gas_used.access_list;
+                        cache_access_list(to, selector, access_list.clone());
+                        call_with_gas.tx.set_access_list(access_list);
+                    }
+                    // An endpoint that doesn't support `eth_createAccessList` just leaves the
+                    // transaction's access list empty, exactly as it was before this was
+                    // opted in, and nothing gets cached.
+                }
+            }
+        }
+    }
+
     Ok(call_with_gas.block(ethers::types::BlockNumber::Pending))
 }
 
+/// Consults the configured external gas oracle for a `(max_priority_fee, max_fee_cap)` pair,
+/// falling back to [`premium_estimation`] if no oracle is configured or the oracle call fails.
+async fn estimate_fees(
+    signer: Arc<DefaultSignerMiddleware>,
+    fee_config: FeeEstimatorConfig,
+    gas_oracle_config: Option<GasOracleConfig>,
+) -> anyhow::Result<(U256, U256)> {
+    if let Some(gas_oracle_config) = gas_oracle_config {
+        let oracle = crate::ipc::gas_oracle::HttpGasOracle::new(gas_oracle_config);
+        if let Ok(estimate) = oracle.fetch().await {
+            return Ok(estimate);
+        }
+    }
+    premium_estimation(signer, fee_config).await
+}
+
 /// Returns an estimation of an optimal `gas_premium` and `gas_fee_cap`
 /// for a transaction considering the average premium, base_fee and reward percentile from
 /// past blocks
 /// This is an adaptation of ethers' `eip1559_default_estimator`:
 /// https://github.com/gakonst/ethers-rs/blob/5dcd3b7e754174448f9a8cbfc0523896609629f9/ethers-core/src/utils/mod.rs#L476
-async fn premium_estimation(signer: Arc<DefaultSignerMiddleware>) -> anyhow::Result<(U256, U256)> {
+async fn premium_estimation(
+    signer: Arc<DefaultSignerMiddleware>,
+    fee_config: FeeEstimatorConfig,
+) -> anyhow::Result<(U256, U256)> {
     let base_fee_per_gas = signer
         .get_block(ethers::types::BlockNumber::Latest)
         .await?
         .ok_or_else(|| anyhow!("Latest block not found"))?
-        .base_fee_per_gas
-        .ok_or_else(|| anyhow!("EIP-1559 not activated"))?;
+        .base_fee_per_gas;
+
+    let Some(base_fee_per_gas) = base_fee_per_gas else {
+        // The subnet hasn't activated EIP-1559 (e.g. pre-London), so `eth_feeHistory`-based
+        // estimation doesn't apply -- fall back to a plain `eth_gasPrice` quote, which
+        // `call_with_premium_and_pending_block` uses as `gas_price` for the
+        // `TypedTransaction::Legacy`/`Eip2930` call variants instead of `max_fee_per_gas`.
+        let gas_price = signer.get_gas_price().await?;
+        return Ok((U256::zero(), gas_price));
+    };
+
+    // On quiet subnets, fee history is empty or flat, so skip it entirely below the
+    // configured threshold and broadcast with the default priority fee instead.
+    if base_fee_per_gas <= U256::from(fee_config.base_fee_threshold) {
+        let default_priority_fee = U256::from(fee_config.default_priority_fee);
+        let max_fee_per_gas = base_fee_surged(base_fee_per_gas) + default_priority_fee;
+        return Ok((default_priority_fee, max_fee_per_gas));
+    }
 
     let fee_history = signer
         .fee_history(
-            ethers::utils::EIP1559_FEE_ESTIMATION_PAST_BLOCKS,
+            fee_config.past_blocks,
             ethers::types::BlockNumber::Latest,
-            &[ethers::utils::EIP1559_FEE_ESTIMATION_REWARD_PERCENTILE],
+            &[fee_config.reward_percentile],
         )
         .await?;
 
-    let max_priority_fee_per_gas = estimate_priority_fee(fee_history.reward); //overestimate?
+    let max_priority_fee_per_gas = estimate_priority_fee(fee_history.reward, fee_config);
     let potential_max_fee = base_fee_surged(base_fee_per_gas);
     let max_fee_per_gas = if max_priority_fee_per_gas > potential_max_fee {
         max_priority_fee_per_gas + potential_max_fee
@@ -353,14 +924,16 @@ fn base_fee_surged(base_fee_per_gas: U256) -> U256 {
 /// Implementation borrowed from
 /// https://github.com/gakonst/ethers-rs/blob/ethers-v2.0.8/ethers-core/src/utils/mod.rs#L536
 /// Refer to the implementation for unit tests
-fn estimate_priority_fee(rewards: Vec<Vec<U256>>) -> U256 {
+fn estimate_priority_fee(rewards: Vec<Vec<U256>>, fee_config: FeeEstimatorConfig) -> U256 {
     let mut rewards: Vec<U256> = rewards
         .iter()
         .map(|r| r[0])
         .filter(|r| *r > U256::zero())
         .collect();
     if rewards.is_empty() {
-        return U256::zero();
+        // No recently priced transactions; fall back to the configured default rather than
+        // broadcasting with a zero priority fee that may never get mined.
+        return U256::from(fee_config.default_priority_fee);
     }
     if rewards.len() == 1 {
         return rewards[0];
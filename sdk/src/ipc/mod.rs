@@ -0,0 +1,11 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! IPC (inter-process-chain) EVM subnet support: a static [`manager::EvmManager`] for the
+//! parent/child subnet gateway contracts, and configuration for the subnet RPC endpoint(s),
+//! fee estimation, and gas oracle.
+
+mod failover;
+pub mod gas_oracle;
+pub mod manager;
+pub mod subnet;
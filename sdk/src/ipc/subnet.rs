@@ -8,13 +8,16 @@ use reqwest::Url;
 use recall_provider::fvm_shared::address::Address;
 use recall_signer::SubnetID;
 
+use crate::ipc::gas_oracle::{GasOracleConfig, HttpGasOracle};
+
 /// The EVM subnet config parameters.
 #[derive(Debug, Clone)]
 pub struct EVMSubnet {
     /// The target subnet ID.
     pub id: SubnetID,
-    /// The EVM RPC provider endpoint.
-    pub provider_http: Url,
+    /// The EVM RPC provider endpoint(s), tried in order with failover on error (see
+    /// [`crate::ipc::manager::get_eth_provider`]). Must be non-empty.
+    pub provider_http: Vec<Url>,
     /// The EVM RPC provider request timeout.
     pub provider_timeout: Option<Duration>,
     /// The EVM RPC provider authorization token.
@@ -25,4 +28,93 @@ pub struct EVMSubnet {
     pub gateway_addr: Address,
     /// The EVM supply source contract address.
     pub supply_source: Option<Address>,
+    /// EIP-1559 fee estimation policy. Defaults to [`FeeEstimatorConfig::default`] when unset.
+    pub fee_estimator_config: Option<FeeEstimatorConfig>,
+    /// External gas-oracle backend to consult before falling back to local fee-history
+    /// based estimation. Useful on subnets whose RPC endpoint returns flat or empty
+    /// `eth_feeHistory` responses.
+    pub gas_oracle_config: Option<GasOracleConfig>,
+    /// Opt-in: populate an EIP-2930 access list (via `eth_createAccessList`) on gateway and
+    /// ERC20 calls before signing. Off by default, since not every RPC endpoint supports the
+    /// method; a failed `eth_createAccessList` call is skipped rather than propagated. The
+    /// result is cached per `(to, selector)` (see [`crate::ipc::manager`]) so repeat calls to
+    /// the same contract method -- e.g. the `approve`/`deposit` pair `Account::deposit` sends in
+    /// sequence -- don't re-query the RPC once the list has been learned.
+    pub access_list_enabled: bool,
+    /// Manual override for [`Self::access_list_enabled`]: a static access list to attach to
+    /// every outgoing transaction instead of calling `eth_createAccessList`. Useful for the
+    /// well-known gateway/supply-source contracts, whose touched storage slots don't change
+    /// between deployments, to skip the extra RPC round trip entirely.
+    pub access_list_override: Option<ethers::types::transaction::eip2930::AccessList>,
+    /// Opt-in: re-broadcast a transaction with bumped fees if no receipt appears within a
+    /// configurable window, so a transaction stuck in the mempool from an underestimated fee
+    /// can still land. Unset disables escalation entirely.
+    pub escalator_config: Option<EscalatorConfig>,
+}
+
+/// Configures gas-escalation retries for transactions stuck in the mempool: if no receipt
+/// appears within `initial_wait`, the same transaction (same nonce) is rebroadcast with
+/// `max_fee_per_gas`/`max_priority_fee_per_gas` multiplied by `escalation_factor`, up to
+/// `max_rounds` times.
+#[derive(Debug, Clone, Copy)]
+pub struct EscalatorConfig {
+    /// How long to wait for a receipt before escalating.
+    pub initial_wait: Duration,
+    /// Factor each round's fees are multiplied by, e.g. `1.25` for a 25% bump per round.
+    pub escalation_factor: f64,
+    /// Maximum number of escalation rounds before giving up.
+    pub max_rounds: u32,
+}
+
+impl Default for EscalatorConfig {
+    fn default() -> Self {
+        EscalatorConfig {
+            initial_wait: Duration::from_secs(30),
+            escalation_factor: 1.25,
+            max_rounds: 5,
+        }
+    }
+}
+
+/// Configures the EIP-1559 fee estimation policy used by [`EvmManager`](crate::ipc::manager::EvmManager)
+/// send paths, so the hard-coded constants in `premium_estimation` can be tuned per subnet.
+#[derive(Debug, Clone, Copy)]
+pub struct FeeEstimatorConfig {
+    /// Number of past blocks to sample via `eth_feeHistory`.
+    pub past_blocks: u64,
+    /// Reward percentile requested from `eth_feeHistory`.
+    pub reward_percentile: f64,
+    /// Priority fee (in wei) to use when the base fee is at or below `base_fee_threshold`,
+    /// or when `eth_feeHistory` returns no usable rewards.
+    pub default_priority_fee: u64,
+    /// Base fee (in wei) at or below which fee-history-based estimation is skipped entirely
+    /// in favor of `default_priority_fee`. Quiet subnets otherwise report empty fee history
+    /// and would broadcast transactions with a zero priority fee that never get mined.
+    pub base_fee_threshold: u64,
+}
+
+impl Default for FeeEstimatorConfig {
+    fn default() -> Self {
+        FeeEstimatorConfig {
+            past_blocks: ethers::utils::EIP1559_FEE_ESTIMATION_PAST_BLOCKS,
+            reward_percentile: ethers::utils::EIP1559_FEE_ESTIMATION_REWARD_PERCENTILE,
+            // 3 gwei.
+            default_priority_fee: 3_000_000_000,
+            base_fee_threshold: 0,
+        }
+    }
+}
+
+impl EVMSubnet {
+    /// Returns the configured fee estimator config, or the default if unset.
+    pub fn fee_estimator_config(&self) -> FeeEstimatorConfig {
+        self.fee_estimator_config.unwrap_or_default()
+    }
+
+    /// Builds the configured external gas oracle, if any.
+    pub fn gas_oracle(&self) -> Option<HttpGasOracle> {
+        self.gas_oracle_config
+            .clone()
+            .map(HttpGasOracle::new)
+    }
 }
@@ -9,8 +9,10 @@ use recall_provider::message::GasParams;
 
 pub mod account;
 pub mod credits;
+pub mod encryption;
 pub mod ipc;
 pub mod machine;
+pub mod middleware;
 pub mod network;
 pub mod progress;
 pub mod storage;
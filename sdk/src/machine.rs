@@ -28,6 +28,11 @@ use recall_signer::Signer;
 pub mod bucket;
 pub mod timehub;
 
+/// Metadata key [`Machine::new_deterministic`] tags a deployment with, so
+/// [`Machine::find_by_salt`] can look the address back up by the same owner+salt later instead
+/// of redeploying.
+pub const DEPLOY_SALT_METADATA_KEY: &str = "recall:deploy-salt";
+
 /// Trait implemented by different machine kinds.
 /// This is modeled after Ethers contract deployment UX.
 #[async_trait]
@@ -45,6 +50,54 @@ pub trait Machine: Send + Sync + Sized {
     where
         C: Client + Send + Sync;
 
+    /// Deploys a machine the same way [`Self::new`] does, but first checks (via
+    /// [`Self::find_by_salt`]) whether the signer already deployed one tagged with this `salt`,
+    /// attaching to it instead of deploying a duplicate if so. Returns `None` in place of a
+    /// transaction result when an existing deployment was reused.
+    ///
+    /// FVM assigns a freshly created actor's address from the init actor's incrementing counter,
+    /// not a hash of its constructor arguments, so there's no on-chain equivalent of predicting a
+    /// CREATE2 address before deploying. This gives callers the property they're usually actually
+    /// after -- the same owner+salt resolving to the same address across redeploys -- by looking
+    /// the prior deployment up on-chain instead of deriving its address off-chain.
+    async fn new_deterministic<C>(
+        provider: &impl Provider<C>,
+        signer: &mut impl Signer,
+        owner: Option<Address>,
+        mut metadata: HashMap<String, String>,
+        salt: &str,
+        gas_params: GasParams,
+    ) -> anyhow::Result<(Self, Option<TxResult<CreateExternalReturn>>)>
+    where
+        C: Client + Send + Sync,
+    {
+        if let Some(address) =
+            Self::find_by_salt(provider, signer, salt, FvmQueryHeight::Committed).await?
+        {
+            return Ok((Self::attach(address).await?, None));
+        }
+        metadata.insert(DEPLOY_SALT_METADATA_KEY.to_string(), salt.to_string());
+        let (this, tx) = Self::new(provider, signer, owner, metadata, gas_params).await?;
+        Ok((this, Some(tx)))
+    }
+
+    /// Looks up the address of a machine of this kind previously deployed with
+    /// [`Self::new_deterministic`] under the same `salt`, without deploying anything. Like
+    /// [`Self::list`], this is scoped to machines owned by `signer`, regardless of any `owner`
+    /// override passed to [`Self::new_deterministic`].
+    async fn find_by_salt(
+        provider: &impl QueryProvider,
+        signer: &impl Signer,
+        salt: &str,
+        height: FvmQueryHeight,
+    ) -> anyhow::Result<Option<Address>> {
+        let deployments = Self::list(provider, signer, height).await?;
+        Ok(deployments
+            .into_iter()
+            .find(|m| m.metadata.get(DEPLOY_SALT_METADATA_KEY).map(String::as_str) == Some(salt))
+            .map(|m| m.address))
+    }
+
     /// List machines owned by the given [`Signer`].
     async fn list(
         provider: &impl QueryProvider,
@@ -124,6 +177,7 @@ where
             CreateExternal as u64,
             params,
             gas_params,
+            None,
             BroadcastMode::Commit,
             decode_create,
         )
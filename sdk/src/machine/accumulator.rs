@@ -66,14 +66,16 @@ where
         args: TxArgs,
     ) -> anyhow::Result<Tx<Cid>> {
         let params = RawBytes::serialize(payload.to_vec())?;
-        let message = signer.transaction(
-            self.address,
-            Default::default(),
-            Push as u64,
-            params,
-            None,
-            args.gas_params,
-        )?;
+        let message = signer
+            .transaction(
+                self.address,
+                Default::default(),
+                Push as u64,
+                params,
+                args.gas_params,
+                None,
+            )
+            .await?;
         provider.perform(message, broadcast_mode, decode_cid).await
     }
 
@@ -86,4 +88,57 @@ where
         let response = provider.call(message, height, decode_cid).await?;
         Ok(response.value)
     }
+
+    /// Proves that the payload pushed at `index` is committed under the accumulator's root at
+    /// `height`, by returning the sibling hashes along the path from that leaf to the root.
+    ///
+    /// Unimplemented: the accumulator actor (`fendermint_actor_accumulator::Method`, as vendored
+    /// here) only exposes `Push` and `Root` -- there is no query method returning a leaf's sibling
+    /// path or even the total leaf count, so this can't be built against the actor as it stands.
+    /// A real implementation needs the actor to expose that (e.g. a `Proof(index)` method), at
+    /// which point this should fetch the siblings and hand them back as a [`MerkleProof`] --
+    /// [`verify_proof`] below is already written against that shape and doesn't depend on this.
+    pub async fn inclusion_proof(
+        &self,
+        _provider: &impl Provider<C>,
+        _index: u64,
+        _height: FvmQueryHeight,
+    ) -> anyhow::Result<MerkleProof> {
+        Err(anyhow::anyhow!(
+            "inclusion_proof is not supported: the accumulator actor doesn't expose a query \
+             method for a leaf's sibling path"
+        ))
+    }
+}
+
+/// A Merkle inclusion proof for one leaf of an [`Accumulator`], verifiable locally with
+/// [`verify_proof`] against a root obtained from [`Accumulator::root`].
+#[derive(Debug, Clone)]
+pub struct MerkleProof {
+    /// Index of the leaf this proof is for.
+    pub leaf_index: u64,
+    /// Sibling hashes along the path from the leaf to the root, ordered from the leaf's
+    /// immediate sibling up to the root's final pair.
+    pub siblings: Vec<[u8; 32]>,
+}
+
+/// Recomputes the Merkle root from `leaf`, `proof`, and the leaf's index, and checks it against
+/// `root`. At each level, `leaf_index`'s parity selects whether the running hash is the left or
+/// right operand, matching a standard bottom-up Merkle tree (hash = blake3(left || right)).
+pub fn verify_proof(root: &[u8; 32], leaf: &[u8], proof: &MerkleProof) -> bool {
+    let mut hash = *blake3::hash(leaf).as_bytes();
+    let mut index = proof.leaf_index;
+    for sibling in &proof.siblings {
+        let mut buf = [0u8; 64];
+        if index % 2 == 0 {
+            buf[..32].copy_from_slice(&hash);
+            buf[32..].copy_from_slice(sibling);
+        } else {
+            buf[..32].copy_from_slice(sibling);
+            buf[32..].copy_from_slice(&hash);
+        }
+        hash = *blake3::hash(&buf).as_bytes();
+        index /= 2;
+    }
+    &hash == root
 }
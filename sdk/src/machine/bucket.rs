@@ -1,33 +1,44 @@
 // Copyright 2025 Recall Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
-use std::path::Path;
-use std::{cmp::min, collections::HashMap, str::FromStr};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::{cmp::min, collections::HashMap, str::FromStr, time::Duration};
 
 use anyhow::anyhow;
 use async_trait::async_trait;
 use fendermint_actor_blobs_shared::state::{Hash, PublicKey};
 use fendermint_actor_bucket::{
-    AddParams, DeleteParams, GetParams, ListObjectsReturn, ListParams,
-    Method::{AddObject, DeleteObject, GetObject, ListObjects, UpdateObjectMetadata},
+    AddParams, DeleteManyParams, DeleteParams, GetParams, ListObjectsReturn, ListParams,
+    Method::{
+        AddObject, DeleteObject, DeleteObjects, GetObject, ListObjects, UpdateObjectMetadata,
+    },
     UpdateObjectMetadataParams, MAX_METADATA_KEY_SIZE, MAX_METADATA_VALUE_SIZE,
 };
 use fendermint_vm_actor_interface::adm::{CreateExternalReturn, Kind};
+use futures::stream::{self, StreamExt as _};
 use indicatif::HumanDuration;
 use iroh_blobs::Hash as IrohHash;
 use peekable::tokio::AsyncPeekable;
+use serde::{Deserialize, Serialize};
 use tendermint::abci::response::DeliverTx;
-use tokio::io::{AsyncRead, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
-use tokio::time::Instant;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt, AsyncWrite, AsyncWriteExt};
+use tokio::sync::Mutex;
+use tokio::time::{sleep, Instant};
 use tokio_stream::StreamExt;
 use tokio_util::io::ReaderStream;
 
+use crate::encryption::decryptor::DecryptWriter;
+use crate::encryption::object::size_encrypted;
+use crate::encryption::{sse_c, CipherSuite};
+
 use recall_provider::{
     fvm_ipld_encoding,
     fvm_ipld_encoding::RawBytes,
     fvm_shared::{address::Address, clock::ChainEpoch, econ::TokenAmount},
     message::{local_message, GasParams},
-    object::ObjectProvider,
+    object::{Blake3Hash, ObjectProvider, UploadManifest, UploadPart},
     query::{FvmQueryHeight, QueryProvider},
     response::{decode_as, decode_bytes},
     tx::{BroadcastMode, TxResult},
@@ -45,6 +56,27 @@ pub use fendermint_actor_bucket::{Object, ObjectState};
 /// Maximum allowed object size in bytes.
 const MAX_OBJECT_LENGTH: u64 = 5_000_000_000; // 5GB
 
+/// Computes the content hash of `data` the same way the bucket actor hashes object bytes on
+/// upload, so callers can compare it against an existing [`ObjectState::hash`] without
+/// re-uploading (e.g. to skip unchanged files when mirroring a local directory).
+pub fn hash(data: &[u8]) -> Hash {
+    Hash(*IrohHash::new(data).as_bytes())
+}
+
+/// A client-side content-encryption key for [`AddOptions::encryption`], sealed with SSE-C
+/// semantics: the sealed object key is stored in the object's metadata, and [`Bucket::get`]
+/// recovers the plaintext key the same way `sse_c` does on the CLI (see
+/// [`crate::encryption::sse_c`]).
+#[derive(Clone, Debug)]
+pub enum ObjectEncryptionKey {
+    /// A raw 32-byte key-encryption-key, base64-encoded.
+    Key(String),
+    /// A human passphrase. The key-encryption-key is derived via Argon2id with a fresh random
+    /// salt, and the salt plus KDF parameters are stored in the object's metadata so `get` can
+    /// repeat the derivation given the same passphrase.
+    Passphrase(String),
+}
+
 /// Object add options.
 #[derive(Clone, Default, Debug)]
 pub struct AddOptions {
@@ -62,6 +94,11 @@ pub struct AddOptions {
     pub broadcast_mode: BroadcastMode,
     /// Gas params for the transaction.
     pub gas_params: GasParams,
+    /// If set, the object is encrypted client-side (SSE-C, AES-256-GCM) before it's uploaded, so
+    /// the node only ever sees ciphertext. Download it with the same key via
+    /// [`GetOptions::decryption_key`] -- unseal it first with
+    /// [`crate::encryption::object::EncryptedObjectExt::sealed_object_key`].
+    pub encryption: Option<ObjectEncryptionKey>,
     /// Whether to show progress-related output (useful for command-line interfaces).
     pub show_progress: bool,
 }
@@ -75,6 +112,15 @@ pub struct DeleteOptions {
     pub gas_params: GasParams,
 }
 
+/// The per-key outcome of a [`Bucket::delete_many`] call.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DeleteManyResult {
+    /// Keys that existed and were deleted.
+    pub deleted: Vec<String>,
+    /// Keys that didn't exist, so there was nothing to delete.
+    pub not_found: Vec<String>,
+}
+
 /// Update object metadata options.
 #[derive(Clone, Default, Debug)]
 pub struct UpdateObjectMetadataOptions {
@@ -85,18 +131,52 @@ pub struct UpdateObjectMetadataOptions {
 }
 
 /// Object get options.
-#[derive(Clone, Default, Debug)]
+#[derive(Clone, Debug)]
 pub struct GetOptions {
-    /// Optional range of bytes to get from the object.
-    /// Format: "start-end" (inclusive).
-    /// Example: "0-99" (first 100 bytes).
+    /// Optional range(s) of bytes to get from the object.
+    /// Format: one or more comma-separated "start-end" (inclusive) or suffix "-N" (last N bytes)
+    /// segments.
+    /// Examples: "0-99" (first 100 bytes), "0-99,500-599,-200" (first 100 bytes, a middle 100
+    /// bytes, and the last 200 bytes).
     /// This follows the HTTP range header format:
     /// `<https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Range>`
     pub range: Option<String>,
     /// Query block height.
     pub height: FvmQueryHeight,
+    /// The content encryption key to decrypt the object with, if it was uploaded encrypted.
+    /// Callers resolve this themselves (e.g. unsealing an SSE-C key, or asking a KMS to unwrap
+    /// an SSE-KMS key) using [`crate::encryption::object::EncryptedObjectExt`] on the [`Object`]
+    /// returned by [`Bucket::head`].
+    pub decryption_key: Option<[u8; 32]>,
+    /// Whether to verify the downloaded bytes against `object.hash` (a whole-object BLAKE3 hash
+    /// computed incrementally while streaming). Bytes are written to `writer` as they arrive and
+    /// the hash is only checked once the whole stream (and a final `flush`) completes, so a
+    /// mismatch is reported as `Err` but `writer` itself has already received every byte --
+    /// verification catches a corrupted or substituted response before the caller trusts it, not
+    /// before `writer` sees it. A caller writing to a real destination path (a file, say) and
+    /// wanting a failed check to leave nothing behind needs to write to a staging location and
+    /// rename into place only on `Ok`, the way the CLI's `bucket get --output` does. Ranged
+    /// requests only verify the byte count, since a partial object can't reproduce the
+    /// whole-object hash. Defaults to `true`.
+    pub verify: bool,
     /// Whether to show progress-related output (useful for command-line interfaces).
     pub show_progress: bool,
+    /// Number of concurrent ranged requests [`Bucket::get_parallel`] splits the download into.
+    /// Values `<= 1` disable parallelism. Ignored by [`Bucket::get`] itself.
+    pub parallelism: usize,
+}
+
+impl Default for GetOptions {
+    fn default() -> Self {
+        GetOptions {
+            range: Default::default(),
+            height: Default::default(),
+            decryption_key: Default::default(),
+            verify: true,
+            show_progress: Default::default(),
+            parallelism: 1,
+        }
+    }
 }
 
 /// Object query options.
@@ -185,7 +265,22 @@ impl Bucket {
         let content_type = infer::get(&buffer[..]).map(|t| t.to_string());
 
         validate_metadata(&options.metadata)?;
-        let options = self.add_content_type_to_metadata(options, content_type);
+        let mut options = self.add_content_type_to_metadata(options, content_type);
+
+        let (reader, size): (Pin<Box<dyn AsyncRead + Send>>, u64) = match options.encryption.take()
+        {
+            Some(encryption_key) => {
+                let sse_c_key = match &encryption_key {
+                    ObjectEncryptionKey::Key(k) => sse_c::SseCKey::Key(k),
+                    ObjectEncryptionKey::Passphrase(p) => sse_c::SseCKey::Passphrase(p),
+                };
+                let (encrypted, sealed_metadata) =
+                    sse_c::encrypt_reader(reader, sse_c_key, key, CipherSuite::AES256GCM)?;
+                options.metadata.extend(sealed_metadata);
+                (Box::pin(encrypted), size_encrypted(size))
+            }
+            None => (Box::pin(reader), size),
+        };
 
         let started = Instant::now();
         let bars = new_multi_bar(!options.show_progress);
@@ -238,6 +333,7 @@ impl Bucket {
                 AddObject as u64,
                 RawBytes::serialize(params)?,
                 options.gas_params,
+                None,
                 options.broadcast_mode,
                 decode_as,
             )
@@ -292,6 +388,243 @@ impl Bucket {
             .await
     }
 
+    /// Adds a file into the bucket the same way [`Bucket::add_from_path`] does, but uploads it in
+    /// fixed-size, content-addressed parts (see [`UploadManifest`]) instead of one continuous
+    /// stream. Progress is checkpointed to a sidecar manifest file under `checkpoint_dir`, so if
+    /// the connection drops partway through a multi-gigabyte upload, re-running this call resumes
+    /// from the last part the node acknowledged rather than starting over. Each part is retried
+    /// independently with exponential backoff before the whole call gives up.
+    ///
+    /// Not supported together with `options.encryption` -- client-side encryption isn't part-aware
+    /// yet, so use [`Bucket::add_from_path`] for encrypted uploads.
+    pub async fn add_from_path_resumable<C>(
+        &self,
+        provider: &(impl Provider<C> + ObjectProvider),
+        signer: &mut impl Signer,
+        from: Address,
+        key: &str,
+        path: impl AsRef<Path>,
+        checkpoint_dir: impl AsRef<Path>,
+        chunk_size: u64,
+        options: AddOptions,
+    ) -> anyhow::Result<TxResult<Object>>
+    where
+        C: Client + Send + Sync,
+    {
+        if options.encryption.is_some() {
+            return Err(anyhow!(
+                "add_from_path_resumable does not support options.encryption"
+            ));
+        }
+        validate_metadata(&options.metadata)?;
+
+        let path = path
+            .as_ref()
+            .canonicalize()
+            .map_err(|e| anyhow!("failed to resolve path: {}", e))?;
+        let mut file = tokio::fs::File::open(&path).await?;
+        let total_size = file.seek(std::io::SeekFrom::End(0)).await?;
+        if total_size > MAX_OBJECT_LENGTH {
+            return Err(anyhow!("file exceeds maximum allowed size of 5 GB"));
+        }
+
+        let content_type = mime_guess::from_path(&path)
+            .first()
+            .map(|mime| mime.to_string());
+        let options = self.add_content_type_to_metadata(options, content_type);
+
+        let manifest_path = UploadManifest::manifest_path(checkpoint_dir.as_ref(), key);
+        let mut manifest = match UploadManifest::load(&manifest_path, key, total_size).await? {
+            Some(manifest) => manifest,
+            None => {
+                let mut manifest = UploadManifest::new(key, total_size, chunk_size);
+                let mut offset = 0u64;
+                while offset < total_size {
+                    let part_size = min(chunk_size, total_size - offset);
+                    let mut buf = vec![0u8; part_size as usize];
+                    file.seek(std::io::SeekFrom::Start(offset)).await?;
+                    file.read_exact(&mut buf).await?;
+                    let hash: Blake3Hash = blake3::hash(&buf).to_hex().parse()?;
+                    manifest.parts.push(UploadPart { hash, size: part_size });
+                    offset += part_size;
+                }
+                manifest
+            }
+        };
+
+        // A part the node reports as already staged (e.g. from a previous attempt this manifest
+        // wasn't persisted in time to record) doesn't need to be re-uploaded either.
+        let all_hashes: Vec<Blake3Hash> = manifest.parts.iter().map(|p| p.hash).collect();
+        let staged = provider.staged_parts(key, &all_hashes).await?;
+        manifest.completed.extend(staged);
+
+        let started = Instant::now();
+        let bars = new_multi_bar(!options.show_progress);
+        let msg_bar = bars.add(new_message_bar());
+        let pro_bar = bars.add(new_progress_bar(total_size));
+        msg_bar.set_prefix("[1/2]");
+        msg_bar.set_message("Uploading parts...");
+
+        let mut uploaded_bytes: u64 = manifest
+            .parts
+            .iter()
+            .filter(|p| manifest.completed.contains(&p.hash))
+            .map(|p| p.size)
+            .sum();
+        pro_bar.set_position(uploaded_bytes);
+
+        let mut offset = 0u64;
+        for part in manifest.parts.clone() {
+            if manifest.completed.contains(&part.hash) {
+                offset += part.size;
+                continue;
+            }
+
+            let mut buf = vec![0u8; part.size as usize];
+            file.seek(std::io::SeekFrom::Start(offset)).await?;
+            file.read_exact(&mut buf).await?;
+
+            let mut attempt = 0u32;
+            loop {
+                match provider
+                    .upload_part(key, part, reqwest::Body::from(buf.clone()))
+                    .await
+                {
+                    Ok(()) => break,
+                    Err(_e) if attempt < 5 => {
+                        attempt += 1;
+                        sleep(Duration::from_millis(200 * 2u64.pow(attempt))).await;
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+
+            manifest.completed.insert(part.hash);
+            manifest.save(&manifest_path).await?;
+            uploaded_bytes += part.size;
+            pro_bar.set_position(uploaded_bytes);
+            offset += part.size;
+        }
+
+        pro_bar.finish_and_clear();
+        msg_bar.set_prefix("[2/2]");
+        msg_bar.set_message("Finalizing upload and broadcasting transaction...");
+
+        let upload_response = provider
+            .finalize_multipart(key, &manifest.parts, total_size)
+            .await?;
+        UploadManifest::remove(&manifest_path).await?;
+
+        let node_addr = provider.node_addr().await?;
+        let params = AddParams {
+            source: PublicKey(*node_addr.node_id.as_bytes()),
+            key: key.into(),
+            hash: Hash(*upload_response.hash.as_bytes()),
+            recovery_hash: Hash(*upload_response.metadata_hash.as_bytes()),
+            size: total_size,
+            ttl: options.ttl,
+            metadata: options.metadata,
+            overwrite: options.overwrite,
+            from,
+        };
+
+        let tx = signer
+            .send_transaction(
+                provider,
+                self.address,
+                options.token_amount.unwrap_or_default(),
+                AddObject as u64,
+                RawBytes::serialize(params)?,
+                options.gas_params,
+                None,
+                options.broadcast_mode,
+                decode_as,
+            )
+            .await?;
+
+        msg_bar.println(format!(
+            "{} Added object in {} (hash={}; size={})",
+            SPARKLE,
+            HumanDuration(started.elapsed()),
+            upload_response.hash,
+            total_size
+        ));
+        msg_bar.finish_and_clear();
+        Ok(tx)
+    }
+
+    /// Recursively adds every file under `dir_path` into the bucket, registering each one under
+    /// `key_prefix` joined with its path relative to `dir_path` (using the same `/` delimiter
+    /// [`QueryOptions`] uses for hierarchy). Reuses [`Bucket::add_from_path`] per file, so
+    /// content-type detection and `options` (TTL, overwrite, encryption, etc.) apply uniformly;
+    /// progress is reported once for the whole tree (files completed / total bytes) rather than
+    /// per file, so `options.show_progress` drives a single aggregate bar instead of one per file.
+    pub async fn add_dir<C>(
+        &self,
+        provider: &impl Provider<C>,
+        signer: &mut impl Signer,
+        from: Address,
+        key_prefix: &str,
+        dir_path: impl AsRef<Path>,
+        options: AddOptions,
+    ) -> anyhow::Result<Vec<TxResult<Object>>>
+    where
+        C: Client + Send + Sync,
+    {
+        let dir_path = dir_path.as_ref();
+        let md = tokio::fs::metadata(dir_path).await?;
+        if !md.is_dir() {
+            return Err(anyhow!("input must be a directory"));
+        }
+
+        let mut files = walk_dir(dir_path).await?;
+        files.sort();
+
+        let mut sizes = Vec::with_capacity(files.len());
+        let mut total_bytes = 0u64;
+        for (path, _) in &files {
+            let size = tokio::fs::metadata(path).await?.len();
+            total_bytes += size;
+            sizes.push(size);
+        }
+
+        let started = Instant::now();
+        let bars = new_multi_bar(!options.show_progress);
+        let msg_bar = bars.add(new_message_bar());
+        let pro_bar = bars.add(new_progress_bar(total_bytes));
+
+        let mut results = Vec::with_capacity(files.len());
+        let mut bytes_done = 0u64;
+        for (i, (path, rel_key)) in files.iter().enumerate() {
+            let key = format!("{}{}", key_prefix, rel_key);
+            msg_bar.set_message(format!("[{}/{}] Adding {} ...", i + 1, files.len(), key));
+
+            let file_options = AddOptions {
+                show_progress: false,
+                ..options.clone()
+            };
+            let result = self
+                .add_from_path(provider, signer, from, &key, path, file_options)
+                .await?;
+
+            bytes_done += sizes[i];
+            pro_bar.set_position(bytes_done);
+            results.push(result);
+        }
+
+        pro_bar.finish_and_clear();
+        msg_bar.println(format!(
+            "{} Added {} objects in {} ({} bytes total)",
+            SPARKLE,
+            files.len(),
+            HumanDuration(started.elapsed()),
+            total_bytes
+        ));
+        msg_bar.finish_and_clear();
+
+        Ok(results)
+    }
+
     /// Delete an object.
     pub async fn delete<C>(
         &self,
@@ -317,36 +650,184 @@ impl Bucket {
                 DeleteObject as u64,
                 params,
                 options.gas_params,
+                None,
                 options.broadcast_mode,
                 |_: &DeliverTx| -> anyhow::Result<()> { Ok(()) },
             )
             .await
     }
 
+    /// Delete many objects in a single transaction, rather than one [`Bucket::delete`] call (and
+    /// one confirmation) per key -- useful when removing a whole prefix listing returned by
+    /// [`Bucket::query`]. Returns which keys were actually deleted versus not found, since a
+    /// caller batching an existing listing may race with a concurrent delete of the same key.
+    pub async fn delete_many<C>(
+        &self,
+        provider: &impl Provider<C>,
+        signer: &mut impl Signer,
+        from: Address,
+        keys: &[String],
+        options: DeleteOptions,
+    ) -> anyhow::Result<TxResult<DeleteManyResult>>
+    where
+        C: Client + Send + Sync,
+    {
+        let params = DeleteManyParams {
+            keys: keys.to_vec(),
+            from,
+        };
+        let params = RawBytes::serialize(params)?;
+        signer
+            .send_transaction(
+                provider,
+                self.address,
+                Default::default(),
+                DeleteObjects as u64,
+                params,
+                options.gas_params,
+                None,
+                options.broadcast_mode,
+                decode_as,
+            )
+            .await
+    }
+
+    /// Copies an object to a new key without downloading and re-uploading its bytes: since
+    /// objects are content-addressed by `hash`, this reads the source object's metadata via
+    /// [`Bucket::head`] and issues an `AddObject` transaction that points `dst_key` at the same
+    /// `hash`/`size`, carrying over its `metadata` (merged with `options.metadata`, which takes
+    /// precedence on key collisions). Use `options.overwrite` to allow overwriting an existing
+    /// object at `dst_key`.
+    pub async fn copy<C>(
+        &self,
+        provider: &(impl Provider<C> + QueryProvider + ObjectProvider),
+        signer: &mut impl Signer,
+        from: Address,
+        src_key: &str,
+        dst_key: &str,
+        options: AddOptions,
+    ) -> anyhow::Result<TxResult<Object>>
+    where
+        C: Client + Send + Sync,
+    {
+        let source = self
+            .head(provider, src_key, FvmQueryHeight::Committed)
+            .await?;
+
+        let mut metadata = source.metadata;
+        metadata.extend(options.metadata);
+        validate_metadata(&metadata)?;
+
+        let node_addr = provider.node_addr().await?;
+        let params = AddParams {
+            source: PublicKey(*node_addr.node_id.as_bytes()),
+            key: dst_key.into(),
+            hash: source.hash,
+            recovery_hash: source.recovery_hash,
+            size: source.size,
+            ttl: options.ttl,
+            metadata,
+            overwrite: options.overwrite,
+            from,
+        };
+
+        signer
+            .send_transaction(
+                provider,
+                self.address,
+                options.token_amount.unwrap_or_default(),
+                AddObject as u64,
+                RawBytes::serialize(params)?,
+                options.gas_params,
+                None,
+                options.broadcast_mode,
+                decode_as,
+            )
+            .await
+    }
+
+    /// Renames an object: equivalent to [`Bucket::copy`] followed by deleting `src_key`, so it
+    /// pays the same near-zero, no-re-upload cost as a copy.
+    pub async fn rename<C>(
+        &self,
+        provider: &(impl Provider<C> + QueryProvider + ObjectProvider),
+        signer: &mut impl Signer,
+        from: Address,
+        src_key: &str,
+        dst_key: &str,
+        options: AddOptions,
+    ) -> anyhow::Result<TxResult<Object>>
+    where
+        C: Client + Send + Sync,
+    {
+        let result = self
+            .copy(provider, signer, from, src_key, dst_key, options)
+            .await?;
+        self.delete(provider, signer, from, src_key, DeleteOptions::default())
+            .await?;
+        Ok(result)
+    }
+
+    /// Get an object's metadata at the given key and height, without downloading its content.
+    pub async fn head(
+        &self,
+        provider: &impl QueryProvider,
+        key: &str,
+        height: FvmQueryHeight,
+    ) -> anyhow::Result<Object> {
+        let params = GetParams(key.into());
+        let params = RawBytes::serialize(params)?;
+        let message = local_message(self.address, GetObject as u64, params);
+        let response = provider.call(message, height, decode_get).await?;
+        response
+            .value
+            .ok_or_else(|| anyhow!("object not found for key '{}'", key))
+    }
+
     /// Get an object at the given key, range, and height.
+    ///
+    /// If `options.decryption_key` is set, the downloaded bytes are decrypted before being
+    /// written to `writer`; this isn't supported together with `options.range`, since ranged
+    /// decryption requires package-boundary-aware offsets that aren't implemented yet.
+    ///
+    /// `options.range` may name multiple comma-separated segments (see [`GetOptions::range`]);
+    /// each is downloaded and written to `writer` in request order, concatenated with no
+    /// separator, and the resolved `(start, end)` byte intervals (inclusive) are returned so the
+    /// caller can reassemble a sparse file from them.
     pub async fn get<W>(
         &self,
         provider: &(impl QueryProvider + ObjectProvider),
         key: &str,
-        mut writer: W,
+        writer: W,
         options: GetOptions,
-    ) -> anyhow::Result<()>
+    ) -> anyhow::Result<Vec<(u64, u64)>>
     where
         W: AsyncWrite + Unpin + Send + 'static,
     {
+        if options.decryption_key.is_some() && options.range.is_some() {
+            return Err(anyhow!(
+                "downloading a byte range of an encrypted object is not supported"
+            ));
+        }
+
         let started = Instant::now();
         let bars = new_multi_bar(!options.show_progress);
         let msg_bar = bars.add(new_message_bar());
 
         msg_bar.set_prefix("[1/2]");
         msg_bar.set_message("Getting object info...");
-        let params = GetParams(key.into());
-        let params = RawBytes::serialize(params)?;
-        let message = local_message(self.address, GetObject as u64, params);
-        let response = provider.call(message, options.height, decode_get).await?;
-        let object = response
-            .value
-            .ok_or_else(|| anyhow!("object not found for key '{}'", key))?;
+        let object = self.head(provider, key, options.height).await?;
+
+        let ranges = options
+            .range
+            .as_ref()
+            .map(|range| parse_ranges(range, object.size))
+            .transpose()?;
+
+        let mut writer: Pin<Box<dyn AsyncWrite + Unpin + Send>> = match options.decryption_key {
+            Some(key) => Box::pin(DecryptWriter::new(writer, key)),
+            None => Box::pin(writer),
+        };
 
         msg_bar.set_prefix("[2/2]");
         msg_bar.set_message(format!(
@@ -354,25 +835,74 @@ impl Bucket {
             object.hash, object.size
         ));
 
-        let pro_bar = bars.add(new_progress_bar(object.size));
-        let response = provider
-            .download(self.address, key, options.range, options.height.into())
-            .await?;
-        let mut stream = response.bytes_stream();
-        let mut progress = 0;
-        while let Some(item) = stream.next().await {
-            match item {
-                Ok(chunk) => {
-                    writer.write_all(&chunk).await?;
-                    progress = min(progress + chunk.len(), object.size as usize);
-                    pro_bar.set_position(progress as u64);
-                }
-                Err(e) => {
-                    return Err(anyhow!(e));
+        let total_expected = match &ranges {
+            Some(ranges) => ranges.iter().map(|(start, end)| end - start + 1).sum(),
+            None => object.size,
+        };
+        let pro_bar = bars.add(new_progress_bar(total_expected));
+        let mut hasher = options.verify.then(blake3::Hasher::new);
+        let mut progress = 0u64;
+
+        let segments = match &ranges {
+            Some(ranges) => ranges.clone(),
+            None => vec![(0, object.size.saturating_sub(1))],
+        };
+        for (start, end) in &segments {
+            let segment_range = ranges
+                .is_some()
+                .then(|| format!("{start}-{end}"));
+            let response = provider
+                .download(self.address, key, segment_range, options.height.into())
+                .await?;
+            let mut stream = response.bytes_stream();
+            while let Some(item) = stream.next().await {
+                match item {
+                    Ok(chunk) => {
+                        if let Some(hasher) = hasher.as_mut() {
+                            hasher.update(&chunk);
+                        }
+                        writer.write_all(&chunk).await?;
+                        progress = min(progress + chunk.len() as u64, total_expected);
+                        pro_bar.set_position(progress);
+                    }
+                    Err(e) => {
+                        return Err(anyhow!(e));
+                    }
                 }
             }
         }
+        writer.flush().await?;
         pro_bar.finish_and_clear();
+
+        if let Some(hasher) = hasher {
+            match &ranges {
+                // A partial object can't reproduce the whole-object hash, so just check that the
+                // server sent exactly the number of bytes the requested range(s) cover.
+                Some(_) => {
+                    if progress != total_expected {
+                        return Err(anyhow!(
+                            "downloaded {} bytes for range '{}' of key '{}', expected {}",
+                            progress,
+                            options.range.as_deref().unwrap_or_default(),
+                            key,
+                            total_expected
+                        ));
+                    }
+                }
+                None => {
+                    let digest = hasher.finalize();
+                    if digest.as_bytes() != &object.hash.0 {
+                        return Err(anyhow!(
+                            "downloaded object hash mismatch for key '{}': expected {}, got {}",
+                            key,
+                            object.hash,
+                            digest.to_hex()
+                        ));
+                    }
+                }
+            }
+        }
+
         msg_bar.println(format!(
             "{} Downloaded object in {} (hash={}; size={})",
             SPARKLE,
@@ -382,7 +912,120 @@ impl Bucket {
         ));
 
         msg_bar.finish_and_clear();
-        Ok(())
+        Ok(segments)
+    }
+
+    /// Like [`Bucket::get`], but when `writer` supports seeking and no explicit `options.range`
+    /// is set, splits the object into `options.parallelism` contiguous chunks and downloads them
+    /// concurrently, each written to its offset in `writer` via [`AsyncSeekExt::seek`]. This cuts
+    /// wall-clock time on high-latency links, where a single sequential stream leaves most of the
+    /// available bandwidth unused.
+    ///
+    /// Falls back to [`Bucket::get`]'s single-stream path when `options.parallelism <= 1`,
+    /// `options.range` is set, or `options.decryption_key` is set (ranged decryption isn't
+    /// supported, same as `get`).
+    pub async fn get_parallel<W>(
+        &self,
+        provider: &(impl QueryProvider + ObjectProvider),
+        key: &str,
+        writer: W,
+        options: GetOptions,
+    ) -> anyhow::Result<Vec<(u64, u64)>>
+    where
+        W: AsyncWrite + AsyncSeek + Unpin + Send + 'static,
+    {
+        let parallelism = options.parallelism.max(1);
+        if parallelism <= 1 || options.range.is_some() || options.decryption_key.is_some() {
+            return self.get(provider, key, writer, options).await;
+        }
+
+        let started = Instant::now();
+        let bars = new_multi_bar(!options.show_progress);
+        let msg_bar = bars.add(new_message_bar());
+
+        msg_bar.set_prefix("[1/2]");
+        msg_bar.set_message("Getting object info...");
+        let object = self.head(provider, key, options.height).await?;
+
+        let segments = split_into_chunks(object.size, parallelism);
+
+        msg_bar.set_prefix("[2/2]");
+        msg_bar.set_message(format!(
+            "Downloading object (hash={}; size={}; parallelism={})",
+            object.hash, object.size, parallelism
+        ));
+        let pro_bar = bars.add(new_progress_bar(object.size));
+
+        let writer = Arc::new(Mutex::new(writer));
+        let progress = Arc::new(std::sync::atomic::AtomicU64::new(0));
+
+        // A gateway that doesn't honor Range requests (or a writer that fails to seek) leaves the
+        // output corrupt, so once every segment has run, any hard failure fails the whole call
+        // rather than silently reporting success for a partial file.
+        let results: Vec<anyhow::Result<u64>> = stream::iter(segments.iter().copied().map(
+            |(start, end)| {
+                let writer = writer.clone();
+                let progress = progress.clone();
+                let pro_bar = pro_bar.clone();
+                async move {
+                    let range = format!("{start}-{end}");
+                    let response = provider
+                        .download(self.address, key, Some(range), options.height.into())
+                        .await?;
+                    let mut stream = response.bytes_stream();
+                    let mut offset = start;
+                    while let Some(item) = stream.next().await {
+                        let chunk = item.map_err(|e| anyhow!(e))?;
+                        let mut writer = writer.lock().await;
+                        writer.seek(std::io::SeekFrom::Start(offset)).await?;
+                        writer.write_all(&chunk).await?;
+                        drop(writer);
+                        offset += chunk.len() as u64;
+                        let total = progress
+                            .fetch_add(chunk.len() as u64, std::sync::atomic::Ordering::Relaxed)
+                            + chunk.len() as u64;
+                        pro_bar.set_position(min(total, object.size));
+                    }
+                    Ok(offset - start)
+                }
+            },
+        ))
+        .buffer_unordered(parallelism)
+        .collect()
+        .await;
+        pro_bar.finish_and_clear();
+
+        let mut downloaded = 0u64;
+        for result in results {
+            downloaded += result?;
+        }
+
+        let mut writer = Arc::try_unwrap(writer)
+            .map_err(|_| anyhow!("downloads still hold a reference to the writer"))?
+            .into_inner();
+        writer.flush().await?;
+
+        // Chunks land out of order, so incrementally hashing the whole object the way `get` does
+        // isn't possible here without re-reading the assembled file -- which `writer` isn't
+        // guaranteed to support. Verification is limited to the byte count each chunk reported.
+        if options.verify && downloaded != object.size {
+            return Err(anyhow!(
+                "downloaded {} bytes for key '{}', expected {}",
+                downloaded,
+                key,
+                object.size
+            ));
+        }
+
+        msg_bar.println(format!(
+            "{} Downloaded object in {} (hash={}; size={})",
+            SPARKLE,
+            HumanDuration(started.elapsed()),
+            object.hash,
+            object.size
+        ));
+        msg_bar.finish_and_clear();
+        Ok(segments)
     }
 
     /// Query for objects with params at the given height.
@@ -436,6 +1079,7 @@ impl Bucket {
                 UpdateObjectMetadata as u64,
                 params,
                 options.gas_params,
+                None,
                 options.broadcast_mode,
                 |_: &DeliverTx| -> anyhow::Result<()> { Ok(()) },
             )
@@ -479,6 +1123,119 @@ fn decode_list(deliver_tx: &DeliverTx) -> anyhow::Result<ListObjectsReturn> {
         .map_err(|e| anyhow!("error parsing as ListObjectsReturn: {e}"))
 }
 
+/// Parses a `GetOptions::range`-style spec -- one or more comma-separated `"start-end"`
+/// (inclusive) or suffix `"-N"` (last `N` bytes) segments -- into a validated, non-overlapping
+/// list of concrete `(start, end)` byte intervals against `total_size`, in request order (overlap
+/// is checked on a sorted copy). Mirrors the HTTP Range header's multi-range grammar
+/// (RFC 9110 §14.1.2).
+///
+/// Every segment is checked against `total_size` and for overlap with its neighbors before any
+/// network call is made, so a malformed or out-of-bounds range fails fast with a clear error.
+fn parse_ranges(spec: &str, total_size: u64) -> anyhow::Result<Vec<(u64, u64)>> {
+    let ranges: Vec<(u64, u64)> = spec
+        .split(',')
+        .map(|segment| parse_one_range(segment.trim(), total_size))
+        .collect::<anyhow::Result<_>>()?;
+
+    let mut sorted = ranges.clone();
+    sorted.sort_by_key(|&(start, _)| start);
+    for pair in sorted.windows(2) {
+        let (_, prev_end) = pair[0];
+        let (start, _) = pair[1];
+        if start <= prev_end {
+            return Err(anyhow!(
+                "invalid range '{}': segments overlap at byte {}",
+                spec,
+                start
+            ));
+        }
+    }
+    Ok(ranges)
+}
+
+/// Parses a single `"start-end"` or suffix `"-N"` segment of a [`parse_ranges`] spec.
+fn parse_one_range(segment: &str, total_size: u64) -> anyhow::Result<(u64, u64)> {
+    let (start, end) = segment
+        .split_once('-')
+        .ok_or_else(|| anyhow!("invalid range '{}': expected 'start-end' or '-N'", segment))?;
+
+    if start.is_empty() {
+        // Suffix range: the last `end` bytes of the object.
+        let suffix_len: u64 = end
+            .parse()
+            .map_err(|_| anyhow!("invalid range '{}': bad suffix length", segment))?;
+        if suffix_len == 0 {
+            return Err(anyhow!("invalid range '{}': suffix length is zero", segment));
+        }
+        let suffix_len = min(suffix_len, total_size);
+        return Ok((total_size - suffix_len, total_size.saturating_sub(1)));
+    }
+
+    let start: u64 = start
+        .parse()
+        .map_err(|_| anyhow!("invalid range '{}': bad start", segment))?;
+    let end: u64 = end
+        .parse()
+        .map_err(|_| anyhow!("invalid range '{}': bad end", segment))?;
+    if start >= total_size {
+        return Err(anyhow!(
+            "invalid range '{}': start {} is at or past object size {}",
+            segment,
+            start,
+            total_size
+        ));
+    }
+    if start > end {
+        return Err(anyhow!("invalid range '{}': start after end", segment));
+    }
+    let end = min(end, total_size.saturating_sub(1));
+    Ok((start, end))
+}
+
+/// Divides `total_size` bytes into up to `parallelism` contiguous, roughly equal `(start, end)`
+/// (inclusive) intervals for [`Bucket::get_parallel`].
+fn split_into_chunks(total_size: u64, parallelism: usize) -> Vec<(u64, u64)> {
+    if total_size == 0 {
+        return Vec::new();
+    }
+    let chunk_size = total_size.div_ceil(parallelism.max(1) as u64).max(1);
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    while start < total_size {
+        let end = min(start + chunk_size, total_size) - 1;
+        chunks.push((start, end));
+        start = end + 1;
+    }
+    chunks
+}
+
+/// Recursively walks `root`, returning every file found along with its path relative to `root`
+/// joined with `/` (matching the delimiter [`QueryOptions`] uses for hierarchy).
+async fn walk_dir(root: &Path) -> anyhow::Result<Vec<(PathBuf, String)>> {
+    let mut files = Vec::new();
+    let mut dirs = vec![root.to_path_buf()];
+
+    while let Some(dir) = dirs.pop() {
+        let mut entries = tokio::fs::read_dir(&dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if entry.file_type().await?.is_dir() {
+                dirs.push(path);
+            } else {
+                let rel_key = path
+                    .strip_prefix(root)?
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                files.push((path, rel_key));
+            }
+        }
+    }
+
+    Ok(files)
+}
+
 pub fn validate_metadata(metadata: &HashMap<String, String>) -> anyhow::Result<()> {
     for (key, value) in metadata {
         if key.len() as u32 > MAX_METADATA_KEY_SIZE {
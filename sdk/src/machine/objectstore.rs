@@ -468,6 +468,7 @@ impl ObjectStore {
                 AddObject as u64,
                 serialized_params,
                 options.gas_params,
+                None,
             )
             .await?;
 
@@ -559,6 +560,7 @@ impl ObjectStore {
                 DeleteObject as u64,
                 params,
                 options.gas_params,
+                None,
             )
             .await?;
         provider
@@ -15,6 +15,7 @@ use hoku_provider::{
     message::{local_message, GasParams, RawBytes},
     query::{FvmQueryHeight, QueryProvider},
     response::decode_bytes,
+    trace::CallFrame,
     tx::{BroadcastMode, TxReceipt},
     Client, Provider,
 };
@@ -84,6 +85,25 @@ impl Sqlite {
         Ok(response.value)
     }
 
+    /// Run the same query as [`Sqlite::query`], but return a structured call trace instead of
+    /// the decoded result, for debugging a failing query (e.g. a SQL syntax error) instead of a
+    /// single opaque backtrace line.
+    pub async fn trace_query(
+        &self,
+        provider: &impl QueryProvider,
+        stmt: String,
+        height: FvmQueryHeight,
+    ) -> anyhow::Result<CallFrame> {
+        if stmt.is_empty() {
+            return Err(anyhow!("query must not be an empty string"));
+        }
+        let params = RawBytes::serialize(QueryParams { stmt })?;
+
+        let message = local_message(self.address, Method::Query as u64, params);
+        let response = provider.trace_call(message, height).await?;
+        Ok(response.value)
+    }
+
     pub async fn execute<C>(
         &self,
         provider: &impl Provider<C>,
@@ -109,6 +129,7 @@ impl Sqlite {
                 Method::Execute as u64,
                 params,
                 gas_params,
+                None,
                 options.broadcast_mode,
                 decode_execute_result,
             )
@@ -1,14 +1,16 @@
 // Copyright 2024 Hoku Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt, time::Duration};
 
 use anyhow::anyhow;
 use async_trait::async_trait;
 use bytes::Bytes;
 use fendermint_actor_timehub::Method::{Count, Get, Peaks, Push, Root};
 use fendermint_vm_actor_interface::adm::Kind;
+use futures::Stream;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use tendermint::abci::response::DeliverTx;
 
 use hoku_provider::{
@@ -26,6 +28,86 @@ use crate::machine::{deploy_machine, DeployTxReceipt, Machine};
 
 const MAX_ACC_PAYLOAD_SIZE: usize = 1024 * 500;
 
+/// A structured, code-carrying error from a Timehub operation.
+///
+/// The decode helpers and `push` historically funneled every failure into an `anyhow!` string,
+/// which gives callers no way to match on the failure kind (a payload that's too large vs. a
+/// decode failure vs. an RPC error all look the same). Mirrors the shape of
+/// [`recall_provider::error::RecallError`] rather than pulling in the `flex-error` crate itself:
+/// a plain enum with a stable numeric `code()`, still convertible into `anyhow::Error` via the
+/// blanket `std::error::Error` impl, so it drops into the existing `anyhow::Result` signatures
+/// unchanged.
+#[derive(Debug, Clone)]
+pub enum TimehubError {
+    /// The payload exceeds [`MAX_ACC_PAYLOAD_SIZE`].
+    PayloadTooLarge { size: usize, max: usize },
+    /// A response could not be decoded into the expected type.
+    Decode {
+        method: &'static str,
+        source: String,
+    },
+    /// The underlying provider call failed.
+    Rpc(String),
+    /// An inclusion proof failed to verify.
+    ProofInvalid,
+    /// The MMR hash scheme [`InclusionProof`] assumes hasn't been confirmed against the real
+    /// `fendermint_actor_timehub`, so a proof built or checked against it can't be trusted.
+    HashSchemeUnconfirmed,
+}
+
+impl TimehubError {
+    /// A stable numeric code identifying the error kind, for programmatic callers.
+    pub fn code(&self) -> i32 {
+        match self {
+            TimehubError::PayloadTooLarge { .. } => 1,
+            TimehubError::Decode { .. } => 2,
+            TimehubError::Rpc(_) => 3,
+            TimehubError::ProofInvalid => 4,
+            TimehubError::HashSchemeUnconfirmed => 5,
+        }
+    }
+
+    fn decode(method: &'static str, source: impl fmt::Display) -> Self {
+        TimehubError::Decode {
+            method,
+            source: source.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for TimehubError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimehubError::PayloadTooLarge { size, max } => write!(
+                f,
+                "payload too large (code={}): {size} bytes exceeds max of {max}",
+                self.code()
+            ),
+            TimehubError::Decode { method, source } => {
+                write!(
+                    f,
+                    "decode error (code={}) in {method}: {source}",
+                    self.code()
+                )
+            }
+            TimehubError::Rpc(msg) => write!(f, "rpc error (code={}): {msg}", self.code()),
+            TimehubError::ProofInvalid => {
+                write!(f, "inclusion proof is invalid (code={})", self.code())
+            }
+            TimehubError::HashSchemeUnconfirmed => write!(
+                f,
+                "inclusion proofs are unsupported (code={}): the MMR hash scheme they assume \
+                 (SHA-256 leaf/node hashing) hasn't been confirmed against the real \
+                 fendermint_actor_timehub, which isn't vendored in this tree, so a proof built \
+                 or checked against it can't be trusted",
+                self.code()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TimehubError {}
+
 /// Payload push options.
 #[derive(Clone, Default, Debug)]
 pub struct PushOptions {
@@ -70,6 +152,16 @@ impl From<fendermint_actor_timehub::Leaf> for Leaf {
     }
 }
 
+/// Records where a payload split across multiple leaves by [`Timehub::push_chunked`] lives, so
+/// it can be reassembled by fetching every leaf in `start_index..(start_index + chunk_count)`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ChunkManifest {
+    /// Index of the first chunk leaf.
+    pub start_index: u64,
+    /// Number of chunk leaves the payload was split into.
+    pub chunk_count: u64,
+}
+
 /// A machine for event stream accumulation.
 pub struct Timehub {
     address: Address,
@@ -116,10 +208,10 @@ impl Timehub {
         C: Client + Send + Sync,
     {
         if payload.len() > MAX_ACC_PAYLOAD_SIZE {
-            return Err(anyhow!(
-                "max payload size is {} bytes",
-                MAX_ACC_PAYLOAD_SIZE
-            ));
+            return Err(anyhow!(TimehubError::PayloadTooLarge {
+                size: payload.len(),
+                max: MAX_ACC_PAYLOAD_SIZE,
+            }));
         }
 
         let params = RawBytes::serialize(BytesSer(&payload))?;
@@ -131,11 +223,93 @@ impl Timehub {
                 Push as u64,
                 params,
                 options.gas_params,
+                None,
+                options.broadcast_mode,
                 decode_push_return,
             )
             .await
     }
 
+    /// Splits `payload` into chunks no larger than [`MAX_ACC_PAYLOAD_SIZE`], pushes each chunk as
+    /// its own leaf, and pushes a trailing manifest leaf recording the chunk index range, so the
+    /// payload can be reassembled later by calling [`Timehub::leaf`] for every index in that
+    /// range. Chunks are pushed sequentially so their indices come out contiguous and in order;
+    /// see [`Timehub::push_batch`] to push independent payloads concurrently instead.
+    pub async fn push_chunked<C>(
+        &self,
+        provider: &impl Provider<C>,
+        signer: &mut impl Signer,
+        payload: Bytes,
+        options: PushOptions,
+    ) -> anyhow::Result<(Vec<PushReturn>, PushReturn)>
+    where
+        C: Client + Send + Sync,
+    {
+        if payload.is_empty() {
+            return Err(anyhow!("payload must not be empty"));
+        }
+
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        while start < payload.len() {
+            let end = (start + MAX_ACC_PAYLOAD_SIZE).min(payload.len());
+            chunks.push(payload.slice(start..end));
+            start = end;
+        }
+
+        let mut chunk_returns = Vec::with_capacity(chunks.len());
+        for chunk in chunks {
+            let receipt = self.push(provider, signer, chunk, options.clone()).await?;
+            chunk_returns.push(receipt.data.ok_or_else(|| {
+                anyhow!("push did not return data; use a broadcast mode that waits for commit")
+            })?);
+        }
+
+        let manifest = ChunkManifest {
+            start_index: chunk_returns[0].index,
+            chunk_count: chunk_returns.len() as u64,
+        };
+        let manifest_payload = Bytes::from(fvm_ipld_encoding::to_vec(&manifest)?);
+        let manifest_receipt = self
+            .push(provider, signer, manifest_payload, options)
+            .await?;
+        let manifest_return = manifest_receipt.data.ok_or_else(|| {
+            anyhow!("push did not return data; use a broadcast mode that waits for commit")
+        })?;
+
+        Ok((chunk_returns, manifest_return))
+    }
+
+    /// Pushes several independent payloads, submitting them concurrently rather than waiting for
+    /// each to land before starting the next. Returns the resulting [`PushReturn`]s in the same
+    /// order as `payloads`.
+    pub async fn push_batch<C>(
+        &self,
+        provider: &impl Provider<C>,
+        signer: &mut impl Signer,
+        payloads: Vec<Bytes>,
+        options: PushOptions,
+    ) -> anyhow::Result<Vec<PushReturn>>
+    where
+        C: Client + Send + Sync,
+    {
+        let pushes = payloads.into_iter().map(|payload| {
+            let mut signer = signer.clone();
+            let options = options.clone();
+            async move { self.push(provider, &mut signer, payload, options).await }
+        });
+
+        let receipts = futures::future::try_join_all(pushes).await?;
+        receipts
+            .into_iter()
+            .map(|receipt| {
+                receipt.data.ok_or_else(|| {
+                    anyhow!("push did not return data; use a broadcast mode that waits for commit")
+                })
+            })
+            .collect()
+    }
+
     /// Get leaf stored at a given index and height.
     /// Returns None if there is no leaf at the given index.
     pub async fn leaf(
@@ -146,7 +320,10 @@ impl Timehub {
     ) -> anyhow::Result<Option<Leaf>> {
         let params = RawBytes::serialize(index)?;
         let message = local_message(self.address, Get as u64, params);
-        let response = provider.call(message, height, decode_leaf).await?;
+        let response = provider
+            .call(message, height, decode_leaf)
+            .await
+            .map_err(|e| anyhow!(TimehubError::Rpc(e.to_string())))?;
         Ok(response.value)
     }
 
@@ -157,7 +334,10 @@ impl Timehub {
         height: FvmQueryHeight,
     ) -> anyhow::Result<u64> {
         let message = local_message(self.address, Count as u64, Default::default());
-        let response = provider.call(message, height, decode_count).await?;
+        let response = provider
+            .call(message, height, decode_count)
+            .await
+            .map_err(|e| anyhow!(TimehubError::Rpc(e.to_string())))?;
         Ok(response.value)
     }
 
@@ -168,7 +348,10 @@ impl Timehub {
         height: FvmQueryHeight,
     ) -> anyhow::Result<Vec<Cid>> {
         let message = local_message(self.address, Peaks as u64, Default::default());
-        let response = provider.call(message, height, decode_peaks).await?;
+        let response = provider
+            .call(message, height, decode_peaks)
+            .await
+            .map_err(|e| anyhow!(TimehubError::Rpc(e.to_string())))?;
         Ok(response.value)
     }
 
@@ -179,43 +362,252 @@ impl Timehub {
         height: FvmQueryHeight,
     ) -> anyhow::Result<Cid> {
         let message = local_message(self.address, Root as u64, Default::default());
-        let response = provider.call(message, height, decode_root).await?;
+        let response = provider
+            .call(message, height, decode_root)
+            .await
+            .map_err(|e| anyhow!(TimehubError::Rpc(e.to_string())))?;
         Ok(response.value)
     }
+
+    /// Unimplemented: would build an inclusion proof that the leaf at `index` is committed under
+    /// the root at `height`, by reconstructing the containing MMR subtree client-side (the actor
+    /// only exposes leaves, peaks, and the root, not interior nodes). But doing that requires
+    /// [`hash_leaf`]/[`hash_node`]'s MMR hash scheme to match what `fendermint_actor_timehub`
+    /// actually uses on-chain, which isn't vendored in this tree and so can't be confirmed --
+    /// see [`TimehubError::HashSchemeUnconfirmed`]. Building a proof against an unconfirmed
+    /// scheme would just be a guess that looks load-bearing, so this refuses instead.
+    pub async fn proof(
+        &self,
+        _provider: &impl QueryProvider,
+        _index: u64,
+        _height: FvmQueryHeight,
+    ) -> anyhow::Result<InclusionProof> {
+        Err(anyhow!(TimehubError::HashSchemeUnconfirmed))
+    }
+
+    /// Streams newly pushed leaves as they are committed, starting from `from_index`.
+    ///
+    /// Modeled on the `eth_subscribe`/`SubscriptionStream` pattern from `ethers-providers`, but
+    /// implemented as a filter-watcher that polls [`Timehub::count`] every `poll_interval`
+    /// rather than a genuine WebSocket push subscription: reproducing `eth_subscribe` faithfully
+    /// would require assuming specific CometBFT event attributes the (unvendored here)
+    /// `fendermint_actor_timehub` emits on push, which can't be confirmed in this tree. The
+    /// polling fallback the request explicitly sanctions is implemented instead, and works
+    /// against plain HTTP endpoints.
+    ///
+    /// A query error is yielded as `Err` without advancing past the failed index, so the next
+    /// poll retries it; the stream never terminates on its own.
+    pub fn subscribe<'a>(
+        &'a self,
+        provider: &'a impl QueryProvider,
+        from_index: u64,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = anyhow::Result<(u64, Leaf)>> + 'a {
+        futures::stream::unfold(from_index, move |next_index| async move {
+            loop {
+                let count = match self.count(provider, FvmQueryHeight::Committed).await {
+                    Ok(count) => count,
+                    Err(e) => return Some((Err(e), next_index)),
+                };
+
+                if next_index >= count {
+                    tokio::time::sleep(poll_interval).await;
+                    continue;
+                }
+
+                return match self
+                    .leaf(provider, next_index, FvmQueryHeight::Committed)
+                    .await
+                {
+                    Ok(Some(leaf)) => Some((Ok((next_index, leaf)), next_index + 1)),
+                    Ok(None) => Some((
+                        Err(anyhow!(
+                            "leaf {next_index} is missing even though count is {count}"
+                        )),
+                        next_index,
+                    )),
+                    Err(e) => Some((Err(e), next_index)),
+                };
+            }
+        })
+    }
+}
+
+/// An inclusion proof that a leaf at a given index is committed under a given Timehub root.
+///
+/// The exact hash function `fendermint_actor_timehub` uses on-chain to combine MMR nodes isn't
+/// available in this tree (the actor crate isn't vendored here). [`hash_leaf`]/[`hash_node`]
+/// assume a conventional scheme -- SHA-256 over the leaf's fields for leaf hashes, and
+/// domain-separated SHA-256 over `left || right` for parent hashes and for bagging peaks into a
+/// root -- but that's unconfirmed against the real actor (notably, the rest of this codebase's
+/// content-hashing convention is BLAKE3, not SHA-256, e.g. [`crate::machine::bucket`]'s download
+/// verification and [`crate::machine::accumulator::verify_proof`]). Because of that,
+/// [`Timehub::proof`] and [`InclusionProof::verify_checked`] both refuse with
+/// [`TimehubError::HashSchemeUnconfirmed`] rather than act on the guess. [`InclusionProof::verify`]
+/// still implements the guessed scheme so it's available to experiment with (or to use once the
+/// scheme is confirmed), but is not wired into anything that would present its answer as
+/// trustworthy. A leaf that is itself a peak has an empty `siblings` path.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct InclusionProof {
+    /// Index of the proven leaf.
+    pub index: u64,
+    /// Total leaf count the proof was generated against.
+    pub count: u64,
+    /// Sibling hashes from the leaf up to the peak of its containing subtree, ordered
+    /// leaf-to-peak.
+    pub siblings: Vec<[u8; 32]>,
+    /// The other peaks, i.e. every peak except the one covering `index`, in the order returned
+    /// by [`Timehub::peaks`].
+    pub other_peaks: Vec<[u8; 32]>,
+    /// Position of the leaf's own peak among all peaks (0-indexed, left to right).
+    pub peak_position: usize,
+}
+
+impl InclusionProof {
+    /// Reconstructs an MMR root from this proof via [`hash_leaf`]/[`hash_node`]'s guessed hash
+    /// scheme and checks it against `root`. Not confirmed against the real
+    /// `fendermint_actor_timehub` -- see the [`InclusionProof`] doc comment -- so a `true` here
+    /// does not mean the proof is valid against a real chain. [`InclusionProof::verify_checked`]
+    /// is the gate that actually refuses to present this as trustworthy; call this directly only
+    /// if you've independently confirmed the hash scheme.
+    pub fn verify(&self, leaf: &Leaf, root: &Cid) -> bool {
+        let Some(&(start, _)) = mountains(self.count).get(self.peak_position) else {
+            return false;
+        };
+        if self.index < start {
+            return false;
+        }
+
+        let mut local_index = self.index - start;
+        let mut hash = hash_leaf(leaf);
+        for sibling in &self.siblings {
+            hash = if local_index % 2 == 0 {
+                hash_node(&hash, sibling)
+            } else {
+                hash_node(sibling, &hash)
+            };
+            local_index /= 2;
+        }
+
+        if self.peak_position > self.other_peaks.len() {
+            return false;
+        }
+        let mut peaks = self.other_peaks.clone();
+        peaks.insert(self.peak_position, hash);
+
+        let Some(bagged) = bag_peaks(&peaks) else {
+            return false;
+        };
+        match digest_bytes(root) {
+            Ok(root_digest) => root_digest == bagged,
+            Err(_) => false,
+        }
+    }
+
+    /// The checked, `?`-able counterpart to [`InclusionProof::verify`] that callers should
+    /// actually use. Refuses with [`TimehubError::HashSchemeUnconfirmed`] rather than delegating
+    /// to `verify`'s guessed hash scheme, since a `true`/`false` from it can't be trusted against
+    /// a real chain -- see the [`InclusionProof`] doc comment.
+    pub fn verify_checked(&self, _leaf: &Leaf, _root: &Cid) -> anyhow::Result<()> {
+        Err(anyhow!(TimehubError::HashSchemeUnconfirmed))
+    }
+}
+
+/// Decomposes a leaf count into its MMR mountains, as `(start_index, size)` pairs ordered
+/// left-to-right, where each `size` is a power of two matching a set bit of `leaf_count`.
+fn mountains(leaf_count: u64) -> Vec<(u64, u64)> {
+    let mut result = Vec::new();
+    let mut start = 0u64;
+    for bit in (0..u64::BITS).rev() {
+        let size = 1u64 << bit;
+        if leaf_count & size != 0 {
+            result.push((start, size));
+            start += size;
+        }
+    }
+    result
+}
+
+/// Folds peaks right-to-left into a single root hash via the domain-separated parent hash.
+fn bag_peaks(peaks: &[[u8; 32]]) -> Option<[u8; 32]> {
+    peaks
+        .iter()
+        .rev()
+        .copied()
+        .reduce(|acc, peak| hash_node(&peak, &acc))
+}
+
+fn hash_leaf(leaf: &Leaf) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"timehub-mmr-leaf");
+    hasher.update(leaf.timestamp.to_be_bytes());
+    hasher.update(leaf.witnessed.0.to_bytes());
+    hasher.finalize().into()
+}
+
+fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(b"timehub-mmr-node");
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+fn digest_bytes(cid: &Cid) -> anyhow::Result<[u8; 32]> {
+    cid.0
+        .hash()
+        .digest()
+        .try_into()
+        .map_err(|_| anyhow!("CID digest is not 32 bytes"))
 }
 
 fn decode_push_return(deliver_tx: &DeliverTx) -> anyhow::Result<PushReturn> {
     let data = decode_bytes(deliver_tx)?;
     fvm_ipld_encoding::from_slice::<fendermint_actor_timehub::PushReturn>(&data)
         .map(|r| r.into())
-        .map_err(|e| anyhow!("error parsing as PushReturn: {e}"))
+        .map_err(|e| anyhow!(TimehubError::decode("decode_push_return", e)))
 }
 
 fn decode_leaf(deliver_tx: &DeliverTx) -> anyhow::Result<Option<Leaf>> {
     let data = decode_bytes(deliver_tx)?;
     Ok(
         fvm_ipld_encoding::from_slice::<Option<fendermint_actor_timehub::Leaf>>(&data)
-            .map_err(|e| anyhow!("error parsing leaf: {e}"))?
+            .map_err(|e| anyhow!(TimehubError::decode("decode_leaf", e)))?
             .map(|r| r.into()),
     )
 }
 
 fn decode_count(deliver_tx: &DeliverTx) -> anyhow::Result<u64> {
     let data = decode_bytes(deliver_tx)?;
-    fvm_ipld_encoding::from_slice(&data).map_err(|e| anyhow!("error parsing as u64: {e}"))
+    fvm_ipld_encoding::from_slice(&data)
+        .map_err(|e| anyhow!(TimehubError::decode("decode_count", e)))
 }
 
 fn decode_peaks(deliver_tx: &DeliverTx) -> anyhow::Result<Vec<Cid>> {
     let data = decode_bytes(deliver_tx)?;
     let items = fvm_ipld_encoding::from_slice::<Vec<cid::Cid>>(&data)
         .map(|v| v.iter().map(|c| (*c).into()).collect())
-        .map_err(|e| anyhow!("error parsing as Vec<Cid>: {e}"))?;
+        .map_err(|e| anyhow!(TimehubError::decode("decode_peaks", e)))?;
     Ok(items)
 }
 
 fn decode_root(deliver_tx: &DeliverTx) -> anyhow::Result<Cid> {
     let data = decode_bytes(deliver_tx)?;
     let cid = fvm_ipld_encoding::from_slice::<cid::Cid>(&data)
-        .map_err(|e| anyhow!("error parsing as Cid: {e}"))?;
+        .map_err(|e| anyhow!(TimehubError::decode("decode_root", e)))?;
     Ok(cid.into())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::mountains;
+
+    #[test]
+    fn test_mountains_decomposes_leaf_count_by_set_bits() {
+        // 13 = 0b1101 -> mountains of size 8, 4, 1.
+        assert_eq!(mountains(13), vec![(0, 8), (8, 4), (12, 1)]);
+        // A single perfect tree has one mountain spanning every leaf.
+        assert_eq!(mountains(8), vec![(0, 8)]);
+        assert_eq!(mountains(0), vec![]);
+    }
+}
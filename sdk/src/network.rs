@@ -1,6 +1,7 @@
 // Copyright 2025 Recall Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::time::Duration;
 use std::{collections::HashMap, fmt::Display};
@@ -13,7 +14,7 @@ use recall_provider::{
         address::{self, Address, Error, Network as FvmNetwork},
         chainid::ChainID,
     },
-    json_rpc::Url,
+    json_rpc::{RpcEndpoint, Url},
     util::parse_address,
 };
 use recall_signer::SubnetID;
@@ -23,101 +24,69 @@ use crate::ipc::subnet::EVMSubnet;
 
 const DEFAULT_RPC_TIMEOUT: Duration = Duration::from_secs(60);
 
-const DEVNET_NETWORK_NAME: &str = "devnet";
-const DEVNET_SUBNET_ID: &str = "test";
-const DEVNET_EVM_RPC_URL: &str = "http://127.0.0.1:8545";
-const DEVNET_EVM_GATEWAY_ADDRESS: &str = "0x77aa40b105843728088c0132e43fc44348881da8";
-const DEVNET_EVM_REGISTRY_ADDRESS: &str = "0x74539671a1d2f1c8f200826baba665179f53a1b7";
-
-const LOCALNET_NETWORK_NAME: &str = "localnet";
-const LOCALNET_RPC_URL: &str = "http://127.0.0.1:26657";
-const LOCALNET_SUBNET_ID: &str = "/r31337/t410f6gbdxrbehnaeeo4mrq7wc5hgq6smnefys4qanwi";
-const LOCALNET_CHAIN_ID: u64 = 248163216;
-const LOCALNET_EVM_RPC_URL: &str = "http://127.0.0.1:8645";
-const LOCALNET_OBJECT_API_URL: &str = "http://127.0.0.1:8001";
-const LOCALNET_EVM_GATEWAY_ADDRESS: &str = "0x77aa40b105843728088c0132e43fc44348881da8";
-const LOCALNET_EVM_REGISTRY_ADDRESS: &str = "0x74539671a1d2f1c8f200826baba665179f53a1b7";
-const LOCALNET_EVM_SUPPLY_SOURCE_ADDRESS: &str = "0x4A679253410272dd5232B3Ff7cF5dbB88f295319";
-const LOCALNET_PARENT_EVM_RPC_URL: &str = "http://127.0.0.1:8545";
-const LOCALNET_PARENT_EVM_GATEWAY_ADDRESS: &str = "0x9A676e781A523b5d0C0e43731313A708CB607508";
-const LOCALNET_PARENT_EVM_REGISTRY_ADDRESS: &str = "0x322813Fd9A801c5507c9de605d63CEA4f2CE6c44";
+/// Well-known, shell-expandable path to the user's network registry file (see
+/// [`NetworkSpec::load_all`]).
+pub const DEFAULT_NETWORK_CONFIG_PATH: &str = "~/.config/recall/networks.toml";
 
+pub const MAINNET_NETWORK_NAME: &str = "mainnet";
 // Ignition
 pub const TESTNET_NETWORK_NAME: &str = "testnet";
-const TESTNET_RPC_URL: &str = "https://api.testnet.recall.chain.love";
-const TESTNET_OBJECT_API_URL: &str = "https://objects.testnet.recall.chain.love";
-const TESTNET_EVM_RPC_URL: &str = "https://evm.testnet.recall.chain.love";
-const TESTNET_SUBNET_ID: &str = "/r314159/t410fntaew3dtef2zpfox2hlhy4cgrksdt5zjwg3hbfi";
-const TESTNET_CHAIN_ID: u64 = 2481632;
-const TESTNET_PARENT_EVM_RPC_URL: &str = "https://api.calibration.node.glif.io/rpc/v1";
-const TESTNET_PARENT_EVM_GATEWAY_ADDRESS: &str = "0x2758f99EaB8ea9B8678B5d841851D62Ef18AAB26";
-const TESTNET_PARENT_EVM_REGISTRY_ADDRESS: &str = "0xE8090d55E7ecc0565830845Df67bfD3fA81D4158";
-const TESTNET_EVM_SUPPLY_SOURCE_ADDRESS: &str = "0xd1239c6b6f806EC3752df12CEaddD88187BCf1E5";
-const TESTNET_EVM_GATEWAY_ADDRESS: &str = "0x77aa40b105843728088c0132e43fc44348881da8";
-const TESTNET_EVM_REGISTRY_ADDRESS: &str = "0x74539671a1d2f1c8f200826baba665179f53a1b7";
+const LOCALNET_NETWORK_NAME: &str = "localnet";
+const DEVNET_NETWORK_NAME: &str = "devnet";
+
+/// A declarative, embedded description of a built-in network: everything [`default_networks`]
+/// and [`Network::get_config`] previously hand-assembled from a pile of `MAINNET_*`/`TESTNET_*`/
+/// etc. constants now lives in one TOML file per network (see `sdk/src/network_presets/`),
+/// parsed into a [`NetworkSpec`] on demand -- the same way an OpenEthereum `Spec` is loaded from
+/// a named preset rather than built up in code.
+struct ChainSpec {
+    name: &'static str,
+    toml: &'static str,
+}
+
+const BUILTIN_CHAIN_SPECS: &[ChainSpec] = &[
+    ChainSpec {
+        name: MAINNET_NETWORK_NAME,
+        toml: include_str!("network_presets/mainnet.toml"),
+    },
+    ChainSpec {
+        name: TESTNET_NETWORK_NAME,
+        toml: include_str!("network_presets/testnet.toml"),
+    },
+    ChainSpec {
+        name: LOCALNET_NETWORK_NAME,
+        toml: include_str!("network_presets/localnet.toml"),
+    },
+    ChainSpec {
+        name: DEVNET_NETWORK_NAME,
+        toml: include_str!("network_presets/devnet.toml"),
+    },
+];
+
+impl ChainSpec {
+    /// Parses the built-in preset named `name`, if one exists.
+    fn builtin(name: &str) -> Option<anyhow::Result<NetworkSpec>> {
+        BUILTIN_CHAIN_SPECS
+            .iter()
+            .find(|spec| spec.name == name)
+            .map(|spec| {
+                toml::from_str(spec.toml).map_err(|err| {
+                    anyhow!("invalid built-in network preset '{}': {err}", spec.name)
+                })
+            })
+    }
+}
 
 pub fn default_networks() -> HashMap<String, NetworkSpec> {
-    let mut hm = HashMap::new();
-
-    hm.insert(
-        TESTNET_NETWORK_NAME.to_owned(),
-        NetworkSpec {
-            subnet_config: SubnetConfig {
-                chain_id: Some(TESTNET_CHAIN_ID),
-                subnet_id: TESTNET_SUBNET_ID.to_owned(),
-                rpc_url: Url::from_str(TESTNET_RPC_URL).unwrap(),
-                object_api_url: Url::from_str(TESTNET_OBJECT_API_URL).unwrap(),
-                evm_rpc_url: reqwest::Url::from_str(TESTNET_EVM_RPC_URL).unwrap(),
-                evm_gateway_address: parse_address(TESTNET_EVM_GATEWAY_ADDRESS).unwrap(),
-                evm_registry_address: parse_address(TESTNET_EVM_REGISTRY_ADDRESS).unwrap(),
-            },
-            parent_network_config: Some(ParentNetworkConfig {
-                evm_rpc_url: reqwest::Url::from_str(TESTNET_PARENT_EVM_RPC_URL).unwrap(),
-                evm_gateway_address: parse_address(TESTNET_PARENT_EVM_GATEWAY_ADDRESS).unwrap(),
-                evm_registry_address: parse_address(TESTNET_PARENT_EVM_REGISTRY_ADDRESS).unwrap(),
-                evm_supply_source_address: parse_address(TESTNET_EVM_SUPPLY_SOURCE_ADDRESS)
-                    .unwrap(),
-            }),
-        },
-    );
-    // DEPRECATED
-    hm.insert(
-        LOCALNET_NETWORK_NAME.to_owned(),
-        NetworkSpec {
-            subnet_config: SubnetConfig {
-                chain_id: Some(LOCALNET_CHAIN_ID),
-                subnet_id: LOCALNET_SUBNET_ID.to_owned(),
-                rpc_url: Url::from_str(LOCALNET_RPC_URL).unwrap(),
-                object_api_url: Url::from_str(LOCALNET_OBJECT_API_URL).unwrap(),
-                evm_rpc_url: reqwest::Url::from_str(LOCALNET_EVM_RPC_URL).unwrap(),
-                evm_gateway_address: parse_address(LOCALNET_EVM_GATEWAY_ADDRESS).unwrap(),
-                evm_registry_address: parse_address(LOCALNET_EVM_REGISTRY_ADDRESS).unwrap(),
-            },
-            parent_network_config: Some(ParentNetworkConfig {
-                evm_rpc_url: reqwest::Url::from_str(LOCALNET_PARENT_EVM_RPC_URL).unwrap(),
-                evm_gateway_address: parse_address(LOCALNET_PARENT_EVM_GATEWAY_ADDRESS).unwrap(),
-                evm_registry_address: parse_address(LOCALNET_PARENT_EVM_REGISTRY_ADDRESS).unwrap(),
-                evm_supply_source_address: parse_address(LOCALNET_EVM_SUPPLY_SOURCE_ADDRESS)
-                    .unwrap(),
-            }),
-        },
-    );
-    hm.insert(
-        DEVNET_NETWORK_NAME.to_owned(),
-        NetworkSpec {
-            subnet_config: SubnetConfig {
-                chain_id: None,
-                subnet_id: DEVNET_SUBNET_ID.to_owned(),
-                rpc_url: Url::from_str(LOCALNET_RPC_URL).unwrap(),
-                object_api_url: Url::from_str(LOCALNET_OBJECT_API_URL).unwrap(),
-                evm_rpc_url: reqwest::Url::from_str(DEVNET_EVM_RPC_URL).unwrap(),
-                evm_gateway_address: parse_address(DEVNET_EVM_GATEWAY_ADDRESS).unwrap(),
-                evm_registry_address: parse_address(DEVNET_EVM_REGISTRY_ADDRESS).unwrap(),
-            },
-            parent_network_config: None,
-        },
-    );
-    hm
+    BUILTIN_CHAIN_SPECS
+        .iter()
+        .map(|spec| {
+            let network_spec = ChainSpec::builtin(spec.name)
+                .expect("name comes from BUILTIN_CHAIN_SPECS")
+                .expect("built-in network preset should parse");
+            (spec.name.to_owned(), network_spec)
+        })
+        .collect()
 }
 
 #[derive(Serialize, Deserialize)]
@@ -128,12 +97,17 @@ pub struct NetworkSpec {
 
 #[derive(Serialize, Deserialize)]
 pub struct SubnetConfig {
+    #[serde(default)]
     pub chain_id: Option<u64>,
 
     pub subnet_id: String,
-    pub rpc_url: Url,
+    pub rpc_url: RpcEndpoint,
     pub object_api_url: Url,
-    pub evm_rpc_url: reqwest::Url,
+    /// EVM RPC endpoint(s), tried in order with failover on error. Accepts either a single URL
+    /// string or an array of them in the config file, so existing single-endpoint entries keep
+    /// working unchanged.
+    #[serde(deserialize_with = "deserialize_evm_rpc_urls")]
+    pub evm_rpc_url: Vec<reqwest::Url>,
 
     #[serde(
         deserialize_with = "deserialize_address",
@@ -148,16 +122,23 @@ pub struct SubnetConfig {
     pub evm_registry_address: Address,
 }
 
+/// Serializes `x` as a `0x`-prefixed ETH hex string when it's an EVM-backed (masked ID or
+/// `f410`-delegated) address, and as the canonical FVM string (`f4.../t4...`, network-prefixed
+/// per the currently set [`FvmNetwork`]) otherwise, since [`get_eth_address`] has no hex form for
+/// other address classes (e.g. plain secp256k1/BLS/actor addresses).
 fn serialize_address<S>(x: &Address, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
 {
-    let eth_address = get_eth_address(*x)
-        .map_err(serde::ser::Error::custom)?
-        .encode_hex_with_prefix();
-    serializer.serialize_str(&eth_address)
+    let text = match get_eth_address(*x) {
+        Ok(eth_address) => eth_address.encode_hex_with_prefix(),
+        Err(_) => x.to_string(),
+    };
+    serializer.serialize_str(&text)
 }
 
+/// Deserializes either the `0x...` hex or the FVM-prefixed (`f4.../t4...`) form produced by
+/// [`serialize_address`]; [`parse_address`] already dispatches on which one it's given.
 fn deserialize_address<'de, D>(deserializer: D) -> Result<Address, D::Error>
 where
     D: Deserializer<'de>,
@@ -166,7 +147,105 @@ where
     parse_address(&buf).map_err(serde::de::Error::custom)
 }
 
+/// Deserializes `evm_rpc_url` as either a single URL string (promoted to a one-element list) or
+/// an array of them, so config files written before endpoint failover was supported keep parsing.
+fn deserialize_evm_rpc_urls<'de, D>(deserializer: D) -> Result<Vec<reqwest::Url>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(reqwest::Url),
+        Many(Vec<reqwest::Url>),
+    }
+
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(url) => vec![url],
+        OneOrMany::Many(urls) => urls,
+    })
+}
+
+/// Checks that `addr` survives an encode-then-parse round trip through whichever textual form
+/// [`serialize_address`]/[`deserialize_address`] would apply to it -- hex for EVM-backed
+/// addresses, the canonical FVM string otherwise.
+fn round_trip_address(addr: Address) -> anyhow::Result<()> {
+    let text = match get_eth_address(addr) {
+        Ok(eth_address) => eth_address.encode_hex_with_prefix(),
+        Err(_) => addr.to_string(),
+    };
+    let parsed = parse_address(&text)?;
+    if parsed != addr {
+        return Err(anyhow!(
+            "address '{}' did not round-trip through its serialized form",
+            text
+        ));
+    }
+    Ok(())
+}
+
 impl NetworkSpec {
+    /// Loads the network registry: the built-in [`default_networks`] presets, layered over by
+    /// whatever's in the TOML file at `path` (or [`DEFAULT_NETWORK_CONFIG_PATH`] if `None`), so a
+    /// user can register their own subnets or override a preset's `evm_rpc_url`/gateway/registry
+    /// addresses without recompiling. A missing file is not an error -- it just means no
+    /// overrides. Every resulting entry is validated: its `subnet_id` must parse, and each of its
+    /// addresses must round-trip through its serialized form (hex for EVM-backed addresses, the
+    /// FVM string otherwise).
+    pub fn load_all(path: Option<PathBuf>) -> anyhow::Result<HashMap<String, NetworkSpec>> {
+        let mut specs = default_networks();
+
+        let path = path.unwrap_or_else(|| PathBuf::from(DEFAULT_NETWORK_CONFIG_PATH));
+        let expanded = shellexpand::full(&path.to_string_lossy())
+            .map_err(|err| anyhow!("cannot expand '{}': {err}", path.display()))?;
+        let path = PathBuf::from(expanded.as_ref());
+
+        if path.exists() {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|err| anyhow!("cannot read '{}': {err}", path.display()))?;
+            let overrides: HashMap<String, NetworkSpec> = toml::from_str(&content)
+                .map_err(|err| anyhow!("cannot parse TOML file '{}': {err}", path.display()))?;
+            specs.extend(overrides);
+        }
+
+        for (name, spec) in &specs {
+            spec.validate()
+                .map_err(|err| anyhow!("invalid network '{}' in registry: {err}", name))?;
+        }
+
+        Ok(specs)
+    }
+
+    /// Validates that `subnet_id` parses, that every address round-trips through its serialized
+    /// form (see [`round_trip_address`]), and that `evm_rpc_url` isn't empty, without constructing a full
+    /// [`NetworkConfig`].
+    fn validate(&self) -> anyhow::Result<()> {
+        SubnetID::from_str(&self.subnet_config.subnet_id).map_err(|err| {
+            anyhow!(
+                "invalid subnet ID '{}': {err}",
+                &self.subnet_config.subnet_id
+            )
+        })?;
+
+        if self.subnet_config.evm_rpc_url.is_empty() {
+            return Err(anyhow!("evm_rpc_url must have at least one endpoint"));
+        }
+        round_trip_address(self.subnet_config.evm_gateway_address)?;
+        round_trip_address(self.subnet_config.evm_registry_address)?;
+        if let Some(parent) = &self.parent_network_config {
+            if parent.evm_rpc_url.is_empty() {
+                return Err(anyhow!(
+                    "parent_network_config.evm_rpc_url must have at least one endpoint"
+                ));
+            }
+            round_trip_address(parent.evm_gateway_address)?;
+            round_trip_address(parent.evm_registry_address)?;
+            round_trip_address(parent.evm_supply_source_address)?;
+        }
+
+        Ok(())
+    }
+
     pub fn into_network_config(self) -> anyhow::Result<NetworkConfig> {
         let network = if FvmNetwork::Mainnet
             .parse_address(&self.subnet_config.subnet_id)
@@ -177,6 +256,14 @@ impl NetworkSpec {
             FvmNetwork::Testnet
         };
         address::set_current_network(network);
+        self.into_network_config_unchecked()
+    }
+
+    /// Like [`into_network_config`](Self::into_network_config), but assumes the caller has
+    /// already set the current [`FvmNetwork`] (e.g. via [`Network::init`]) instead of guessing it
+    /// from `subnet_id` -- used for the built-in presets, which know their network unambiguously
+    /// from which [`Network`] variant resolved them.
+    fn into_network_config_unchecked(self) -> anyhow::Result<NetworkConfig> {
         let mut subnet_id = SubnetID::from_str(&self.subnet_config.subnet_id).map_err(|err| {
             anyhow!(
                 "invalid subnet ID '{}': {err}",
@@ -202,9 +289,9 @@ impl NetworkSpec {
 #[derive(Debug, Clone)]
 pub struct NetworkConfig {
     pub subnet_id: SubnetID,
-    pub rpc_url: Url,
+    pub rpc_url: RpcEndpoint,
     pub object_api_url: Url,
-    pub evm_rpc_url: reqwest::Url,
+    pub evm_rpc_url: Vec<reqwest::Url>,
     pub evm_gateway_address: Address,
     pub evm_registry_address: Address,
     pub parent_network_config: Option<ParentNetworkConfig>,
@@ -212,7 +299,11 @@ pub struct NetworkConfig {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ParentNetworkConfig {
-    pub evm_rpc_url: reqwest::Url,
+    /// EVM RPC endpoint(s), tried in order with failover on error. Accepts either a single URL
+    /// string or an array of them in the config file, so existing single-endpoint entries keep
+    /// working unchanged.
+    #[serde(deserialize_with = "deserialize_evm_rpc_urls")]
+    pub evm_rpc_url: Vec<reqwest::Url>,
 
     #[serde(
         deserialize_with = "deserialize_address",
@@ -243,6 +334,10 @@ impl NetworkConfig {
             registry_addr: self.evm_registry_address,
             gateway_addr: self.evm_gateway_address,
             supply_source: None,
+            fee_estimator_config: None,
+            gas_oracle_config: None,
+            access_list_enabled: false,
+            escalator_config: None,
         }
     }
 
@@ -258,6 +353,10 @@ impl NetworkConfig {
             registry_addr: parent.evm_registry_address,
             gateway_addr: parent.evm_gateway_address,
             supply_source: Some(parent.evm_supply_source_address),
+            fee_estimator_config: None,
+            gas_oracle_config: None,
+            access_list_enabled: false,
+            escalator_config: None,
         })
     }
 }
@@ -274,6 +373,9 @@ pub enum Network {
     Localnet,
     /// Network presets for local development.
     Devnet,
+    /// A network registered only in the user's network registry file (see
+    /// [`NetworkSpec::load_all`]), resolved by name.
+    Custom(String),
 }
 
 impl Network {
@@ -287,65 +389,80 @@ impl Network {
         self
     }
 
-    pub fn get_config(&self) -> NetworkConfig {
+    pub fn get_config(&self) -> anyhow::Result<NetworkConfig> {
         self.init();
-        match self {
-            Network::Mainnet => todo!(),
-            Network::Testnet => NetworkConfig {
-                subnet_id: SubnetID::from_str(TESTNET_SUBNET_ID)
-                    .unwrap()
-                    .with_chain_id(ChainID::from(TESTNET_CHAIN_ID)),
-                rpc_url: Url::from_str(TESTNET_RPC_URL).unwrap(),
-                object_api_url: Url::from_str(TESTNET_OBJECT_API_URL).unwrap(),
-                evm_rpc_url: reqwest::Url::from_str(TESTNET_EVM_RPC_URL).unwrap(),
-                evm_gateway_address: parse_address(TESTNET_EVM_GATEWAY_ADDRESS).unwrap(),
-                evm_registry_address: parse_address(TESTNET_EVM_REGISTRY_ADDRESS).unwrap(),
-                parent_network_config: Some(ParentNetworkConfig {
-                    evm_rpc_url: reqwest::Url::from_str(TESTNET_PARENT_EVM_RPC_URL).unwrap(),
-                    evm_gateway_address: parse_address(TESTNET_PARENT_EVM_GATEWAY_ADDRESS).unwrap(),
-                    evm_registry_address: parse_address(TESTNET_PARENT_EVM_REGISTRY_ADDRESS)
-                        .unwrap(),
-                    evm_supply_source_address: parse_address(TESTNET_EVM_SUPPLY_SOURCE_ADDRESS)
-                        .unwrap(),
-                }),
-            },
-            Network::Localnet => NetworkConfig {
-                subnet_id: SubnetID::from_str(LOCALNET_SUBNET_ID)
-                    .unwrap()
-                    .with_chain_id(ChainID::from(LOCALNET_CHAIN_ID)),
-                rpc_url: Url::from_str(LOCALNET_RPC_URL).unwrap(),
-                object_api_url: Url::from_str(LOCALNET_OBJECT_API_URL).unwrap(),
-                evm_rpc_url: reqwest::Url::from_str(LOCALNET_EVM_RPC_URL).unwrap(),
-                evm_gateway_address: parse_address(LOCALNET_EVM_GATEWAY_ADDRESS).unwrap(),
-                evm_registry_address: parse_address(LOCALNET_EVM_REGISTRY_ADDRESS).unwrap(),
-                parent_network_config: Some(ParentNetworkConfig {
-                    evm_rpc_url: reqwest::Url::from_str(LOCALNET_PARENT_EVM_RPC_URL).unwrap(),
-                    evm_gateway_address: parse_address(LOCALNET_PARENT_EVM_GATEWAY_ADDRESS)
-                        .unwrap(),
-                    evm_registry_address: parse_address(LOCALNET_PARENT_EVM_REGISTRY_ADDRESS)
-                        .unwrap(),
-                    evm_supply_source_address: parse_address(LOCALNET_EVM_SUPPLY_SOURCE_ADDRESS)
-                        .unwrap(),
-                }),
-            },
-            Network::Devnet => NetworkConfig {
-                subnet_id: SubnetID::from_str(DEVNET_SUBNET_ID).unwrap(),
-                rpc_url: Url::from_str(LOCALNET_RPC_URL).unwrap(),
-                object_api_url: Url::from_str(LOCALNET_OBJECT_API_URL).unwrap(),
-                evm_rpc_url: reqwest::Url::from_str(DEVNET_EVM_RPC_URL).unwrap(),
-                evm_gateway_address: parse_address(DEVNET_EVM_GATEWAY_ADDRESS).unwrap(),
-                evm_registry_address: parse_address(DEVNET_EVM_REGISTRY_ADDRESS).unwrap(),
-                parent_network_config: None,
-            },
-        }
+        let name = match self {
+            Network::Mainnet => MAINNET_NETWORK_NAME,
+            Network::Testnet => TESTNET_NETWORK_NAME,
+            Network::Localnet => LOCALNET_NETWORK_NAME,
+            Network::Devnet => DEVNET_NETWORK_NAME,
+            Network::Custom(name) => {
+                // `from_str` already confirmed `name` is in the registry, but re-reads the file
+                // rather than trusting that to still hold: it could have been deleted, truncated,
+                // or edited to drop `name` since then. Propagate that as an error instead of
+                // `.expect()`-ing the earlier check still applies.
+                let spec = NetworkSpec::load_all(None)?.remove(name).ok_or_else(|| {
+                    anyhow!("custom network '{name}' is no longer in the network registry")
+                })?;
+                return spec.into_network_config();
+            }
+        };
+        Ok(ChainSpec::builtin(name)
+            .expect("name is one of the built-in presets")
+            .expect("built-in network preset should parse")
+            .into_network_config_unchecked()
+            .expect("built-in network preset should be valid"))
     }
 }
 
 #[test]
 fn correct_network_definitions() {
-    let _ = Network::Devnet.get_config();
-    let _ = Network::Localnet.get_config();
-    let _ = Network::Testnet.get_config();
+    Network::Devnet.get_config().unwrap();
+    Network::Localnet.get_config().unwrap();
+    Network::Testnet.get_config().unwrap();
+    Network::Mainnet.get_config().unwrap();
+}
+
+#[test]
+fn default_network_addresses_round_trip_in_both_formats() {
+    for (name, spec) in default_networks() {
+        let mut addrs = vec![
+            spec.subnet_config.evm_gateway_address,
+            spec.subnet_config.evm_registry_address,
+        ];
+        if let Some(parent) = &spec.parent_network_config {
+            addrs.push(parent.evm_gateway_address);
+            addrs.push(parent.evm_registry_address);
+            addrs.push(parent.evm_supply_source_address);
+        }
+
+        for addr in addrs {
+            let hex = get_eth_address(addr)
+                .unwrap_or_else(|err| panic!("{name}: {err}"))
+                .encode_hex_with_prefix();
+            assert_eq!(
+                parse_address(&hex).unwrap(),
+                addr,
+                "{name}: address did not round-trip through its 0x-hex form"
+            );
+
+            let fvm = addr.to_string();
+            assert_eq!(
+                parse_address(&fvm).unwrap(),
+                addr,
+                "{name}: address did not round-trip through its FVM-prefixed form"
+            );
+        }
+    }
+}
+
+#[test]
+fn non_evm_backed_address_round_trips_via_fvm_string() {
+    // `get_eth_address` only has a hex form for masked ID and `f410`-delegated addresses, so a
+    // actor address exercises `serialize_address`/`round_trip_address`'s FVM-string fallback.
+    let addr = Address::new_actor(b"round-trip");
+    assert!(get_eth_address(addr).is_err());
+    round_trip_address(addr).unwrap();
 }
 
 impl FromStr for Network {
@@ -357,7 +474,14 @@ impl FromStr for Network {
             "testnet" => Ok(Network::Testnet),
             "localnet" => Ok(Network::Localnet),
             "devnet" => Ok(Network::Devnet),
-            _ => Err(Error::UnknownNetwork.to_string()),
+            _ => {
+                let specs = NetworkSpec::load_all(None).map_err(|err| err.to_string())?;
+                if specs.contains_key(s) {
+                    Ok(Network::Custom(s.to_owned()))
+                } else {
+                    Err(Error::UnknownNetwork.to_string())
+                }
+            }
         }
     }
 }
@@ -369,6 +493,7 @@ impl Display for Network {
             Network::Testnet => write!(f, "testnet"),
             Network::Localnet => write!(f, "localnet"),
             Network::Devnet => write!(f, "devnet"),
+            Network::Custom(name) => write!(f, "{name}"),
         }
     }
 }
@@ -9,11 +9,21 @@ pub struct Range {
 
 impl Range {
     pub fn parse(range: &str) -> anyhow::Result<Self> {
-        let range: Vec<String> = range
-            .replace("bytes=", "")
-            .split('-')
-            .map(|n| n.to_string())
-            .collect();
+        Self::parse_one(&range.replace("bytes=", ""))
+    }
+
+    /// Parses a full HTTP `Range` header that may list several comma-separated subranges
+    /// (`bytes=0-99,500-599,-200`), returning one [`Range`] per subrange in request order.
+    /// Each subrange follows the same `a-b`/`a-`/`-b` grammar as [`Self::parse`].
+    pub fn parse_multi(range: &str) -> anyhow::Result<Vec<Self>> {
+        let body = range
+            .strip_prefix("bytes=")
+            .ok_or_else(|| anyhow!("invalid range: missing \"bytes=\" prefix"))?;
+        body.split(',').map(Self::parse_one).collect()
+    }
+
+    fn parse_one(spec: &str) -> anyhow::Result<Self> {
+        let range: Vec<String> = spec.split('-').map(|n| n.to_string()).collect();
         if range.len() != 2 {
             return Err(anyhow!("invalid range"));
         }
@@ -30,6 +40,10 @@ impl Range {
             None
         };
 
+        if start.is_none() && end.is_none() {
+            return Err(anyhow!("invalid range"));
+        }
+
         Ok(Range { start, end })
     }
 
@@ -64,32 +78,113 @@ impl Range {
 
     fn get_range_for_encrypted(&self, size: u64) -> String {
         let (offset, length) = self.get_offset_length(size);
+        let (start, end) = encrypted_package_range(offset, length, size);
+        format!("{start}-{end}")
+    }
+
+    /// Resolves a multi-range spec (`bytes=0-99,500-599,-200`) against an object of `size`
+    /// bytes, producing concrete, non-overlapping inclusive `(start, end)` byte ranges in
+    /// ascending order. Subranges may be given out of order; overlapping or adjacent subranges
+    /// are coalesced into a single range rather than rejected, so a caller never issues two
+    /// fetches that cover the same bytes.
+    pub fn resolve_multi(range: &str, size: u64) -> anyhow::Result<Vec<(u64, u64)>> {
+        let resolved: Vec<(u64, u64)> = Self::parse_multi(range)?
+            .iter()
+            .map(|r| {
+                let (offset, length) = r.get_offset_length(size);
+                (offset, offset + length - 1)
+            })
+            .collect();
+        Ok(coalesce(resolved))
+    }
 
-        let last_package_index = size / MAX_PAYLOAD_SIZE as u64;
-        let start_package_index = offset / MAX_PAYLOAD_SIZE as u64;
-        let end_package_index = (offset + length) / MAX_PAYLOAD_SIZE as u64;
+    /// Like [`Self::resolve_multi`], but for a DARE-encrypted object: each resolved plaintext
+    /// byte range is expanded to the whole encrypted packages it overlaps (see
+    /// [`Self::get_range_for_encrypted`]) before coalescing, so two plaintext subranges that
+    /// land in the same package still produce a single fetch instead of two overlapping ones.
+    pub fn resolve_multi_encrypted(range: &str, size: u64) -> anyhow::Result<Vec<(u64, u64)>> {
+        let resolved: Vec<(u64, u64)> = Self::parse_multi(range)?
+            .iter()
+            .map(|r| {
+                let (offset, length) = r.get_offset_length(size);
+                encrypted_package_range(offset, length, size)
+            })
+            .collect();
+        Ok(coalesce(resolved))
+    }
+}
 
-        let package_size = (HEADER_SIZE + MAX_PAYLOAD_SIZE + TAG_SIZE) as u64;
+/// Maps a plaintext `(offset, length)` byte range onto the encrypted packages it overlaps.
+/// Each package is `HEADER_SIZE + MAX_PAYLOAD_SIZE + TAG_SIZE` bytes; the final package of the
+/// object is shorter (only as much payload as remains), so its end is computed from `size`
+/// directly rather than assumed to be a full package.
+fn encrypted_package_range(offset: u64, length: u64, size: u64) -> (u64, u64) {
+    let last_package_index = size / MAX_PAYLOAD_SIZE as u64;
+    let start_package_index = offset / MAX_PAYLOAD_SIZE as u64;
+    let end_package_index = (offset + length) / MAX_PAYLOAD_SIZE as u64;
 
-        if end_package_index < last_package_index {
-            return format!(
-                "{}-{}",
-                start_package_index * package_size,
-                (end_package_index + 1) * package_size - 1
-            );
-        }
+    let package_size = (HEADER_SIZE + MAX_PAYLOAD_SIZE + TAG_SIZE) as u64;
 
-        format!(
-            "{}-{}",
+    if end_package_index < last_package_index {
+        return (
             start_package_index * package_size,
-            size + (last_package_index + 1) * (HEADER_SIZE + TAG_SIZE) as u64 - 1
-        )
+            (end_package_index + 1) * package_size - 1,
+        );
     }
+
+    (
+        start_package_index * package_size,
+        size + (last_package_index + 1) * (HEADER_SIZE + TAG_SIZE) as u64 - 1,
+    )
+}
+
+/// Sorts `ranges` by start and merges any that overlap or are directly adjacent (an end one
+/// byte before the next start), so the result never contains two ranges a single fetch could
+/// have covered in one request.
+fn coalesce(mut ranges: Vec<(u64, u64)>) -> Vec<(u64, u64)> {
+    ranges.sort_unstable_by_key(|&(start, _)| start);
+
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        if let Some(last) = merged.last_mut() {
+            if start <= last.1 + 1 {
+                last.1 = last.1.max(end);
+                continue;
+            }
+        }
+        merged.push((start, end));
+    }
+    merged
+}
+
+/// Assembles an HTTP `multipart/byteranges` response body (RFC 9110 §14.6) for `ranges`, each
+/// paired with the matching slice of `body` (the full, unencrypted object bytes) and a
+/// `Content-Range` part header. Pass the returned boundary in the response's
+/// `Content-Type: multipart/byteranges; boundary=<boundary>` header.
+pub fn build_multipart_byteranges(
+    ranges: &[(u64, u64)],
+    body: &[u8],
+    content_type: &str,
+    total_size: u64,
+    boundary: &str,
+) -> Vec<u8> {
+    let mut out = Vec::new();
+    for &(start, end) in ranges {
+        out.extend_from_slice(format!("--{boundary}\r\n").as_bytes());
+        out.extend_from_slice(format!("Content-Type: {content_type}\r\n").as_bytes());
+        out.extend_from_slice(
+            format!("Content-Range: bytes {start}-{end}/{total_size}\r\n\r\n").as_bytes(),
+        );
+        out.extend_from_slice(&body[start as usize..=end as usize]);
+        out.extend_from_slice(b"\r\n");
+    }
+    out.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+    out
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::range::Range;
+    use crate::range::{build_multipart_byteranges, Range};
 
     #[test]
     fn test_offset_length() {
@@ -195,4 +290,70 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_resolve_multi() {
+        let object_size = 1000;
+
+        // In-order, non-overlapping.
+        assert_eq!(
+            Range::resolve_multi("bytes=0-99,500-599", object_size).unwrap(),
+            vec![(0, 99), (500, 599)]
+        );
+
+        // Out-of-order subranges still come back sorted.
+        assert_eq!(
+            Range::resolve_multi("bytes=500-599,0-99", object_size).unwrap(),
+            vec![(0, 99), (500, 599)]
+        );
+
+        // A suffix range (`-200`) composes with the others.
+        assert_eq!(
+            Range::resolve_multi("bytes=0-99,-200", object_size).unwrap(),
+            vec![(0, 99), (800, 999)]
+        );
+
+        // Overlapping subranges are coalesced into one.
+        assert_eq!(
+            Range::resolve_multi("bytes=0-99,50-149", object_size).unwrap(),
+            vec![(0, 149)]
+        );
+
+        // Adjacent subranges (no gap between them) are coalesced too.
+        assert_eq!(
+            Range::resolve_multi("bytes=0-99,100-199", object_size).unwrap(),
+            vec![(0, 199)]
+        );
+    }
+
+    #[test]
+    fn test_resolve_multi_encrypted() {
+        // Both subranges fall in the first package, so they coalesce into one fetch even
+        // though they don't overlap in plaintext terms.
+        assert_eq!(
+            Range::resolve_multi_encrypted("bytes=100-200,60000-60002", 70000).unwrap(),
+            vec![(0, 65567)]
+        );
+
+        // Packages 0 and 3, with two whole packages in between: stays as two separate fetches.
+        let object_size = 3 * 65536 + 100;
+        assert_eq!(
+            Range::resolve_multi_encrypted("bytes=100-200,196608-196700", object_size).unwrap(),
+            vec![(0, 65567), (196704, 196835)]
+        );
+    }
+
+    #[test]
+    fn test_build_multipart_byteranges() {
+        let body = b"0123456789";
+        let out = build_multipart_byteranges(&[(0, 2), (5, 9)], body, "text/plain", 10, "B");
+        let out = String::from_utf8(out).unwrap();
+
+        assert_eq!(
+            out,
+            "--B\r\nContent-Type: text/plain\r\nContent-Range: bytes 0-2/10\r\n\r\n012\r\n\
+             --B\r\nContent-Type: text/plain\r\nContent-Range: bytes 5-9/10\r\n\r\n56789\r\n\
+             --B--\r\n"
+        );
+    }
 }
@@ -1,11 +1,23 @@
 // Copyright 2025 Recall Contributors
 // SPDX-License-Identifier: Apache-2.0, MIT
 
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+
+use ethers::utils::hex::ToHexExt;
 use fendermint_actor_blobs_shared::state::TokenCreditRate;
 use fendermint_actor_recall_config_shared::Method::{GetAdmin, GetConfig, SetAdmin, SetConfig};
 use fendermint_actor_recall_config_shared::{RecallConfig, SetAdminParams, SetConfigParams};
 use fendermint_vm_actor_interface::recall_config::RECALL_CONFIG_ACTOR_ADDR;
-use tendermint::chain;
+use futures_core::Stream;
+use recall_fendermint_vm_actor_interface::blobs::BLOBS_ACTOR_ADDR;
+use serde::{Deserialize, Serialize};
+use tendermint::{block::Height, chain};
+use tendermint_rpc::query::{EventType, Query};
+use tendermint_rpc::{Paging, WebSocketClientUrl};
+use tokio::time::Instant;
+use tokio_stream::StreamExt;
 
 use recall_provider::{
     fvm_shared::{address::Address, clock::ChainEpoch},
@@ -14,9 +26,81 @@ use recall_provider::{
     query::{FvmQueryHeight, QueryProvider},
     response::{decode_as, decode_empty},
     tx::{BroadcastMode, TxResult},
+    util::get_eth_address,
     {Client, Provider, TendermintClient},
 };
-use recall_signer::Signer;
+use recall_signer::{Signer, Wallet};
+
+use crate::account::{Account, SetSponsorOptions};
+
+/// A validator's current consensus weight, as reported by CometBFT.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidatorInfo {
+    /// The validator's CometBFT address (not its EVM address).
+    pub address: String,
+    /// The validator's voting power.
+    pub voting_power: u64,
+    /// The validator's proposer priority, used to select the next block proposer.
+    pub proposer_priority: i64,
+}
+
+/// Block production stats over a recent window of blocks, as reported by CometBFT.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BlockProductionStats {
+    /// Height of the first block in the window.
+    pub start_height: u64,
+    /// Height of the last block in the window (the chain's current tip).
+    pub end_height: u64,
+    /// Number of blocks produced in the window.
+    pub num_blocks: u64,
+    /// Average time between consecutive blocks in the window, in seconds.
+    pub avg_block_time_secs: f64,
+    /// Number of blocks produced in the window, keyed by the proposer's CometBFT address.
+    pub blocks_by_proposer: std::collections::HashMap<String, u64>,
+}
+
+/// Which family of subnet events [`Subnet::subscribe`] should emit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubnetEventKind {
+    /// Config-admin changes and `SetConfig` updates.
+    Config,
+    /// The batched credit-debit sweeps driven by `blob_credit_debit_interval`/
+    /// `account_debit_batch_size`.
+    CreditDebit,
+}
+
+/// One committed transaction observed by [`Subnet::subscribe`], classified by which watched
+/// actor it mentions.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SubnetEvent {
+    /// Which watched actor this transaction touched.
+    pub kind: SubnetEventKind,
+    /// The block height the transaction committed at, if the subscription reported one.
+    pub height: Option<i64>,
+    /// The transaction's hash, if the subscription reported one.
+    pub hash: Option<String>,
+}
+
+/// Subnet-wide credit supply stats, combining [`crate::credits::CreditStats`] with the
+/// configured [`SetConfigOptions::blob_capacity`]. The capacity reported is the configured total;
+/// free (unused) storage capacity is not computed.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SupplyStats {
+    /// Total credits ever sold.
+    #[serde(with = "recall_provider::util::decimal_string")]
+    pub credit_sold: crate::credits::Credit,
+    /// Total credits debited (consumed) so far.
+    #[serde(with = "recall_provider::util::decimal_string")]
+    pub credit_debited: crate::credits::Credit,
+    /// Total credits currently committed to active storage.
+    #[serde(with = "recall_provider::util::decimal_string")]
+    pub credit_committed: crate::credits::Credit,
+    /// The subnet's configured total storage capacity.
+    pub blob_capacity: u64,
+    /// Number of accounts with a credit balance.
+    pub num_accounts: u64,
+}
 
 /// Options for setting config admin.
 #[derive(Clone, Debug)]
@@ -50,12 +134,141 @@ pub struct SetConfigOptions {
     pub gas_params: GasParams,
 }
 
+/// Options for [`Subnet::load_test`].
+#[derive(Clone, Debug)]
+pub struct LoadTestOptions {
+    /// Aggregate target transactions per second across all workers. The limiter backs off
+    /// rather than letting a saturated node build an unbounded backlog of queued sends.
+    pub tps: f64,
+    /// How long to drive traffic for.
+    pub duration: Duration,
+    /// Broadcast mode used to submit each synthetic transaction. [`BroadcastMode::Commit`] (the
+    /// default) is what makes submit-to-commit latency measurable; `Async`/`Sync` would only
+    /// time the broadcast, not inclusion.
+    pub broadcast_mode: BroadcastMode,
+    /// Gas params for each synthetic transaction.
+    pub gas_params: GasParams,
+}
+
+impl Default for LoadTestOptions {
+    fn default() -> Self {
+        LoadTestOptions {
+            tps: 10.0,
+            duration: Duration::from_secs(30),
+            broadcast_mode: BroadcastMode::Commit,
+            gas_params: GasParams::default(),
+        }
+    }
+}
+
+/// Aggregate result of a [`Subnet::load_test`] run.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LoadTestReport {
+    /// Number of workers (accounts) that drove traffic.
+    pub workers: usize,
+    /// Wall-clock duration the run actually took, in seconds.
+    pub duration_secs: f64,
+    /// Number of transactions that committed successfully.
+    pub accepted: u64,
+    /// Number of transactions that failed to submit or commit.
+    pub rejected: u64,
+    /// Accepted transactions per second, sustained over `duration_secs`.
+    pub tps: f64,
+    /// Median submit-to-commit latency, in milliseconds.
+    pub latency_p50_ms: f64,
+    /// 90th percentile submit-to-commit latency, in milliseconds.
+    pub latency_p90_ms: f64,
+    /// 99th percentile submit-to-commit latency, in milliseconds.
+    pub latency_p99_ms: f64,
+    /// Count of rejected transactions, keyed by a short error classification.
+    pub errors: HashMap<String, u64>,
+}
+
+/// A hand-rolled token-bucket rate limiter shared across all [`Subnet::load_test`] workers.
+///
+/// Unlike an unbounded queue, [`Self::acquire`] makes a caller sleep until a token is actually
+/// available instead of admitting work ahead of schedule -- so when the node falls behind, the
+/// whole worker pool slows down with it rather than building up a backlog that bursts once the
+/// node catches up.
+struct TokenBucket {
+    capacity: f64,
+    refill_per_sec: f64,
+    state: StdMutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+    fn new(rate: f64) -> Self {
+        let capacity = rate.max(1.0);
+        TokenBucket {
+            capacity,
+            refill_per_sec: if rate > 0.0 { rate } else { capacity },
+            state: StdMutex::new((capacity, Instant::now())),
+        }
+    }
+
+    async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().expect("token bucket mutex poisoned");
+                let (tokens, last_refill) = &mut *state;
+                let elapsed = last_refill.elapsed().as_secs_f64();
+                *tokens = (*tokens + elapsed * self.refill_per_sec).min(self.capacity);
+                *last_refill = Instant::now();
+                if *tokens >= 1.0 {
+                    *tokens -= 1.0;
+                    None
+                } else {
+                    Some(Duration::from_secs_f64((1.0 - *tokens) / self.refill_per_sec))
+                }
+            };
+            match wait {
+                None => return,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+}
+
+/// Per-worker outcome of a [`Subnet::load_test`] run, aggregated by the caller into a
+/// [`LoadTestReport`].
+#[derive(Default)]
+struct WorkerStats {
+    latencies_ms: Vec<f64>,
+    accepted: u64,
+    rejected: u64,
+    errors: HashMap<String, u64>,
+}
+
+/// The nearest-rank percentile of `sorted_ms` (already sorted ascending), matching the
+/// convention used by [`recall_provider::gas_oracle`]'s fee-percentile helper.
+fn percentile(sorted_ms: &[f64], percentile: f64) -> f64 {
+    if sorted_ms.is_empty() {
+        return 0.0;
+    }
+    let rank = ((percentile / 100.0) * (sorted_ms.len() - 1) as f64).round() as usize;
+    sorted_ms[rank.min(sorted_ms.len() - 1)]
+}
+
+/// Classifies an error into a short, stable label for [`LoadTestReport::errors`], grouping on
+/// the first line of the message so transient details (addresses, sequence numbers) don't
+/// fragment the breakdown into one bucket per occurrence.
+fn classify_error(err: &anyhow::Error) -> String {
+    err.to_string()
+        .lines()
+        .next()
+        .unwrap_or("unknown error")
+        .to_string()
+}
+
 /// Accessors for fetching subnet-wide information from a node via the CometBFT RPCs.
 pub struct Subnet {}
 
 impl Subnet {
     /// Returns the chain ID.
-    pub async fn chain_id(provider: JsonRpcProvider) -> anyhow::Result<chain::Id> {
+    pub async fn chain_id<C>(provider: JsonRpcProvider<C>) -> anyhow::Result<chain::Id>
+    where
+        C: Client + Send + Sync,
+    {
         let response = provider.underlying().status().await?;
         Ok(response.node_info.network)
     }
@@ -80,6 +293,7 @@ impl Subnet {
                 SetAdmin as u64,
                 params,
                 options.gas_params,
+                None,
                 options.broadcast_mode,
                 decode_empty,
             )
@@ -127,6 +341,7 @@ impl Subnet {
                 SetConfig as u64,
                 params,
                 options.gas_params,
+                None,
                 options.broadcast_mode,
                 decode_empty,
             )
@@ -146,4 +361,277 @@ impl Subnet {
         let response = provider.call(message, height, decode_as).await?;
         Ok(response.value)
     }
+
+    /// Returns the current validator set and each validator's voting power, as reported by
+    /// CometBFT.
+    pub async fn validators<C>(provider: JsonRpcProvider<C>) -> anyhow::Result<Vec<ValidatorInfo>>
+    where
+        C: Client + Send + Sync,
+    {
+        let response = provider
+            .underlying()
+            .validators(Height::default(), Paging::All)
+            .await?;
+        Ok(response
+            .validators
+            .into_iter()
+            .map(|v| ValidatorInfo {
+                address: v.address.to_string(),
+                voting_power: v.power(),
+                proposer_priority: v.proposer_priority.value(),
+            })
+            .collect())
+    }
+
+    /// Reports block/proposer production stats over the last `epochs` blocks, as reported by
+    /// CometBFT.
+    pub async fn block_production<C>(
+        provider: JsonRpcProvider<C>,
+        epochs: u64,
+    ) -> anyhow::Result<BlockProductionStats>
+    where
+        C: Client + Send + Sync,
+    {
+        let status = provider.underlying().status().await?;
+        let end_height = status.sync_info.latest_block_height.value();
+        let start_height = end_height.saturating_sub(epochs.saturating_sub(1)).max(1);
+
+        let response = provider
+            .underlying()
+            .blockchain(Height::try_from(start_height)?, Height::try_from(end_height)?)
+            .await?;
+
+        let mut block_metas = response.block_metas;
+        block_metas.sort_by_key(|meta| meta.header.height.value());
+
+        let mut blocks_by_proposer = std::collections::HashMap::new();
+        for meta in &block_metas {
+            *blocks_by_proposer
+                .entry(meta.header.proposer_address.to_string())
+                .or_insert(0u64) += 1;
+        }
+
+        let avg_block_time_secs = if block_metas.len() > 1 {
+            let first = block_metas.first().unwrap().header.time;
+            let last = block_metas.last().unwrap().header.time;
+            let elapsed = last.duration_since(first)?.as_secs_f64();
+            elapsed / (block_metas.len() - 1) as f64
+        } else {
+            0.0
+        };
+
+        Ok(BlockProductionStats {
+            start_height,
+            end_height,
+            num_blocks: block_metas.len() as u64,
+            avg_block_time_secs,
+            blocks_by_proposer,
+        })
+    }
+
+    /// Reports total credits issued vs. debited and committed, the number of credit accounts,
+    /// and the configured [`SetConfigOptions::blob_capacity`]. Free blob storage capacity is not
+    /// computed; `blob_capacity` is the configured total.
+    pub async fn supply(
+        provider: &impl QueryProvider,
+        height: FvmQueryHeight,
+    ) -> anyhow::Result<SupplyStats> {
+        let stats = crate::credits::Credits::stats(provider, height).await?;
+        let config = Self::get_config(provider, height).await?;
+        Ok(SupplyStats {
+            credit_sold: stats.credit_sold,
+            credit_debited: stats.credit_debited,
+            credit_committed: stats.credit_committed,
+            blob_capacity: config.blob_capacity,
+            num_accounts: stats.num_accounts,
+        })
+    }
+
+    /// Opens a dedicated WebSocket subscription on `ws_url` and streams every committed
+    /// transaction that touches the config-admin actor or the blobs (credit) actor, restricted
+    /// to `kinds` and (if set) mentioning `address`. Transactions matching neither a watched
+    /// actor nor, when set, `address`, are silently dropped from the stream rather than yielded.
+    pub async fn subscribe<C, U>(
+        provider: &JsonRpcProvider<C>,
+        ws_url: U,
+        kinds: Vec<SubnetEventKind>,
+        address: Option<Address>,
+    ) -> anyhow::Result<impl Stream<Item = anyhow::Result<SubnetEvent>>>
+    where
+        C: Client + Send + Sync,
+        U: TryInto<WebSocketClientUrl, Error = tendermint_rpc::Error> + std::fmt::Display + Clone,
+    {
+        let events = provider
+            .subscribe_tx_events(ws_url, Query::from(EventType::Tx), None, None)
+            .await?;
+
+        let config_needles = actor_needles(RECALL_CONFIG_ACTOR_ADDR);
+        let credit_needles = actor_needles(BLOBS_ACTOR_ADDR);
+        let address_needle = address.map(|addr| addr.to_string());
+
+        Ok(events.filter_map(move |event| {
+            let event = match event {
+                Ok(event) => event,
+                Err(e) => return Some(Err(e)),
+            };
+            let values: Vec<&String> = event.attributes.values().flatten().collect();
+
+            let kind = if kinds.contains(&SubnetEventKind::Config)
+                && config_needles.iter().any(|n| values.iter().any(|v| *v == n))
+            {
+                SubnetEventKind::Config
+            } else if kinds.contains(&SubnetEventKind::CreditDebit)
+                && credit_needles.iter().any(|n| values.iter().any(|v| *v == n))
+            {
+                SubnetEventKind::CreditDebit
+            } else {
+                return None;
+            };
+            if let Some(needle) = &address_needle {
+                if !values.iter().any(|v| *v == needle) {
+                    return None;
+                }
+            }
+
+            Some(Ok(SubnetEvent {
+                kind,
+                height: event.height,
+                hash: event.hash.map(|h| h.to_string()),
+            }))
+        }))
+    }
+
+    /// Drives synthetic traffic from `wallets` to measure the subnet's sustained TPS and
+    /// submit-to-commit latency under its current config (useful for validating
+    /// `blob_delete_batch_size`/`account_debit_batch_size` tuning).
+    ///
+    /// Each wallet is a disjoint worker: it owns its own sequence (nonce) and submits
+    /// transactions in a tight loop gated by a single token-bucket limiter shared across the
+    /// pool, so the aggregate rate stays at `options.tps` however many workers are given. A
+    /// failed send re-queries the wallet's sequence from chain state before its next attempt, so
+    /// a transient failure can't permanently desync it from the actor's actual nonce.
+    ///
+    /// Callers are expected to have already primed each wallet's sequence (e.g. via
+    /// [`recall_signer::Wallet::set_sequence`]) so workers don't all start from zero.
+    pub async fn load_test<C>(
+        provider: &JsonRpcProvider<C>,
+        wallets: Vec<Wallet>,
+        options: LoadTestOptions,
+    ) -> anyhow::Result<LoadTestReport>
+    where
+        C: Client + Send + Sync + Clone + 'static,
+    {
+        if wallets.is_empty() {
+            return Err(anyhow::anyhow!("load_test requires at least one wallet"));
+        }
+
+        let workers = wallets.len();
+        let bucket = Arc::new(TokenBucket::new(options.tps));
+        let deadline = Instant::now() + options.duration;
+        let start = Instant::now();
+
+        let mut handles = Vec::with_capacity(workers);
+        for wallet in wallets {
+            let provider = provider.clone();
+            let bucket = bucket.clone();
+            let broadcast_mode = options.broadcast_mode;
+            let gas_params = options.gas_params.clone();
+            handles.push(tokio::spawn(async move {
+                run_load_test_worker(provider, wallet, bucket, deadline, broadcast_mode, gas_params)
+                    .await
+            }));
+        }
+
+        let mut accepted = 0u64;
+        let mut rejected = 0u64;
+        let mut latencies_ms = Vec::new();
+        let mut errors = HashMap::new();
+        for handle in handles {
+            let stats = handle.await?;
+            accepted += stats.accepted;
+            rejected += stats.rejected;
+            latencies_ms.extend(stats.latencies_ms);
+            for (label, count) in stats.errors {
+                *errors.entry(label).or_insert(0) += count;
+            }
+        }
+
+        let duration_secs = start.elapsed().as_secs_f64();
+        latencies_ms.sort_by(|a, b| a.total_cmp(b));
+
+        Ok(LoadTestReport {
+            workers,
+            duration_secs,
+            accepted,
+            rejected,
+            tps: if duration_secs > 0.0 {
+                accepted as f64 / duration_secs
+            } else {
+                0.0
+            },
+            latency_p50_ms: percentile(&latencies_ms, 50.0),
+            latency_p90_ms: percentile(&latencies_ms, 90.0),
+            latency_p99_ms: percentile(&latencies_ms, 99.0),
+            errors,
+        })
+    }
+}
+
+/// One [`Subnet::load_test`] worker's tight send loop, running until `deadline`. Submits a
+/// [`Account::set_sponsor`] no-op (unsetting any sponsor) as cheap, value-free synthetic traffic
+/// that still exercises a full sign-submit-commit round trip through the blobs actor.
+async fn run_load_test_worker<C>(
+    provider: JsonRpcProvider<C>,
+    mut wallet: Wallet,
+    bucket: Arc<TokenBucket>,
+    deadline: Instant,
+    broadcast_mode: BroadcastMode,
+    gas_params: GasParams,
+) -> WorkerStats
+where
+    C: Client + Send + Sync,
+{
+    let mut stats = WorkerStats::default();
+    while Instant::now() < deadline {
+        bucket.acquire().await;
+
+        let submitted_at = Instant::now();
+        let result = Account::set_sponsor(
+            &provider,
+            &mut wallet,
+            None,
+            SetSponsorOptions {
+                broadcast_mode,
+                gas_params: gas_params.clone(),
+            },
+        )
+        .await;
+
+        match result {
+            Ok(_) => {
+                stats.accepted += 1;
+                stats
+                    .latencies_ms
+                    .push(submitted_at.elapsed().as_secs_f64() * 1000.0);
+            }
+            Err(err) => {
+                stats.rejected += 1;
+                *stats.errors.entry(classify_error(&err)).or_insert(0) += 1;
+                // Resync from chain state before the next attempt so a failed send (e.g. a
+                // sequence mismatch) can't permanently desync this worker's nonce.
+                let _ = wallet.set_sequence(None, &provider).await;
+            }
+        }
+    }
+    stats
+}
+
+/// The address forms a subnet transaction's indexed attributes might mention `addr` as: its
+/// native FVM string form, and (if it has one) its Ethereum-hex form.
+fn actor_needles(addr: Address) -> Vec<String> {
+    let mut needles = vec![addr.to_string()];
+    if let Ok(eth) = get_eth_address(addr) {
+        needles.push(eth.encode_hex_with_prefix());
+    }
+    needles
 }
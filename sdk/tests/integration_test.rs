@@ -67,9 +67,9 @@ async fn can_deposit() {
     )
     .unwrap();
 
-    // Deposit some funds into the subnet
+    // Deposit some funds into the subnet, and wait for it to be confirmed.
     // Note: The debit account _must_ have Funds on parent
-    let tx = match Account::deposit(
+    let pending = match Account::deposit(
         &signer,
         signer.address(),
         network_config
@@ -81,15 +81,16 @@ async fn can_deposit() {
     )
     .await
     {
-        Ok(txr) => txr,
+        Ok(pending) => pending,
         Err(e) => panic!("transaction failed {}", e),
     };
+    let updated_balance = match pending.await {
+        Ok(balance) => balance,
+        Err(e) => panic!("deposit did not confirm {}", e),
+    };
 
     println!("Deposited 1 RECALL to {}", signer.eth_address().unwrap());
-    println!(
-        "Transaction hash: 0x{}",
-        hex::encode(tx.transaction_hash.to_fixed_bytes())
-    );
+    println!("Updated supply source balance: {updated_balance}");
 
     // TODO: some failures will throw, but we should assert that deposit worked too
 }
@@ -104,7 +105,7 @@ async fn can_add_bucket() {
         Wallet::new_secp256k1(sk, AccountKind::Ethereum, network_config.subnet_id.clone()).unwrap();
 
     // Setup network provider
-    let provider = JsonRpcProvider::new_http(
+    let provider = JsonRpcProvider::new_auto(
         network_config.rpc_url,
         network_config.subnet_id.chain_id(),
         None,
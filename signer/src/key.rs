@@ -1,10 +1,22 @@
 // Copyright 2022-2024 Protocol Labs
 // SPDX-License-Identifier: Apache-2.0, MIT
 
-use anyhow::Context;
+use std::path::Path;
+
+use aes::cipher::{KeyIvInit, StreamCipher};
+use anyhow::{anyhow, Context};
+use ctr::Ctr128BE;
+use pbkdf2::pbkdf2_hmac;
+use rand::RngCore;
+use scrypt::Params as ScryptParams;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use sha3::{Digest, Keccak256};
 
 pub use fendermint_crypto::SecretKey;
 
+type Aes128Ctr = Ctr128BE<aes::Aes128>;
+
 /// Parse [`SecretKey`] from a hex string.
 pub fn parse_secret_key(hex_str: &str) -> anyhow::Result<SecretKey> {
     let mut hex_str = hex_str.trim();
@@ -21,3 +33,203 @@ pub fn random_secretkey() -> SecretKey {
     let mut rng = rand::thread_rng();
     SecretKey::random(&mut rng)
 }
+
+/// An [Ethereum Secret Storage (v3)](https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/)
+/// keystore file, as read/written by [`load_keystore`]/[`save_keystore`].
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    crypto: Crypto,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Crypto {
+    cipher: String,
+    ciphertext: String,
+    cipherparams: CipherParams,
+    kdf: String,
+    kdfparams: KdfParams,
+    mac: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum KdfParams {
+    Scrypt {
+        n: u32,
+        r: u32,
+        p: u32,
+        dklen: u32,
+        salt: String,
+    },
+    Pbkdf2 {
+        c: u32,
+        prf: String,
+        dklen: u32,
+        salt: String,
+    },
+}
+
+/// Loads a [`SecretKey`] from a Web3 Secret Storage (v3) keystore file at `path`, decrypting it
+/// with `passphrase`.
+pub fn load_keystore(path: &Path, passphrase: &str) -> anyhow::Result<SecretKey> {
+    let json = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read keystore file {}", path.display()))?;
+    decrypt_keystore(&json, passphrase)
+}
+
+/// Decrypts an in-memory Web3 Secret Storage (v3) keystore JSON document with `passphrase`,
+/// verifying its MAC before recovering the private key.
+pub fn decrypt_keystore(json: &str, passphrase: &str) -> anyhow::Result<SecretKey> {
+    let file: KeystoreFile = serde_json::from_str(json).context("failed to parse keystore JSON")?;
+    if file.crypto.cipher != "aes-128-ctr" {
+        return Err(anyhow!(
+            "unsupported keystore cipher: {}",
+            file.crypto.cipher
+        ));
+    }
+
+    let mut ciphertext =
+        hex::decode(&file.crypto.ciphertext).context("invalid keystore ciphertext")?;
+    let iv = hex::decode(&file.crypto.cipherparams.iv).context("invalid keystore IV")?;
+    let iv: [u8; 16] = iv
+        .try_into()
+        .map_err(|_| anyhow!("keystore IV must be 16 bytes"))?;
+    let mac = hex::decode(&file.crypto.mac).context("invalid keystore mac")?;
+
+    let dk = derive_key(&file.crypto.kdfparams, passphrase)?;
+    if dk.len() < 32 {
+        return Err(anyhow!(
+            "derived key must be at least 32 bytes for aes-128-ctr + mac, got {}",
+            dk.len()
+        ));
+    }
+
+    let expected_mac = mac_of(&dk, &ciphertext);
+    if expected_mac != mac {
+        return Err(anyhow!(
+            "keystore MAC does not match: wrong passphrase or corrupted file"
+        ));
+    }
+
+    let key: [u8; 16] = dk[0..16].try_into().expect("checked dk.len() >= 32 above");
+    let mut cipher = Aes128Ctr::new(&key.into(), &iv.into());
+    cipher.apply_keystream(&mut ciphertext);
+
+    SecretKey::try_from(ciphertext).context("decrypted keystore payload is not a valid secret key")
+}
+
+/// Encrypts `sk` into a new Web3 Secret Storage (v3) keystore file at `path`, protected by
+/// `passphrase`.
+pub fn save_keystore(sk: &SecretKey, path: &Path, passphrase: &str) -> anyhow::Result<()> {
+    let json = encrypt_keystore(sk, passphrase)?;
+    std::fs::write(path, json)
+        .with_context(|| format!("failed to write keystore file {}", path.display()))
+}
+
+/// Encrypts `sk` into a Web3 Secret Storage (v3) keystore JSON document, protected by
+/// `passphrase`. Uses scrypt for key derivation with N = 8192, r = 8, p = 1 and a 32-byte
+/// derived key.
+pub fn encrypt_keystore(sk: &SecretKey, passphrase: &str) -> anyhow::Result<String> {
+    const LOG_N: u8 = 13; // N = 8192
+    const R: u32 = 8;
+    const P: u32 = 1;
+    const DKLEN: usize = 32;
+
+    let mut rng = rand::thread_rng();
+    let mut salt = [0u8; 32];
+    rng.fill_bytes(&mut salt);
+    let mut iv = [0u8; 16];
+    rng.fill_bytes(&mut iv);
+
+    let params =
+        ScryptParams::new(LOG_N, R, P, DKLEN).expect("fixed scrypt parameters are always valid");
+    let mut dk = [0u8; DKLEN];
+    scrypt::scrypt(passphrase.as_bytes(), &salt, &params, &mut dk)
+        .map_err(|e| anyhow!("scrypt key derivation failed: {e}"))?;
+
+    let mut ciphertext = sk.serialize().to_vec();
+    let key: [u8; 16] = dk[0..16].try_into().expect("DKLEN is 32 >= 16");
+    let mut cipher = Aes128Ctr::new(&key.into(), &iv.into());
+    cipher.apply_keystream(&mut ciphertext);
+
+    let mac = mac_of(&dk, &ciphertext);
+
+    let file = KeystoreFile {
+        version: 3,
+        id: Some(uuid::Uuid::new_v4().to_string()),
+        address: None,
+        crypto: Crypto {
+            cipher: "aes-128-ctr".to_string(),
+            ciphertext: hex::encode(ciphertext),
+            cipherparams: CipherParams { iv: hex::encode(iv) },
+            kdf: "scrypt".to_string(),
+            kdfparams: KdfParams::Scrypt {
+                n: 1 << LOG_N,
+                r: R,
+                p: P,
+                dklen: DKLEN as u32,
+                salt: hex::encode(salt),
+            },
+            mac: hex::encode(mac),
+        },
+    };
+    serde_json::to_string_pretty(&file).context("failed to serialize keystore")
+}
+
+/// Derives the keystore's scrypt/pbkdf2 key material per its `kdfparams`.
+fn derive_key(kdf: &KdfParams, passphrase: &str) -> anyhow::Result<Vec<u8>> {
+    match kdf {
+        KdfParams::Scrypt {
+            n,
+            r,
+            p,
+            dklen,
+            salt,
+        } => {
+            let salt = hex::decode(salt).context("invalid scrypt salt")?;
+            if !n.is_power_of_two() {
+                return Err(anyhow!("scrypt N must be a power of two, got {n}"));
+            }
+            let log_n = n.trailing_zeros() as u8;
+            let params = ScryptParams::new(log_n, *r, *p, *dklen as usize)
+                .map_err(|e| anyhow!("invalid scrypt parameters: {e}"))?;
+            let mut dk = vec![0u8; *dklen as usize];
+            scrypt::scrypt(passphrase.as_bytes(), &salt, &params, &mut dk)
+                .map_err(|e| anyhow!("scrypt key derivation failed: {e}"))?;
+            Ok(dk)
+        }
+        KdfParams::Pbkdf2 {
+            c,
+            prf,
+            dklen,
+            salt,
+        } => {
+            if prf != "hmac-sha256" {
+                return Err(anyhow!("unsupported pbkdf2 prf: {prf}"));
+            }
+            let salt = hex::decode(salt).context("invalid pbkdf2 salt")?;
+            let mut dk = vec![0u8; *dklen as usize];
+            pbkdf2_hmac::<Sha256>(passphrase.as_bytes(), &salt, *c, &mut dk);
+            Ok(dk)
+        }
+    }
+}
+
+/// `keccak256(dk[16:32] || ciphertext)`, the MAC the v3 spec uses to detect a wrong passphrase or
+/// a corrupted keystore without needing to attempt a decrypt first.
+fn mac_of(dk: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    let mut input = Vec::with_capacity(16 + ciphertext.len());
+    input.extend_from_slice(&dk[16..32]);
+    input.extend_from_slice(ciphertext);
+    Keccak256::digest(&input).to_vec()
+}
@@ -6,12 +6,18 @@
 //! A transaction signer for Recall.
 
 pub mod key;
+mod ledger;
+mod nonce_manager;
 mod signer;
 mod subnet;
+mod threshold;
 mod void;
 mod wallet;
 
+pub use ledger::{LedgerConfig, LedgerDerivationPath, LedgerSigner};
+pub use nonce_manager::NonceManager;
 pub use signer::{EthAddress, Signer};
 pub use subnet::SubnetID;
+pub use threshold::{NonceCommitment, PartialSignature, ThresholdConfig, ThresholdSigner};
 pub use void::Void;
 pub use wallet::{AccountKind, Wallet};
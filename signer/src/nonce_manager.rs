@@ -0,0 +1,196 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+//! A nonce-caching [`Signer`] wrapper.
+//!
+//! A bare [`crate::Wallet`] primes its sequence once (see `Wallet::init_sequence`) and then
+//! increments it locally under a mutex, which serializes concurrent `send_transaction` calls on
+//! that single lock and leaves the account wedged if a broadcast is dropped or the cached value
+//! ever drifts from the chain. [`NonceManager`] borrows the nonce-manager idea from ethers.js'
+//! middleware stack: it hands out the next sequence optimistically, and only pays for a
+//! round-trip query when the subnet actually rejects a message for having the wrong one.
+
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use fendermint_crypto::SecretKey;
+use recall_provider::error::RecallError;
+use recall_provider::fvm_ipld_encoding::RawBytes;
+use recall_provider::fvm_shared::{
+    address::Address, crypto::signature::Signature, econ::TokenAmount, error::ExitCode,
+    message::Message, MethodNum,
+};
+use recall_provider::gas_oracle::GasOracle;
+use recall_provider::message::{ChainMessage, GasParams, SignedMessage};
+use recall_provider::query::{FvmQueryHeight, GasSearchParams, QueryProvider};
+use recall_provider::tx::{BroadcastMode, DeliverTx, TxResult};
+use recall_provider::{Client, Provider};
+use tokio::sync::Mutex;
+
+use crate::signer::Signer;
+use crate::SubnetID;
+
+/// Wraps a [`Signer`] with a locally-cached, monotonically increasing sequence (nonce).
+///
+/// The cache is primed from the actor's on-chain state on first use, then handed out under a
+/// mutex so concurrent `send_transaction` calls don't each pay for their own query. If the
+/// subnet rejects a broadcast because the sequence no longer matches, the cache is dropped and
+/// resynced from on-chain state before the send is retried once.
+#[derive(Clone)]
+pub struct NonceManager<S: Signer> {
+    inner: S,
+    sequence: Arc<Mutex<Option<u64>>>,
+}
+
+impl<S: Signer> NonceManager<S> {
+    /// Wraps `inner`. The cache starts empty; the first call to [`Self::next`] (or
+    /// `send_transaction`) primes it from the actor's on-chain state.
+    pub fn new(inner: S) -> Self {
+        NonceManager {
+            inner,
+            sequence: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Returns the next sequence to use, priming the cache from on-chain state first if it's
+    /// never been set, and incrementing it for the next caller.
+    pub async fn next(&self, provider: &impl QueryProvider) -> anyhow::Result<u64> {
+        let mut guard = self.sequence.lock().await;
+        if guard.is_none() {
+            *guard = Some(self.fetch_sequence(provider).await?);
+        }
+        let sequence = guard.expect("primed above");
+        *guard = Some(sequence + 1);
+        Ok(sequence)
+    }
+
+    /// Drops the cached sequence, so the next [`Self::next`] (or `send_transaction`) call
+    /// re-queries on-chain state instead of trusting its last guess. Call this after a broadcast
+    /// fails in a way that leaves the account's actual sequence unknown.
+    pub async fn reset(&self) {
+        *self.sequence.lock().await = None;
+    }
+
+    async fn fetch_sequence(&self, provider: &impl QueryProvider) -> anyhow::Result<u64> {
+        let res = provider
+            .actor_state(&self.inner.address(), FvmQueryHeight::Pending)
+            .await?;
+
+        match res.value {
+            Some((_, state)) => Ok(state.sequence),
+            None => Err(anyhow!(
+                "failed to sync sequence; actor {} cannot be found",
+                self.inner.address()
+            )),
+        }
+    }
+}
+
+/// `true` if `err` is a [`RecallError::ActorReverted`] whose exit code indicates the subnet
+/// rejected the message for carrying the wrong sequence.
+fn is_sequence_mismatch(err: &anyhow::Error) -> bool {
+    matches!(
+        err.downcast_ref::<RecallError>(),
+        Some(RecallError::ActorReverted { exit_code, .. })
+            if *exit_code == ExitCode::SYS_SENDER_STATE_INVALID
+    )
+}
+
+#[async_trait]
+impl<S: Signer> Signer for NonceManager<S> {
+    fn address(&self) -> Address {
+        self.inner.address()
+    }
+
+    fn secret_key(&self) -> Option<SecretKey> {
+        self.inner.secret_key()
+    }
+
+    fn subnet_id(&self) -> Option<SubnetID> {
+        self.inner.subnet_id()
+    }
+
+    async fn send_transaction<
+        C: Client + Send + Sync,
+        T: Send + Sync,
+        F: FnOnce(&DeliverTx) -> anyhow::Result<T> + Send + Sync + Clone,
+    >(
+        &mut self,
+        provider: &impl Provider<C>,
+        to: Address,
+        value: TokenAmount,
+        method_num: MethodNum,
+        params: RawBytes,
+        mut gas_params: GasParams,
+        gas_oracle: Option<&(dyn GasOracle + Sync)>,
+        broadcast_mode: BroadcastMode,
+        decode_fn: F,
+    ) -> anyhow::Result<TxResult<T>> {
+        gas_params.set_limits();
+
+        // One retry: if the subnet rejects the first attempt for a stale sequence, resync from
+        // on-chain state and try again with a fresh one.
+        for attempt in 0..2 {
+            let sequence = self.next(provider).await?;
+
+            let mut message = Message {
+                version: Default::default(),
+                from: self.inner.address(),
+                to,
+                sequence,
+                value: value.clone(),
+                method_num,
+                params: params.clone(),
+                gas_limit: gas_params.gas_limit,
+                gas_fee_cap: gas_params.gas_fee_cap.clone(),
+                gas_premium: gas_params.gas_premium.clone(),
+            };
+
+            if let Some(gas_oracle) = gas_oracle.filter(|_| gas_params.gas_limit == 0) {
+                let estimated = gas_oracle.estimate(&message).await?;
+                message.gas_limit = estimated.gas_limit;
+                message.gas_fee_cap = estimated.gas_fee_cap.clone();
+                message.gas_premium = estimated.gas_premium.clone();
+            }
+
+            let gas_limit = provider
+                .estimate_gas_limit_searched(
+                    message.clone(),
+                    FvmQueryHeight::Committed,
+                    GasSearchParams {
+                        safety_margin: gas_params.gas_search_safety_margin,
+                        max_iterations: gas_params.gas_search_max_iterations,
+                        ..Default::default()
+                    },
+                )
+                .await?;
+            message.gas_limit = gas_limit;
+
+            let signed = self.inner.sign_message(message)?;
+            let signed_message = ChainMessage::Signed(signed);
+
+            match provider
+                .perform(signed_message, broadcast_mode, decode_fn.clone())
+                .await
+            {
+                Ok(result) => return Ok(result),
+                Err(err) if attempt == 0 && is_sequence_mismatch(&err) => {
+                    self.reset().await;
+                    continue;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        unreachable!("loop above always returns on its second attempt")
+    }
+
+    fn sign_message(&self, message: Message) -> anyhow::Result<SignedMessage> {
+        self.inner.sign_message(message)
+    }
+
+    fn verify_message(&self, message: &Message, signature: &Signature) -> anyhow::Result<()> {
+        self.inner.verify_message(message, signature)
+    }
+}
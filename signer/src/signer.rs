@@ -3,6 +3,7 @@
 
 use async_trait::async_trait;
 
+use hoku_provider::gas_oracle::GasOracle;
 use hoku_provider::message::{ChainMessage, GasParams, Message, SignedMessage};
 use hoku_provider::util::get_eth_address;
 use hoku_provider::{
@@ -38,6 +39,10 @@ pub trait Signer: Clone + Send + Sync {
     fn subnet_id(&self) -> Option<SubnetID>;
 
     /// Returns a [`ChainMessage`] that can be submitted to a provider.
+    ///
+    /// If `gas_oracle` is given and `gas_params` was left at [`GasParams::default`] (i.e. its
+    /// `gas_limit` is still zero), the oracle is consulted to fill in real gas parameters
+    /// instead of shipping zeros.
     async fn transaction(
         &mut self,
         to: Address,
@@ -45,6 +50,7 @@ pub trait Signer: Clone + Send + Sync {
         method_num: MethodNum,
         params: RawBytes,
         gas_params: GasParams,
+        gas_oracle: Option<&(dyn GasOracle + Sync)>,
     ) -> anyhow::Result<ChainMessage>;
 
     /// Returns a raw [`SignedMessage`].  
@@ -0,0 +1,159 @@
+// Copyright 2025 Recall Contributors
+// SPDX-License-Identifier: Apache-2.0, MIT
+
+use anyhow::anyhow;
+use recall_provider::fvm_shared::{address::Address, crypto::signature::Signature, message::Message};
+use recall_provider::message::{OriginKind, SignedMessage};
+
+use crate::signer::EthAddress;
+use crate::{AccountKind, SubnetID};
+
+/// A participant's first-round nonce commitment, broadcast to every other participant before
+/// anyone starts round 2.
+#[derive(Debug, Clone)]
+pub struct NonceCommitment {
+    pub participant: u16,
+    pub commitment: Vec<u8>,
+}
+
+/// A participant's second-round partial signature over `message_hash`, computed from a
+/// threshold's worth of [`NonceCommitment`]s gathered in round 1.
+#[derive(Debug, Clone)]
+pub struct PartialSignature {
+    pub participant: u16,
+    pub message_hash: [u8; 32],
+    pub share: Vec<u8>,
+}
+
+/// Static description of an n-of-m threshold-signing group: how many participants exist, how
+/// many of them must contribute a partial signature, and the group's aggregated public key.
+#[derive(Debug, Clone)]
+pub struct ThresholdConfig {
+    /// Number of partial signatures required to produce a valid aggregate.
+    pub threshold: u16,
+    /// Total number of participants in the group.
+    pub participants: u16,
+    /// The group's aggregated secp256k1 public key.
+    pub group_public_key: Vec<u8>,
+}
+
+/// Describes an n-of-m threshold-signing group and verifies aggregate secp256k1/Schnorr
+/// signatures it produces, following the two-round commit-then-sign protocol used by Serai's
+/// Ethereum integration: a nonce/commitment round ([`ThresholdSigner::round1_commit`]), then a
+/// partial-signature round keyed by the message hash ([`ThresholdSigner::round2_sign`]), combined
+/// into one [`Signature`] ([`ThresholdSigner::combine`]).
+///
+/// This does *not* implement [`Signer`](crate::Signer). That trait's contract is to produce a
+/// `SignedMessage` from `sign_message`/`send_transaction`, but this tree doesn't vendor a
+/// FROST/threshold-Schnorr backend (no `modular-frost`/`dkg` equivalent is a dependency here), so
+/// the elliptic-curve math behind `round1_commit`/`round2_sign`/`combine` can't be done for real
+/// in this snapshot -- the same gap [`LedgerSigner`](crate::LedgerSigner) hits for hardware-backed
+/// signing. Unlike `LedgerSigner`, there's no alternate code path elsewhere in this crate that
+/// completes threshold signing for real, so rather than implement `Signer` with `sign_message`
+/// and `send_transaction` methods whose only possible outcome is an error, this type is scoped
+/// down to what it can actually do: deriving the group's address from its aggregated public key,
+/// checking a commitment/share count against `threshold` before bothering to call into the
+/// (missing) backend, and verifying an aggregate signature against the group's public key, which
+/// is a plain secp256k1 check and needs no threshold machinery at all. Wiring this up to
+/// `Account::deposit`/`Blobs::fund`/bucket mutations for real quorum-authorized transactions needs
+/// a vendored FROST backend first.
+#[derive(Clone)]
+pub struct ThresholdSigner {
+    config: ThresholdConfig,
+    addr: Address,
+    subnet_id: SubnetID,
+}
+
+impl ThresholdSigner {
+    /// Builds a signer for the group described by `config`, deriving its address from the
+    /// group's aggregated public key the same way [`Wallet`](crate::Wallet) derives one from an
+    /// individual key.
+    pub fn new(config: ThresholdConfig, kind: AccountKind, subnet_id: SubnetID) -> anyhow::Result<Self> {
+        if config.threshold == 0 || config.threshold > config.participants {
+            return Err(anyhow!(
+                "threshold {} must be between 1 and the participant count {}",
+                config.threshold,
+                config.participants
+            ));
+        }
+        let addr = match kind {
+            AccountKind::Regular => Address::new_secp256k1(&config.group_public_key)?,
+            AccountKind::Ethereum => {
+                Address::from(EthAddress::new_secp256k1(&config.group_public_key)?)
+            }
+        };
+        Ok(ThresholdSigner {
+            config,
+            addr,
+            subnet_id,
+        })
+    }
+
+    /// Round 1: produces this participant's nonce commitment.
+    pub fn round1_commit(&self, _participant: u16) -> anyhow::Result<NonceCommitment> {
+        Err(anyhow!(
+            "threshold signing requires a FROST/Schnorr backend not vendored in this tree; \
+             cannot generate a nonce commitment"
+        ))
+    }
+
+    /// Round 2: turns this participant's share of the nonces gathered in round 1 into a partial
+    /// signature over `message_hash`, the hash of the serialized FVM [`Message`] being authorized.
+    pub fn round2_sign(
+        &self,
+        _participant: u16,
+        _message_hash: [u8; 32],
+        commitments: &[NonceCommitment],
+    ) -> anyhow::Result<PartialSignature> {
+        if commitments.len() < self.config.threshold as usize {
+            return Err(anyhow!(
+                "need {} round-1 commitments to start round 2, only have {}",
+                self.config.threshold,
+                commitments.len()
+            ));
+        }
+        Err(anyhow!(
+            "threshold signing requires a FROST/Schnorr backend not vendored in this tree; \
+             cannot produce a partial signature"
+        ))
+    }
+
+    /// Combines a threshold's worth of [`PartialSignature`]s into the single aggregate
+    /// [`Signature`] that [`ThresholdSigner::verify_message`] can check.
+    pub fn combine(&self, shares: &[PartialSignature]) -> anyhow::Result<Signature> {
+        if shares.len() < self.config.threshold as usize {
+            return Err(anyhow!(
+                "need {} partial signatures to combine, only have {}",
+                self.config.threshold,
+                shares.len()
+            ));
+        }
+        Err(anyhow!(
+            "threshold signing requires a FROST/Schnorr backend not vendored in this tree; \
+             cannot combine partial signatures into an aggregate"
+        ))
+    }
+
+    /// The group's address, derived from its aggregated public key.
+    pub fn address(&self) -> Address {
+        self.addr
+    }
+
+    /// The subnet this group is configured to transact on.
+    pub fn subnet_id(&self) -> &SubnetID {
+        &self.subnet_id
+    }
+
+    /// Verifies an aggregate signature produced by [`ThresholdSigner::combine`] against the
+    /// group's public key. The aggregate is a single secp256k1 signature once combined, so this
+    /// is a plain signature check against the group's address -- no threshold machinery needed.
+    pub fn verify_message(&self, message: &Message, signature: &Signature) -> anyhow::Result<()> {
+        SignedMessage::verify_signature(
+            OriginKind::Fvm,
+            message,
+            signature,
+            &self.subnet_id.chain_id(),
+        )?;
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use recall_provider::fvm_ipld_encoding::RawBytes;
 use recall_provider::fvm_shared::{
     address::Address, crypto::signature::Signature, econ::TokenAmount, message::Message, MethodNum,
 };
+use recall_provider::gas_oracle::GasOracle;
 use recall_provider::message::{GasParams, SignedMessage};
 use recall_provider::tx::{BroadcastMode, DeliverTx, TxResult};
 use recall_provider::{Client, Provider};
@@ -53,6 +54,7 @@ impl Signer for Void {
         _method_num: MethodNum,
         _params: RawBytes,
         _gas_params: GasParams,
+        _gas_oracle: Option<&(dyn GasOracle + Sync)>,
         _broadcast_mode: BroadcastMode,
         _decode_fn: F,
     ) -> anyhow::Result<TxResult<T>> {
@@ -8,12 +8,13 @@ use tokio::sync::Mutex;
 
 use crate::signer::{EthAddress, Signer};
 use crate::SubnetID;
+use hoku_provider::gas_oracle::GasOracle;
 use hoku_provider::tx::{BroadcastMode, DeliverTx, TxReceipt};
 use hoku_provider::{
     fvm_ipld_encoding::RawBytes,
     fvm_shared::{address::Address, crypto::signature::Signature, econ::TokenAmount, MethodNum},
     message::{ChainMessage, GasParams, Message, OriginKind, SignedMessage},
-    query::{FvmQueryHeight, QueryProvider},
+    query::{FvmQueryHeight, GasSearchParams, QueryProvider},
     Client, Provider,
 };
 
@@ -67,6 +68,7 @@ impl Signer for Wallet {
         method_num: MethodNum,
         params: RawBytes,
         mut gas_params: GasParams,
+        gas_oracle: Option<&(dyn GasOracle + Sync)>,
         broadcast_mode: BroadcastMode,
         decode_fn: F,
     ) -> anyhow::Result<TxReceipt<T>> {
@@ -78,13 +80,29 @@ impl Signer for Wallet {
             value,
             method_num,
             params,
-            gas_limit: gas_params.gas_limit.clone(),
+            gas_limit: gas_params.gas_limit,
             gas_fee_cap: gas_params.gas_fee_cap.clone(),
             gas_premium: gas_params.gas_premium.clone(),
         };
-        // Set gas limit to the estimated value
+
+        if let Some(gas_oracle) = gas_oracle.filter(|_| gas_params.gas_limit == 0) {
+            gas_params = gas_oracle.estimate(&message).await?;
+            message.gas_limit = gas_params.gas_limit;
+            message.gas_fee_cap = gas_params.gas_fee_cap.clone();
+            message.gas_premium = gas_params.gas_premium.clone();
+        }
+
+        // Set gas limit to a binary-searched, margined estimate.
         let gas_limit = provider
-            .estimate_gas_limit(message.clone(), FvmQueryHeight::Committed)
+            .estimate_gas_limit_searched(
+                message.clone(),
+                FvmQueryHeight::Committed,
+                GasSearchParams {
+                    safety_margin: gas_params.gas_search_safety_margin,
+                    max_iterations: gas_params.gas_search_max_iterations,
+                    ..Default::default()
+                },
+            )
             .await?;
         message.gas_limit = gas_limit;
 
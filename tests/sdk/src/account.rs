@@ -3,11 +3,9 @@
 #[cfg(test)]
 mod tests {
     use std::ops::Sub;
-    use std::time::Duration;
 
     use anyhow::anyhow;
     use more_asserts::{assert_ge, assert_gt};
-    use tokio::time::timeout;
 
     use recall_provider::fvm_shared::econ::TokenAmount;
     use recall_sdk::{account::Account, ipc::subnet::EVMSubnet, network::NetworkConfig};
@@ -64,8 +62,9 @@ mod tests {
 
         let tokens_to_deposit = TokenAmount::from_whole(1);
 
-        // Deposit some funds into the subnet
-        Account::deposit(
+        // Deposit some funds into the subnet, and wait for the supply source balance on the
+        // parent to reflect it (or time out).
+        let updated_supply_source_balance = Account::deposit(
             &signer,
             signer.address(),
             network_config
@@ -76,40 +75,32 @@ mod tests {
             tokens_to_deposit.clone(),
         )
         .await
+        .unwrap()
+        .await
         .unwrap();
+        assert_eq!(
+            supply_source_balance.clone().sub(&updated_supply_source_balance),
+            tokens_to_deposit
+        );
+        println!(
+            "Supply source balance for {} updated from {} to {}",
+            signer.eth_address().unwrap(),
+            supply_source_balance,
+            updated_supply_source_balance
+        );
 
-        // Wait for the balances to be updated
-        assert!(
-            timeout(Duration::from_secs(120), async {
-                loop {
-                    let (updated_account_balance, updated_supply_source_balance) =
-                        get_account_balances(&signer, network_config.clone()).await;
-                    if (updated_account_balance.clone().sub(&account_balance) == tokens_to_deposit)
-                        && (supply_source_balance
-                            .clone()
-                            .sub(&updated_supply_source_balance)
-                            == tokens_to_deposit)
-                    {
-                        println!(
-                            "Account balance for {} updated from {} to {}",
-                            signer.eth_address().unwrap(),
-                            account_balance,
-                            updated_account_balance
-                        );
-                        println!(
-                            "Supply source balance for {} updated from {} to {}",
-                            signer.eth_address().unwrap(),
-                            supply_source_balance,
-                            updated_supply_source_balance
-                        );
-                        return;
-                    }
-                    tokio::time::sleep(Duration::from_secs(1)).await;
-                }
-            })
-            .await
-            .is_ok(),
-            "Timeout waiting for balances to update"
+        // The destination subnet should have picked up the deposit by now too.
+        let (updated_account_balance, _) =
+            get_account_balances(&signer, network_config.clone()).await;
+        assert_eq!(
+            updated_account_balance.clone().sub(&account_balance),
+            tokens_to_deposit
+        );
+        println!(
+            "Account balance for {} updated from {} to {}",
+            signer.eth_address().unwrap(),
+            account_balance,
+            updated_account_balance
         );
     }
 }
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0, MIT
 #[cfg(test)]
 mod tests {
+    use futures::future::try_join_all;
     use rand::{thread_rng, Rng};
     use std::collections::HashMap;
     use std::time::Duration;
@@ -13,7 +14,7 @@ mod tests {
         bucket::{AddOptions, Bucket, GetOptions, QueryOptions},
         Machine,
     };
-    use recall_signer::{key::parse_secret_key, AccountKind, Wallet};
+    use recall_signer::{key::parse_secret_key, AccountKind, NonceManager, Signer, Wallet};
 
     use crate::test_utils;
 
@@ -28,7 +29,7 @@ mod tests {
                 .unwrap();
 
         // Setup network provider
-        let provider = JsonRpcProvider::new_http(
+        let provider = JsonRpcProvider::new_auto(
             network_config.rpc_url,
             network_config.subnet_id.chain_id(),
             None,
@@ -121,4 +122,70 @@ mod tests {
 
         // TODO: failure might throw, but need to add assertion for deleting
     }
+
+    #[tokio::test]
+    #[ignore]
+    async fn can_add_multiple_objects_concurrently() {
+        let network_config = test_utils::get_network_config();
+        let sk_env = test_utils::get_runner_secret_key();
+        let sk = parse_secret_key(&sk_env).unwrap();
+        let signer =
+            Wallet::new_secp256k1(sk, AccountKind::Ethereum, network_config.subnet_id.clone())
+                .unwrap();
+
+        let provider = JsonRpcProvider::new_auto(
+            network_config.rpc_url,
+            network_config.subnet_id.chain_id(),
+            None,
+            Some(network_config.object_api_url),
+        )
+        .unwrap();
+
+        let from = signer.address();
+        // A bare `Wallet` serializes concurrent sends by holding its sequence mutex for the
+        // whole send; `NonceManager` hands out the next sequence from a shared, locally-cached
+        // counter and releases it before sending, so these adds can all be in flight at once.
+        let mut signer = NonceManager::new(signer);
+
+        let (machine, _) = Bucket::new(
+            &provider,
+            &mut signer,
+            None,
+            HashMap::new(),
+            Default::default(),
+        )
+        .await
+        .unwrap();
+
+        const NUM_OBJECTS: usize = 5;
+        let mut rng = thread_rng();
+        let mut uploads = Vec::with_capacity(NUM_OBJECTS);
+        for i in 0..NUM_OBJECTS {
+            let mut file = async_tempfile::TempFile::new().await.unwrap();
+            let mut random_data = vec![0; 1024]; // 1 KiB
+            rng.fill(&mut random_data[..]);
+            file.write_all(&random_data).await.unwrap();
+            file.flush().await.unwrap();
+
+            let machine = &machine;
+            let provider = &provider;
+            let mut signer = signer.clone();
+            let key = format!("concurrent/{i}");
+            uploads.push(async move {
+                machine
+                    .add_from_path(
+                        provider,
+                        &mut signer,
+                        from,
+                        &key,
+                        file.file_path(),
+                        AddOptions::default(),
+                    )
+                    .await
+            });
+        }
+
+        let results = try_join_all(uploads).await.unwrap();
+        assert_eq!(results.len(), NUM_OBJECTS);
+    }
 }